@@ -39,6 +39,10 @@ impl Argument {
         self.resolved.borrow().is_some()
     }
 
+    pub fn unresolve(&self) {
+        *(unsafe { &mut *self.resolved.as_ptr() }) = None;
+    }
+
     pub fn resolved_name(&self) -> Option<&str> {
         if let Some(name) = self.name() {
             Some(name.name())
@@ -52,7 +56,7 @@ impl Argument {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ArgumentResolved {
     pub name: String,
     pub expect: Type,
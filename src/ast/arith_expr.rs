@@ -84,12 +84,30 @@ impl Write for BinaryOperation {
     }
 }
 
-#[derive(Debug)]
+declare_container_node!(TernaryOperation, pub(crate) condition: usize, pub(crate) consequent: usize, pub(crate) alternative: usize);
+
+impl_container_node_defaults!(TernaryOperation);
+
+impl TernaryOperation {
+
+    node_child_fn!(condition, ArithExpr);
+    node_child_fn!(consequent, Expression);
+    node_child_fn!(alternative, Expression);
+}
+
+impl Write for TernaryOperation {
+    fn write<'a>(&'a self, writer: &mut Writer<'a>) {
+        writer.write_children(self, self.children.values())
+    }
+}
+
+#[derive(Debug, Clone)]
 pub enum ArithExpr {
     Expression(Box<Expression>),
     UnaryOperation(UnaryOperation),
     BinaryOperation(BinaryOperation),
     UnaryPostfixOperation(UnaryPostfixOperation),
+    TernaryOperation(TernaryOperation),
 }
 
 impl ArithExpr {
@@ -100,6 +118,7 @@ impl ArithExpr {
             ArithExpr::UnaryOperation(n) => n,
             ArithExpr::BinaryOperation(n) => n,
             ArithExpr::UnaryPostfixOperation(n) => n,
+            ArithExpr::TernaryOperation(n) => n,
         }
     }
 
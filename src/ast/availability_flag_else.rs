@@ -0,0 +1,18 @@
+use crate::{declare_node, impl_node_defaults};
+use crate::format::Writer;
+use crate::traits::write::Write;
+
+declare_node!(AvailabilityFlagElse);
+
+impl_node_defaults!(AvailabilityFlagElse);
+
+impl Write for AvailabilityFlagElse {
+
+    fn write<'a>(&'a self, writer: &mut Writer<'a>) {
+        writer.write_content(self, "#else\n");
+    }
+
+    fn is_block_level_element(&self) -> bool {
+        true
+    }
+}
@@ -8,6 +8,7 @@ impl_node_defaults!(CodeComment);
 
 impl CodeComment {
 
+    /// Contents of the `//` comments. Block comments are kept with their delimiters.
     pub fn lines(&self) -> &Vec<String> {
         &self.lines
     }
@@ -17,7 +18,9 @@ impl Write for CodeComment {
     fn write<'a>(&'a self, writer: &mut Writer<'a>) {
         let mut contents = vec![];
         for line in self.lines() {
-            contents.push("//");
+            if !line.starts_with("/*") {
+                contents.push("//");
+            }
             contents.push(line.as_str());
             contents.push("\n");
         }
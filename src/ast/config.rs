@@ -1,15 +1,18 @@
+use indexmap::IndexMap;
 use crate::ast::keyword::Keyword;
 use crate::ast::expression::Expression;
 use crate::ast::identifier::Identifier;
 use crate::ast::span::Span;
 use crate::{declare_container_node, impl_container_node_defaults, node_optional_child_fn, node_child_fn};
 use crate::ast::literals::DictionaryLiteral;
+use crate::evaluator::evaluate_expression::evaluate_expression;
 use crate::format::Writer;
 use crate::traits::has_availability::HasAvailability;
 use crate::traits::info_provider::InfoProvider;
 use crate::traits::named_identifiable::NamedIdentifiable;
 use crate::traits::node_trait::NodeTrait;
 use crate::traits::write::Write;
+use crate::value::Value;
 
 declare_container_node!(Config, named, availability,
     pub keyword: usize,
@@ -43,6 +46,14 @@ impl Config {
     pub fn items(&self) -> Vec<(&Expression, &Expression)> {
         self.dictionary_literal().expressions().map(|e| (e.key(), e.value())).collect()
     }
+
+    /// The compile time values of the available items. Items whose values are only known at
+    /// runtime are left out.
+    pub fn evaluated_items(&self) -> IndexMap<String, Value> {
+        self.dictionary_literal().expressions().filter(|item| item.is_available()).filter_map(|item| {
+            Some((item.key().named_key_without_resolving()?.to_owned(), evaluate_expression(item.value())?))
+        }).collect()
+    }
 }
 
 impl NamedIdentifiable for Config {
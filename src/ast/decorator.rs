@@ -19,6 +19,21 @@ impl Decorator {
     node_child_fn!(identifier_path, IdentifierPath);
 
     node_optional_child_fn!(argument_list, ArgumentList);
+
+    /// The resolved string value of the first argument, like the name given to `@map`.
+    pub(crate) fn string_argument(&self) -> Option<&str> {
+        let argument = self.argument_list()?.arguments().next()?;
+        if argument.value().is_resolved() {
+            argument.value().resolved().value()?.as_str()
+        } else {
+            None
+        }
+    }
+}
+
+/// The name given to the `@map` decorator among `decorators`.
+pub(crate) fn mapped_name<'a>(mut decorators: impl Iterator<Item = &'a Decorator>) -> Option<&'a str> {
+    decorators.find(|d| d.identifier_path().identifiers().last().unwrap().name() == "map")?.string_argument()
 }
 
 impl Resolve<Vec<usize>> for Decorator {
@@ -11,6 +11,11 @@ pub struct DocComment {
     pub(crate) path: Vec<usize>,
     pub(crate) name: Option<String>,
     pub(crate) desc: Option<String>,
+    pub(crate) params: Vec<(String, String)>,
+    pub(crate) examples: Vec<String>,
+    pub(crate) deprecated: Option<String>,
+    pub(crate) see: Vec<String>,
+    pub(crate) since: Option<String>,
 }
 
 impl_node_defaults!(DocComment);
@@ -24,6 +29,36 @@ impl DocComment {
     pub fn desc(&self) -> Option<&str> {
         self.desc.as_ref().map(|n| n.as_str())
     }
+
+    /// Names and descriptions of the `@param` tags.
+    pub fn params(&self) -> &Vec<(String, String)> {
+        &self.params
+    }
+
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params.iter().find(|(n, _)| n == name).map(|(_, desc)| desc.as_str())
+    }
+
+    pub fn examples(&self) -> &Vec<String> {
+        &self.examples
+    }
+
+    /// The message of the `@deprecated` tag, empty if the tag has no message.
+    pub fn deprecated(&self) -> Option<&str> {
+        self.deprecated.as_ref().map(|d| d.as_str())
+    }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated.is_some()
+    }
+
+    pub fn see(&self) -> &Vec<String> {
+        &self.see
+    }
+
+    pub fn since(&self) -> Option<&str> {
+        self.since.as_ref().map(|s| s.as_str())
+    }
 }
 
 impl Write for DocComment {
@@ -39,6 +74,41 @@ impl Write for DocComment {
             contents.push(desc);
             contents.push("\n");
         }
+        for (name, desc) in self.params() {
+            contents.push("/// @param ");
+            contents.push(name);
+            if !desc.is_empty() {
+                contents.push(" ");
+                contents.push(desc);
+            }
+            contents.push("\n");
+        }
+        for example in self.examples() {
+            contents.push("/// @example\n");
+            for line in example.lines() {
+                contents.push("/// ");
+                contents.push(line);
+                contents.push("\n");
+            }
+        }
+        if let Some(deprecated) = self.deprecated() {
+            contents.push("/// @deprecated");
+            if !deprecated.is_empty() {
+                contents.push(" ");
+                contents.push(deprecated);
+            }
+            contents.push("\n");
+        }
+        for see in self.see() {
+            contents.push("/// @see ");
+            contents.push(see);
+            contents.push("\n");
+        }
+        if let Some(since) = self.since() {
+            contents.push("/// @since ");
+            contents.push(since);
+            contents.push("\n");
+        }
         writer.write_contents(self, contents);
 
     }
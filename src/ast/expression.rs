@@ -9,7 +9,7 @@ use crate::ast::group::Group;
 use crate::ast::pipeline::Pipeline;
 use crate::ast::identifier::Identifier;
 use crate::ast::int_subscript::IntSubscript;
-use crate::ast::literals::{ArrayLiteral, BoolLiteral, DictionaryLiteral, EnumVariantLiteral, NullLiteral, NumericLiteral, RegexLiteral, StringLiteral, TupleLiteral};
+use crate::ast::literals::{ArrayLiteral, BoolLiteral, DictionaryLiteral, EnumVariantLiteral, NullLiteral, NumericLiteral, RegexLiteral, StringLiteral, TupleLiteral, DateLiteral};
 use crate::ast::named_expression::NamedExpression;
 use crate::ast::node::Node;
 use crate::ast::span::Span;
@@ -23,13 +23,14 @@ use crate::traits::resolved::{Resolve, ResolveAndClone};
 use crate::traits::write::Write;
 use crate::expr::ExprInfo;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ExpressionKind {
     Group(Group),
     ArithExpr(ArithExpr),
     NumericLiteral(NumericLiteral),
     StringLiteral(StringLiteral),
     RegexLiteral(RegexLiteral),
+    DateLiteral(DateLiteral),
     BoolLiteral(BoolLiteral),
     NullLiteral(NullLiteral),
     EnumVariantLiteral(EnumVariantLiteral),
@@ -57,6 +58,7 @@ impl ExpressionKind {
             ExpressionKind::NumericLiteral(n) => n,
             ExpressionKind::StringLiteral(n) => n,
             ExpressionKind::RegexLiteral(n) => n,
+            ExpressionKind::DateLiteral(n) => n,
             ExpressionKind::BoolLiteral(n) => n,
             ExpressionKind::NullLiteral(n) => n,
             ExpressionKind::EnumVariantLiteral(n) => n,
@@ -109,6 +111,17 @@ impl ExpressionKind {
         self.as_regex_literal().is_some()
     }
 
+    pub fn as_date_literal(&self) -> Option<&DateLiteral> {
+        match self {
+            ExpressionKind::DateLiteral(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    pub fn is_date_literal(&self) -> bool {
+        self.as_date_literal().is_some()
+    }
+
     pub fn as_bool_literal(&self) -> Option<&BoolLiteral> {
         match self {
             ExpressionKind::BoolLiteral(n) => Some(n),
@@ -282,7 +295,7 @@ impl Display for ExpressionKind {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Expression {
     pub kind: ExpressionKind,
     pub resolved: RefCell<Option<ExprInfo>>,
@@ -314,6 +327,22 @@ impl Expression {
         false
     }
 
+    pub fn is_single_string_literal(&self) -> bool {
+        if self.kind.is_string_literal() {
+            return true;
+        }
+        if let Some(arith_expr) = self.kind.as_arith_expr() {
+            return match arith_expr {
+                ArithExpr::Expression(e) => e.is_single_string_literal(),
+                _ => false,
+            };
+        }
+        if let Some(unit) = self.kind.as_unit() {
+            return unit.expressions().count() == 1 && unit.expression_at(0).unwrap().is_single_string_literal();
+        }
+        false
+    }
+
     pub fn unwrap_enumerable_enum_member_strings(&self) -> Option<Vec<&str>> {
         self.kind.unwrap_enumerable_enum_member_strings()
     }
@@ -324,7 +353,7 @@ impl Expression {
 
     pub fn named_key_without_resolving(&self) -> Option<&str> {
         match &self.kind {
-            ExpressionKind::StringLiteral(s) => Some(s.value.as_str()),
+            ExpressionKind::StringLiteral(s) => s.value(),
             ExpressionKind::Identifier(i) => Some(i.name()),
             _ => None,
         }
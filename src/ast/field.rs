@@ -1,7 +1,7 @@
 use std::cell::RefCell;
 use crate::ast::span::Span;
 use crate::ast::doc_comment::DocComment;
-use crate::ast::decorator::Decorator;
+use crate::ast::decorator::{Decorator, mapped_name};
 use crate::ast::type_expr::TypeExpr;
 use crate::ast::identifier::Identifier;
 use crate::ast::reference_space::ReferenceSpace;
@@ -102,7 +102,7 @@ impl FieldClass {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FieldResolved {
     pub class: FieldClass,
 }
@@ -113,6 +113,7 @@ declare_container_node!(Field, named, availability,
     pub(crate) empty_decorator_spans: Vec<Span>,
     pub(crate) identifier: usize,
     pub(crate) type_expr: usize,
+    pub(crate) origin: Vec<usize>,
     pub(crate) resolved: RefCell<Option<FieldResolved>>,
 );
 
@@ -129,6 +130,22 @@ impl Field {
     node_child_fn!(identifier, Identifier);
 
     node_child_fn!(type_expr, TypeExpr);
+
+    /// The path of the field this field is copied from by an extending model, or the path of
+    /// this field if it's declared in its parent. Spans and children belong to the origin.
+    pub fn origin(&self) -> &Vec<usize> {
+        &self.origin
+    }
+
+    pub fn origin_source_id(&self) -> usize {
+        *self.origin.first().unwrap()
+    }
+
+    /// The underlying database column name. It's the name given to `@map`, otherwise the field
+    /// name without backticks.
+    pub fn column_name(&self) -> &str {
+        mapped_name(self.decorators()).unwrap_or(self.identifier().name())
+    }
 }
 
 impl InfoProvider for Field {
@@ -2,20 +2,28 @@ use crate::{declare_node, impl_node_defaults};
 use crate::format::Writer;
 use crate::traits::write::Write;
 
-declare_node!(Identifier, pub(crate) name: String);
+declare_node!(Identifier, pub(crate) name: String, pub(crate) escaped: bool);
 
 impl Identifier {
 
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
+
+    /// Whether this identifier is written in backticks.
+    pub fn is_escaped(&self) -> bool {
+        self.escaped
+    }
 }
 
 impl_node_defaults!(Identifier);
 
 impl Write for Identifier {
     fn write<'a>(&'a self, writer: &mut Writer<'a>) {
-        writer.write_content(self, self.name());
+        if self.escaped {
+            writer.write_contents(self, vec!["`", self.name(), "`"]);
+        } else {
+            writer.write_content(self, self.name());
+        }
     }
 }
-
@@ -1,31 +1,112 @@
+use crate::ast::identifier::Identifier;
 use crate::ast::literals::StringLiteral;
-use crate::ast::span::Span;
-use crate::{declare_node, impl_node_defaults};
+use crate::{declare_container_node, impl_container_node_defaults, node_child_fn, node_optional_child_fn};
 use crate::format::Writer;
 use crate::traits::write::Write;
 
-declare_node!(Import,
-    pub source: StringLiteral,
-    pub file_path: String,
+declare_container_node!(Import,
+    pub(crate) source: usize,
+    pub(crate) identifiers: Option<Vec<usize>>,
+    pub(crate) as_identifier: Option<usize>,
+    /// The files this import loads. A glob pattern or a directory loads every schema file it
+    /// matches, sorted by path. A directory containing an `index.teo` loads the index file only.
+    pub file_paths: Vec<String>,
 );
 
-impl_node_defaults!(Import);
+impl_container_node_defaults!(Import);
+
+pub struct IdentifiersIter<'a> {
+    index: usize,
+    owner: &'a Import,
+}
+
+impl<'a> Iterator for IdentifiersIter<'a> {
+    type Item = &'a Identifier;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.index += 1;
+        self.owner.identifiers.as_ref().and_then(|identifiers| identifiers.get(self.index - 1)).map(|i| self.owner.children.get(i).unwrap().try_into().unwrap())
+    }
+}
 
 impl Import {
 
-    pub fn new(path: Vec<usize>, source: StringLiteral, file_path: String, span: Span) -> Self {
-        Self {
-            path,
-            source,
-            file_path,
-            span,
+    node_child_fn!(source, StringLiteral);
+
+    node_optional_child_fn!(as_identifier, Identifier);
+
+    pub fn identifiers(&self) -> IdentifiersIter {
+        IdentifiersIter {
+            owner: self,
+            index: 0,
+        }
+    }
+
+    /// The first file this import loads, empty if it loads none.
+    #[deprecated(note = "an import may load several files, use `file_paths`")]
+    pub fn file_path(&self) -> &str {
+        self.file_paths.first().map_or("", |file_path| file_path.as_str())
+    }
+
+    pub fn is_selective(&self) -> bool {
+        self.identifiers.is_some()
+    }
+
+    /// The name the imported declarations are reached through. The declarations keep their own
+    /// `string_path`, so models of the same name in two aliased files share a `string_path`
+    /// and are told apart by their `path`.
+    pub fn alias(&self) -> Option<&str> {
+        self.as_identifier().map(|identifier| identifier.name())
+    }
+
+    pub fn imports_name(&self, name: &str) -> bool {
+        if self.is_selective() {
+            self.identifiers().any(|identifier| identifier.name() == name)
+        } else {
+            self.alias().is_none()
+        }
+    }
+
+    /// Map an identifier path written in the importing source to the path to look up in the
+    /// imported source. Returns `None` if this import doesn't expose the path.
+    pub fn imported_identifier_path_names<'a>(&self, identifier_path_names: &Vec<&'a str>) -> Option<Vec<&'a str>> {
+        if let Some(alias) = self.alias() {
+            if identifier_path_names.len() > 1 && identifier_path_names.first() == Some(&alias) {
+                Some(identifier_path_names[1..].to_vec())
+            } else {
+                None
+            }
+        } else if identifier_path_names.first().map_or(false, |name| self.imports_name(name)) {
+            Some(identifier_path_names.clone())
+        } else {
+            None
         }
     }
 }
 
 impl Write for Import {
     fn write<'a>(&'a self, writer: &mut Writer<'a>) {
-        writer.write_contents(self, vec!["import ", self.source.display.as_str()])
+        let mut contents = vec!["import "];
+        if self.is_selective() {
+            if self.identifiers().next().is_some() {
+                contents.push("{ ");
+                for (index, identifier) in self.identifiers().enumerate() {
+                    if index != 0 {
+                        contents.push(", ");
+                    }
+                    contents.push(identifier.name());
+                }
+                contents.push(" } from ");
+            } else {
+                contents.push("{} from ");
+            }
+        }
+        contents.push(self.source().display.as_str());
+        if let Some(alias) = self.alias() {
+            contents.push(" as ");
+            contents.push(alias);
+        }
+        writer.write_contents(self, contents)
     }
 
     fn always_start_on_new_line(&self) -> bool {
@@ -35,4 +116,4 @@ impl Write for Import {
     fn always_end_on_new_line(&self) -> bool {
         true
     }
-}
\ No newline at end of file
+}
@@ -77,7 +77,7 @@ impl Resolve<IncludeHandlerFromTemplateResolved> for IncludeHandlerFromTemplate
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IncludeHandlerFromTemplateResolved {
     pub input_type: Option<Type>,
     pub output_type: Type,
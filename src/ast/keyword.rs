@@ -12,6 +12,10 @@ impl Write for Keyword {
         writer.write_content(self, self.name());
     }
 
+    fn prefer_whitespace_before(&self) -> bool {
+        self.name() == "extends"
+    }
+
     fn prefer_whitespace_after(&self) -> bool {
         self.name() != "variant"
     }
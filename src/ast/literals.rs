@@ -6,6 +6,7 @@ use crate::ast::identifier::Identifier;
 use crate::{declare_container_node, declare_node, impl_container_node_defaults, impl_node_defaults, node_child_fn, node_children_iter, node_children_iter_fn, node_optional_child_fn};
 use crate::ast::named_expression::NamedExpression;
 use crate::ast::punctuations::Punctuation;
+use crate::ast::span::Span;
 use crate::format::Writer;
 use crate::traits::write::Write;
 
@@ -19,10 +20,52 @@ impl Write for NumericLiteral {
     }
 }
 
-declare_node!(StringLiteral, pub(crate) value: String, pub(crate) display: String);
+// `value` is the text of a literal without interpolations, an interpolated literal only has a
+// value after it's resolved.
+declare_node!(StringLiteral, pub(crate) value: Option<String>, pub(crate) display: String, pub(crate) segments: Vec<StringSegment>);
 
 impl_node_defaults!(StringLiteral);
 
+impl StringLiteral {
+
+    pub fn segments(&self) -> &Vec<StringSegment> {
+        &self.segments
+    }
+
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    pub fn is_interpolated(&self) -> bool {
+        self.segments.iter().any(|s| s.is_interpolation())
+    }
+}
+
+/// A part of a string literal. Interpolations like `${CONST}` are resolved into constant values
+/// by the resolver, `\${CONST}` is a text.
+#[derive(Debug, Clone)]
+pub enum StringSegment {
+    Text(String),
+    Interpolation(Span, Vec<String>),
+}
+
+impl StringSegment {
+
+    pub fn is_interpolation(&self) -> bool {
+        match self {
+            StringSegment::Interpolation(_, _) => true,
+            _ => false,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            StringSegment::Text(text) => Some(text.as_str()),
+            _ => None,
+        }
+    }
+}
+
 impl Write for StringLiteral {
     fn write<'a>(&'a self, writer: &mut Writer<'a>) {
         writer.write_content(self, self.display.as_str());
@@ -39,6 +82,16 @@ impl Write for RegexLiteral {
     }
 }
 
+declare_node!(DateLiteral, pub(crate) value: Value, pub(crate) display: String);
+
+impl_node_defaults!(DateLiteral);
+
+impl Write for DateLiteral {
+    fn write<'a>(&'a self, writer: &mut Writer<'a>) {
+        writer.write_content(self, self.display.as_str());
+    }
+}
+
 declare_node!(BoolLiteral, pub(crate) value: bool);
 
 impl_node_defaults!(BoolLiteral);
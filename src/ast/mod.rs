@@ -45,6 +45,7 @@ pub mod type_info;
 pub mod use_middlewares;
 pub mod node;
 pub mod availability_flag;
+pub mod availability_flag_else;
 pub mod availability_flag_end;
 pub mod punctuations;
 pub mod operators;
@@ -57,6 +58,7 @@ pub mod partial_argument;
 pub mod empty_decorator;
 pub mod synthesized_shape_declaration;
 pub mod synthesized_shape_field_declaration;
+pub mod type_alias_declaration;
 pub mod handler_template_declaration;
 pub mod include_handler_from_template;
 pub mod type_as_value_expression;
\ No newline at end of file
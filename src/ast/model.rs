@@ -2,10 +2,11 @@ use std::cell::RefCell;
 use indexmap::IndexMap;
 use serde::Serialize;
 use crate::ast::doc_comment::DocComment;
-use crate::ast::decorator::Decorator;
+use crate::ast::decorator::{Decorator, mapped_name};
 use crate::ast::field::Field;
 use crate::ast::handler::HandlerDeclaration;
 use crate::ast::identifier::Identifier;
+use crate::ast::identifier_path::IdentifierPath;
 use crate::ast::span::Span;
 use crate::{declare_container_node, impl_container_node_defaults, node_child_fn, node_children_iter, node_children_iter_fn, node_optional_child_fn};
 use crate::ast::include_handler_from_template::IncludeHandlerFromTemplate;
@@ -23,8 +24,10 @@ use crate::traits::resolved::Resolve;
 use crate::traits::write::Write;
 
 declare_container_node!(Model, named, availability,
+    pub r#abstract: bool,
     pub(crate) comment: Option<usize>,
     pub(crate) identifier: usize,
+    pub(crate) extends: Vec<usize>,
     pub(crate) fields: Vec<usize>,
     pub(crate) partial_fields: Vec<usize>,
     pub(crate) decorators: Vec<usize>,
@@ -40,6 +43,8 @@ impl_container_node_defaults!(Model, named, availability);
 
 node_children_iter!(Model, Decorator, DecoratorsIter, decorators);
 
+node_children_iter!(Model, IdentifierPath, ExtendsIter, extends);
+
 node_children_iter!(Model, Field, FieldsIter, fields);
 
 node_children_iter!(Model, PartialField, PartialFieldsIter, partial_fields);
@@ -56,13 +61,38 @@ impl Model {
 
     node_children_iter_fn!(decorators, DecoratorsIter);
 
+    node_children_iter_fn!(extends, ExtendsIter);
+
+    /// An abstract model is only extended by other models. It has no shapes and isn't listed
+    /// in `Schema::models`.
+    pub fn is_abstract(&self) -> bool {
+        self.r#abstract
+    }
+
     node_children_iter_fn!(fields, FieldsIter);
 
+    /// Fields declared in the body of this model, without the fields copied from the models
+    /// and interfaces it extends.
+    pub fn declared_fields(&self) -> impl Iterator<Item = &Field> {
+        self.fields().filter(|field| !self.is_mixin_field(field))
+    }
+
+    /// Whether `field` is copied from a model or interface this model extends.
+    pub fn is_mixin_field(&self, field: &Field) -> bool {
+        field.origin != field.path
+    }
+
     node_children_iter_fn!(partial_fields, PartialFieldsIter);
 
     node_children_iter_fn!(handlers, HandlersIter);
 
     node_children_iter_fn!(handler_inclusions, HandlersInclusionIter);
+
+    /// The underlying database table name. It's the name given to `@map`, otherwise the model
+    /// name without backticks.
+    pub fn table_name(&self) -> &str {
+        mapped_name(self.decorators()).unwrap_or(self.identifier().name())
+    }
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -115,7 +145,8 @@ impl InfoProvider for Model {
 
 impl Write for Model {
     fn write<'a>(&'a self, writer: &mut Writer<'a>) {
-        writer.write_children(self, self.children.values());
+        // copied fields are written by the models and interfaces they are copied from
+        writer.write_children(self, self.children.values().filter(|node| node.as_field().map_or(true, |field| !self.is_mixin_field(field))));
     }
 
     fn is_block_level_element(&self) -> bool {
@@ -37,27 +37,27 @@ impl Namespace {
     }
 
     pub fn get_connector(&self) -> Option<&Config> {
-        self.references.connector.map(|id| self.children.get(&id).unwrap().as_config().unwrap())
+        self.references.connector.and_then(|id| self.children.get(&id).and_then(|node| node.as_config()))
     }
 
     fn get_enum(&self, id: usize) -> Option<&Enum> {
-        self.children.get(&id).unwrap().as_enum()
+        self.children.get(&id).and_then(|node| node.as_enum())
     }
 
     fn get_model(&self, id: usize) -> Option<&Model> {
-        self.children.get(&id).unwrap().as_model()
+        self.children.get(&id).and_then(|node| node.as_model())
     }
 
     pub fn get_namespace(&self, id: usize) -> Option<&Namespace> {
-        self.children.get(&id).unwrap().as_namespace()
+        self.children.get(&id).and_then(|node| node.as_namespace())
     }
 
     fn get_data_set(&self, id: usize) -> Option<&DataSet> {
-        self.children.get(&id).unwrap().as_data_set()
+        self.children.get(&id).and_then(|node| node.as_data_set())
     }
 
     fn get_handler_group(&self, id: usize) -> Option<&HandlerGroupDeclaration> {
-        self.children.get(&id).unwrap().as_handler_group_declaration()
+        self.children.get(&id).and_then(|node| node.as_handler_group_declaration())
     }
 
     pub fn models(&self) -> Vec<&Model> {
@@ -69,7 +69,7 @@ impl Namespace {
     }
 
     pub fn handler_groups(&self) -> Vec<&HandlerGroupDeclaration> {
-        self.references.namespaces.iter().map(|m| self.get_handler_group(*m).unwrap()).collect()
+        self.references.handler_groups.iter().map(|m| self.get_handler_group(*m).unwrap()).collect()
     }
 
     pub fn namespaces(&self) -> Vec<&Namespace> {
@@ -132,7 +132,7 @@ impl Namespace {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct NamespaceReferences {
     pub constants: BTreeSet<usize>,
     pub connector: Option<usize>,
@@ -154,6 +154,7 @@ pub struct NamespaceReferences {
     pub unattached_decorators: BTreeSet<usize>,
     pub synthesized_shape_declarations: BTreeSet<usize>,
     pub handler_template_declarations: BTreeSet<usize>,
+    pub type_alias_declarations: BTreeSet<usize>,
 }
 
 impl NamespaceReferences {
@@ -180,6 +181,7 @@ impl NamespaceReferences {
             unattached_decorators: btreeset! {},
             synthesized_shape_declarations: btreeset! {},
             handler_template_declarations: btreeset! {},
+            type_alias_declarations: btreeset! {},
         }
     }
 }
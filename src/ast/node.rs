@@ -4,8 +4,9 @@ use crate::ast::argument::Argument;
 use crate::ast::argument_declaration::{ArgumentDeclaration};
 use crate::ast::argument_list::ArgumentList;
 use crate::ast::argument_list_declaration::ArgumentListDeclaration;
-use crate::ast::arith_expr::{ArithExpr, BinaryOperation, UnaryOperation, UnaryPostfixOperation};
+use crate::ast::arith_expr::{ArithExpr, BinaryOperation, TernaryOperation, UnaryOperation, UnaryPostfixOperation};
 use crate::ast::availability_flag::AvailabilityFlag;
+use crate::ast::availability_flag_else::AvailabilityFlagElse;
 use crate::ast::availability_flag_end::AvailabilityFlagEnd;
 use crate::ast::bracket_expression::BracketExpression;
 use crate::ast::code_comment::CodeComment;
@@ -33,7 +34,7 @@ use crate::ast::import::Import;
 use crate::ast::include_handler_from_template::IncludeHandlerFromTemplate;
 use crate::ast::int_subscript::IntSubscript;
 use crate::ast::interface::InterfaceDeclaration;
-use crate::ast::literals::{ArrayLiteral, BoolLiteral, DictionaryLiteral, EnumVariantLiteral, NullLiteral, NumericLiteral, RegexLiteral, StringLiteral, TupleLiteral};
+use crate::ast::literals::{ArrayLiteral, BoolLiteral, DictionaryLiteral, EnumVariantLiteral, NullLiteral, NumericLiteral, RegexLiteral, StringLiteral, TupleLiteral, DateLiteral};
 use crate::ast::middleware::MiddlewareDeclaration;
 use crate::ast::model::Model;
 use crate::ast::named_expression::NamedExpression;
@@ -51,6 +52,7 @@ use crate::ast::struct_declaration::StructDeclaration;
 use crate::ast::subscript::Subscript;
 use crate::ast::synthesized_shape_declaration::SynthesizedShapeDeclaration;
 use crate::ast::synthesized_shape_field_declaration::SynthesizedShapeFieldDeclaration;
+use crate::ast::type_alias_declaration::TypeAliasDeclaration;
 use crate::ast::type_as_value_expression::TypeAsValueExpression;
 use crate::ast::type_expr::{TypeBinaryOperation, TypedEnum, TypedShape, TypedShapeItem, TypeExpr, TypeGenerics, TypeGroup, TypeItem, TypeSubscript, TypeTuple};
 use crate::ast::unit::Unit;
@@ -63,7 +65,7 @@ use crate::traits::named_identifiable::NamedIdentifiable;
 use crate::traits::node_trait::NodeTrait;
 use crate::traits::write::Write;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Node {
     Argument(Argument),
     ArgumentList(ArgumentList),
@@ -73,7 +75,9 @@ pub enum Node {
     UnaryOperation(UnaryOperation),
     UnaryPostfixOperation(UnaryPostfixOperation),
     BinaryOperation(BinaryOperation),
+    TernaryOperation(TernaryOperation),
     AvailabilityFlag(AvailabilityFlag),
+    AvailabilityFlagElse(AvailabilityFlagElse),
     AvailabilityFlagEnd(AvailabilityFlagEnd),
     CodeComment(CodeComment),
     DocComment(DocComment),
@@ -96,6 +100,7 @@ pub enum Node {
     NumericLiteral(NumericLiteral),
     StringLiteral(StringLiteral),
     RegexLiteral(RegexLiteral),
+    DateLiteral(DateLiteral),
     BoolLiteral(BoolLiteral),
     NullLiteral(NullLiteral),
     EnumVariantLiteral(EnumVariantLiteral),
@@ -147,6 +152,7 @@ pub enum Node {
     HandlerTemplateDeclaration(HandlerTemplateDeclaration),
     IncludeHandlerFromTemplate(IncludeHandlerFromTemplate),
     TypeAsValueExpression(TypeAsValueExpression),
+    TypeAliasDeclaration(TypeAliasDeclaration),
 }
 
 impl Node {
@@ -239,6 +245,17 @@ impl Node {
         }
     }
     
+    pub fn is_ternary_operation(&self) -> bool {
+        self.as_ternary_operation().is_some()
+    }
+
+    pub fn as_ternary_operation(&self) -> Option<&TernaryOperation> {
+        match self {
+            Node::TernaryOperation(c) => Some(c),
+            _ => None,
+        }
+    }
+    
     pub fn is_availability_flag(&self) -> bool {
         self.as_availability_flag().is_some()
     }
@@ -250,6 +267,17 @@ impl Node {
         }
     }
     
+    pub fn is_availability_flag_else(&self) -> bool {
+        self.as_availability_flag_else().is_some()
+    }
+
+    pub fn as_availability_flag_else(&self) -> Option<&AvailabilityFlagElse> {
+        match self {
+            Node::AvailabilityFlagElse(c) => Some(c),
+            _ => None,
+        }
+    }
+    
     pub fn is_availability_flag_end(&self) -> bool {
         self.as_availability_flag_end().is_some()
     }
@@ -492,6 +520,17 @@ impl Node {
         }
     }
 
+    pub fn is_date_literal(&self) -> bool {
+        self.as_date_literal().is_some()
+    }
+
+    pub fn as_date_literal(&self) -> Option<&DateLiteral> {
+        match self {
+            Node::DateLiteral(c) => Some(c),
+            _ => None,
+        }
+    }
+
     pub fn is_bool_literal(&self) -> bool {
         self.as_bool_literal().is_some()
     }
@@ -1020,6 +1059,17 @@ impl Node {
         }
     }
 
+    pub fn is_type_alias_declaration(&self) -> bool {
+        self.as_type_alias_declaration().is_some()
+    }
+
+    pub fn as_type_alias_declaration(&self) -> Option<&TypeAliasDeclaration> {
+        match self {
+            Node::TypeAliasDeclaration(c) => Some(c),
+            _ => None,
+        }
+    }
+
     pub fn is_handler_template_declaration(&self) -> bool {
         self.as_handler_template_declaration().is_some()
     }
@@ -1063,7 +1113,9 @@ impl Node {
             Node::UnaryOperation(n) => n,
             Node::UnaryPostfixOperation(n) => n,
             Node::BinaryOperation(n) => n,
+            Node::TernaryOperation(n) => n,
             Node::AvailabilityFlag(n) => n,
+            Node::AvailabilityFlagElse(n) => n,
             Node::AvailabilityFlagEnd(n) => n,
             Node::CodeComment(n) => n,
             Node::DocComment(n) => n,
@@ -1086,6 +1138,7 @@ impl Node {
             Node::NumericLiteral(n) => n,
             Node::StringLiteral(n) => n,
             Node::RegexLiteral(n) => n,
+            Node::DateLiteral(n) => n,
             Node::BoolLiteral(n) => n,
             Node::NullLiteral(n) => n,
             Node::EnumVariantLiteral(n) => n,
@@ -1137,6 +1190,7 @@ impl Node {
             Node::HandlerTemplateDeclaration(n) => n,
             Node::IncludeHandlerFromTemplate(n) => n,
             Node::TypeAsValueExpression(n) => n,
+            Node::TypeAliasDeclaration(n) => n,
         }
     }
 
@@ -1156,6 +1210,8 @@ impl Node {
             Node::PipelineItemDeclaration(p) => Some(p.identifier().span()),
             Node::StructDeclaration(s) => Some(s.identifier().span()),
             Node::SynthesizedShapeDeclaration(s) => Some(s.identifier().span()),
+            Node::TypeAliasDeclaration(t) => Some(t.identifier().span()),
+            Node::Import(i) => i.as_identifier().map(|identifier| identifier.span()),
             _ => None,
         }
     }
@@ -1171,6 +1227,7 @@ impl Node {
             Node::PipelineItemDeclaration(t) => t.define_availability().contains(availability),
             Node::StructDeclaration(t) => t.define_availability().contains(availability),
             Node::SynthesizedShapeDeclaration(s) => s.define_availability().contains(availability),
+            Node::TypeAliasDeclaration(t) => t.define_availability().contains(availability),
             _ => true,
         }
     }
@@ -1191,6 +1248,7 @@ impl Node {
             Node::PipelineItemDeclaration(p) => Some(p.string_path()),
             Node::StructDeclaration(s) => Some(s.string_path()),
             Node::SynthesizedShapeDeclaration(s) => Some(s.string_path()),
+            Node::TypeAliasDeclaration(t) => Some(t.string_path()),
             _ => None,
         }
     }
@@ -1211,6 +1269,8 @@ impl Node {
             Node::PipelineItemDeclaration(p) => Some(p.str_path()),
             Node::StructDeclaration(s) => Some(s.str_path()),
             Node::SynthesizedShapeDeclaration(s) => Some(s.str_path()),
+            Node::TypeAliasDeclaration(t) => Some(t.str_path()),
+            Node::Import(i) => i.alias().map(|alias| vec![alias]),
             _ => None,
         }
     }
@@ -1231,8 +1291,35 @@ impl Node {
             Node::PipelineItemDeclaration(p) => Some(p.identifier().name()),
             Node::StructDeclaration(s) => Some(s.identifier().name()),
             Node::SynthesizedShapeDeclaration(s) => Some(s.identifier().name()),
+            Node::TypeAliasDeclaration(t) => Some(t.identifier().name()),
             Node::HandlerDeclaration(h) => Some(h.identifier().name()),
             Node::HandlerTemplateDeclaration(h) => Some(h.identifier().name()),
+            Node::Import(i) => i.alias(),
+            _ => None,
+        }
+    }
+
+    pub fn comment(&self) -> Option<&DocComment> {
+        match self {
+            Node::ConstantDeclaration(c) => c.comment(),
+            Node::Enum(e) => e.comment(),
+            Node::EnumMember(m) => m.comment(),
+            Node::Model(m) => m.comment(),
+            Node::Field(f) => f.comment(),
+            Node::ConfigDeclaration(c) => c.comment(),
+            Node::DataSet(d) => d.comment(),
+            Node::MiddlewareDeclaration(m) => m.comment(),
+            Node::HandlerGroupDeclaration(h) => h.comment(),
+            Node::HandlerDeclaration(h) => h.comment(),
+            Node::HandlerTemplateDeclaration(h) => h.comment(),
+            Node::InterfaceDeclaration(i) => i.comment(),
+            Node::Namespace(n) => n.comment(),
+            Node::DecoratorDeclaration(d) => d.comment(),
+            Node::PipelineItemDeclaration(p) => p.comment(),
+            Node::StructDeclaration(s) => s.comment(),
+            Node::FunctionDeclaration(f) => f.comment(),
+            Node::SynthesizedShapeDeclaration(s) => s.comment(),
+            Node::TypeAliasDeclaration(t) => t.comment(),
             _ => None,
         }
     }
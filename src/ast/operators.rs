@@ -31,21 +31,21 @@ impl Write for Operator {
 
     fn prefer_whitespace_before(&self) -> bool {
         match self.content() {
-            "!" | "?" | ".." | "..." => false,
+            "!" | ".." | "..." => false,
             _ => true,
         }
     }
 
     fn prefer_whitespace_after(&self) -> bool {
         match self.content() {
-            "!" | "?" | ".." | "..." => false,
+            "!" | ".." | "..." => false,
             _ => true,
         }
     }
 
     fn prefer_always_no_whitespace_before(&self) -> bool {
         match self.content() {
-            "!" | "?" | ".." | "..." => true,
+            "!" | ".." | "..." => true,
             _ => false,
         }
     }
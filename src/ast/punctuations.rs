@@ -30,14 +30,14 @@ impl Write for Punctuation {
 
     fn prefer_whitespace_before(&self) -> bool {
         match self.content() {
-            "@" | "}" | "{" | "->" => true,
+            "@" | "}" | "{" | "->" | "=" => true,
             _ => false,
         }
     }
 
     fn prefer_whitespace_after(&self) -> bool {
         match self.content() {
-            "," | ":" | "{" | "->" => true,
+            "," | ":" | "{" | "->" | "=" => true,
             _ => false,
         }
     }
@@ -1,4 +1,5 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
 use crate::availability::Availability;
 use crate::ast::config::Config;
 use crate::ast::config_declaration::ConfigDeclaration;
@@ -6,6 +7,7 @@ use crate::ast::data_set::DataSet;
 use crate::ast::decorator_declaration::DecoratorDeclaration;
 use crate::ast::handler::{HandlerDeclaration, HandlerGroupDeclaration};
 use crate::ast::handler_template_declaration::HandlerTemplateDeclaration;
+use crate::ast::import::Import;
 use crate::ast::interface::InterfaceDeclaration;
 use crate::ast::middleware::MiddlewareDeclaration;
 use crate::ast::model::Model;
@@ -16,16 +18,51 @@ use crate::ast::r#enum::Enum;
 use crate::ast::source::Source;
 use crate::ast::struct_declaration::StructDeclaration;
 use crate::ast::synthesized_shape_declaration::SynthesizedShapeDeclaration;
+use crate::ast::type_alias_declaration::TypeAliasDeclaration;
+use crate::diagnostics::diagnostics::Diagnostics;
+use crate::evaluator::evaluate_expression::evaluate_expression;
+use crate::resolver::resolver_context::ResolverState;
 use crate::traits::identifiable::Identifiable;
+use crate::traits::resolved::Resolve;
+use crate::utils::path::{SchemaFileSystem, UnsavedFileSystem};
+use crate::utils::environment::Environment;
+use crate::value::Value;
 
 #[derive(Debug)]
 pub struct Schema {
     pub sources: BTreeMap<usize, Source>,
     pub references: SchemaReferences,
+    pub(crate) last_id: usize,
+    pub(crate) parser_diagnostics: BTreeMap<usize, Diagnostics>,
+    pub(crate) file_system: Arc<UnsavedFileSystem>,
+    pub(crate) library_roots: Vec<String>,
+    pub(crate) profiles: BTreeSet<String>,
+    pub(crate) environment: Option<BTreeMap<String, String>>,
+    // where the environment is loaded from, a `.env` file is read again when it's changed
+    pub(crate) environment_origin: Option<Environment>,
+    pub(crate) resolver_state: ResolverState,
 }
 
 impl Schema {
 
+    /// The file system the schema is parsed from, with the unsaved files laid over it.
+    pub fn file_system(&self) -> &dyn SchemaFileSystem {
+        self.file_system.as_ref()
+    }
+
+    pub fn library_roots(&self) -> &Vec<String> {
+        &self.library_roots
+    }
+
+    pub fn profiles(&self) -> &BTreeSet<String> {
+        &self.profiles
+    }
+
+    /// The injected environment variables, `None` if the environment is unknown.
+    pub fn environment(&self) -> Option<&BTreeMap<String, String>> {
+        self.environment.as_ref()
+    }
+
     pub fn main_source(&self) -> &Source {
         self.source(self.references.main_source.unwrap()).unwrap()
     }
@@ -38,6 +75,26 @@ impl Schema {
         self.sources().iter().find_map(|s| if s.file_path.as_str() == path { Some(*s) } else { None })
     }
 
+    pub fn import_sources(&self, import: &Import) -> Vec<&Source> {
+        import.file_paths.iter().filter_map(|path| self.source_at_path(path)).collect()
+    }
+
+    /// The file dependency graph of the user schema. Each user source file maps to the files
+    /// it imports, in import order.
+    pub fn import_graph(&self) -> BTreeMap<String, Vec<String>> {
+        self.user_sources().iter().map(|source| {
+            let mut imported: Vec<String> = vec![];
+            for import in source.imports() {
+                for file_path in &import.file_paths {
+                    if !imported.contains(file_path) {
+                        imported.push(file_path.clone());
+                    }
+                }
+            }
+            (source.file_path.clone(), imported)
+        }).collect()
+    }
+
     pub fn builtin_sources(&self) -> Vec<&Source> {
         self.references.builtin_sources.iter().map(|id| self.source(*id).unwrap()).collect()
     }
@@ -67,11 +124,7 @@ impl Schema {
         if path.len() < 2 {
             return None;
         }
-        if let Some(source) = self.source(*path.get(0).unwrap()) {
-            source.find_top_by_path(path)
-        } else {
-            None
-        }
+        self.source(*path.first()?)?.find_top_by_path(path)
     }
 
     // Public APIs
@@ -112,7 +165,16 @@ impl Schema {
         self.references.enums.iter().map(|path| self.find_top_by_path(path).unwrap().as_enum().unwrap()).collect()
     }
 
+    /// The models of the schema, without the abstract ones.
     pub fn models(&self) -> Vec<&Model> {
+        self.all_models().into_iter().filter(|model| !model.is_abstract()).collect()
+    }
+
+    pub fn abstract_models(&self) -> Vec<&Model> {
+        self.all_models().into_iter().filter(|model| model.is_abstract()).collect()
+    }
+
+    fn all_models(&self) -> Vec<&Model> {
         self.references.models.iter().map(|path| self.find_top_by_path(path).unwrap().as_model().unwrap()).collect()
     }
 
@@ -163,6 +225,24 @@ impl Schema {
     pub fn declared_shapes(&self) -> Vec<&SynthesizedShapeDeclaration> {
         self.references.declared_shapes.iter().map(|path| self.find_top_by_path(path).unwrap().as_synthesized_shape_declaration().unwrap()).collect()
     }
+
+    pub fn type_alias_declarations(&self) -> Vec<&TypeAliasDeclaration> {
+        self.references.type_aliases.iter().map(|path| self.find_top_by_path(path).unwrap().as_type_alias_declaration().unwrap()).collect()
+    }
+
+    /// The compile time value of the constant at the namespace path, `None` if the value is only
+    /// known at runtime.
+    pub fn evaluate_constant(&self, path: &Vec<&str>) -> Option<Value> {
+        let filter: Arc<dyn Fn(&Node) -> bool> = Arc::new(|node: &Node| node.is_constant_declaration());
+        let constant = self.user_sources().into_iter().find_map(|source| {
+            source.find_node_by_string_path(path, &filter, Availability::default())
+        })?.as_constant_declaration()?;
+        if constant.is_resolved() {
+            constant.resolved().value.clone()
+        } else {
+            evaluate_expression(constant.expression())
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -191,7 +271,10 @@ pub struct SchemaReferences {
     pub handler_groups: Vec<Vec<usize>>,
     pub struct_declarations: Vec<Vec<usize>>,
     pub declared_shapes: Vec<Vec<usize>>,
+    pub type_aliases: Vec<Vec<usize>>,
     pub use_middlewares_blocks: Vec<Vec<usize>>,
+    /// Conditional blocks depending on environment variables.
+    pub environment_flags: Vec<Vec<usize>>,
 }
 
 impl SchemaReferences {
@@ -222,7 +305,9 @@ impl SchemaReferences {
             handler_groups: vec![],
             struct_declarations: vec![],
             declared_shapes: vec![],
+            type_aliases: vec![],
             use_middlewares_blocks: vec![],
+            environment_flags: vec![],
         }
     }
 
@@ -242,4 +327,45 @@ impl SchemaReferences {
             self.admin = Some(config.path().clone());
         }
     }
+
+    /// Take over the references of a source from the references of a previous parse.
+    pub(crate) fn add_source_references(&mut self, previous: &SchemaReferences, source_id: usize) {
+        let in_source = |path: &Vec<usize>| path.first() == Some(&source_id);
+        let copy = |to: &mut Vec<Vec<usize>>, from: &Vec<Vec<usize>>| to.extend(from.iter().filter(|path| in_source(path)).cloned());
+        copy(&mut self.configs, &previous.configs);
+        copy(&mut self.connectors, &previous.connectors);
+        copy(&mut self.entities, &previous.entities);
+        copy(&mut self.clients, &previous.clients);
+        copy(&mut self.enums, &previous.enums);
+        copy(&mut self.models, &previous.models);
+        copy(&mut self.data_sets, &previous.data_sets);
+        copy(&mut self.interfaces, &previous.interfaces);
+        copy(&mut self.namespaces, &previous.namespaces);
+        copy(&mut self.config_declarations, &previous.config_declarations);
+        copy(&mut self.decorator_declarations, &previous.decorator_declarations);
+        copy(&mut self.pipeline_item_declarations, &previous.pipeline_item_declarations);
+        copy(&mut self.middlewares, &previous.middlewares);
+        copy(&mut self.handlers, &previous.handlers);
+        copy(&mut self.handler_templates, &previous.handler_templates);
+        copy(&mut self.handler_groups, &previous.handler_groups);
+        copy(&mut self.struct_declarations, &previous.struct_declarations);
+        copy(&mut self.declared_shapes, &previous.declared_shapes);
+        copy(&mut self.type_aliases, &previous.type_aliases);
+        copy(&mut self.use_middlewares_blocks, &previous.use_middlewares_blocks);
+        copy(&mut self.environment_flags, &previous.environment_flags);
+        if let Some(server) = previous.server.as_ref().filter(|path| in_source(path)) {
+            self.server = Some(server.clone());
+        }
+        if let Some(debug) = previous.debug.as_ref().filter(|path| in_source(path)) {
+            self.debug = Some(debug.clone());
+        }
+        if let Some(admin) = previous.admin.as_ref().filter(|path| in_source(path)) {
+            self.admin = Some(admin.clone());
+        }
+        if previous.builtin_sources.contains(&source_id) {
+            self.builtin_sources.push(source_id);
+        } else {
+            self.user_sources.push(source_id);
+        }
+    }
 }
\ No newline at end of file
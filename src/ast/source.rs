@@ -8,6 +8,7 @@ use crate::ast::empty_decorator::EmptyDecorator;
 use crate::ast::import::Import;
 use crate::ast::namespace::Namespace;
 use crate::ast::node::Node;
+use crate::ast::span::Span;
 use crate::format::Writer;
 use crate::traits::write::Write;
 
@@ -18,17 +19,20 @@ pub struct Source {
     pub file_path: String,
     pub children: BTreeMap<usize, Node>,
     pub references: SourceReferences,
+    /// Inactive profile and environment blocks. Their nodes are kept but unavailable.
+    pub inactive_spans: Vec<Span>,
 }
 
 impl Source {
 
-    pub fn new(id: usize, builtin: bool, file_path: String, children: BTreeMap<usize, Node>, references: SourceReferences) -> Self {
+    pub fn new(id: usize, builtin: bool, file_path: String, children: BTreeMap<usize, Node>, references: SourceReferences, inactive_spans: Vec<Span>) -> Self {
         Self {
             id,
             builtin,
             file_path,
             children,
             references,
+            inactive_spans,
         }
     }
 
@@ -53,15 +57,15 @@ impl Source {
     }
 
     pub fn get_connector(&self) -> Option<&Config> {
-        self.references.connector.map(|id| self.children.get(&id).unwrap().as_config().unwrap())
+        self.references.connector.and_then(|id| self.children.get(&id).and_then(|node| node.as_config()))
     }
 
     pub fn get_namespace(&self, id: usize) -> Option<&Namespace> {
-        self.children.get(&id).unwrap().as_namespace()
+        self.children.get(&id).and_then(|node| node.as_namespace())
     }
 
     pub fn get_unattached_decorator(&self, id: usize) -> Option<&Decorator> {
-        self.children.get(&id).unwrap().as_decorator()
+        self.children.get(&id).and_then(|node| node.as_decorator())
     }
 
     pub fn find_top_by_id(&self, id: usize) -> Option<&Node> {
@@ -79,7 +83,7 @@ impl Source {
     }
 
     pub fn find_top_by_path(&self, path: &Vec<usize>) -> Option<&Node> {
-        if *path.first().unwrap() != self.id {
+        if path.first() != Some(&self.id) {
             return None;
         }
         if path.len() < 2 {
@@ -120,10 +124,10 @@ impl Source {
     }
 
     pub fn find_child_namespace_by_path(&self, path: &Vec<usize>) -> Option<&Namespace> {
-        if *path.first().unwrap() != self.id {
+        if path.first() != Some(&self.id) {
             return None;
         }
-        let mut ns = self.get_namespace(*path.get(1).unwrap());
+        let mut ns = self.get_namespace(*path.get(1)?);
         for (index, item) in path.iter().enumerate() {
             if index > 1 {
                 if let Some(ns_ref) = ns {
@@ -174,6 +178,7 @@ pub struct SourceReferences {
     pub unattached_decorators: BTreeSet<usize>,
     pub synthesized_shape_declarations: BTreeSet<usize>,
     pub handler_template_declarations: BTreeSet<usize>,
+    pub type_alias_declarations: BTreeSet<usize>,
 }
 
 impl SourceReferences {
@@ -200,6 +205,7 @@ impl SourceReferences {
             unattached_decorators: btreeset! {},
             synthesized_shape_declarations: btreeset! {},
             handler_template_declarations: btreeset! {},
+            type_alias_declarations: btreeset! {},
         }
     }
 }
@@ -59,7 +59,7 @@ impl Write for SynthesizedShapeDeclaration {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SynthesizedShapeDeclarationResolved {
     pub base_shape: IndexMap<String, Type>,
 }
\ No newline at end of file
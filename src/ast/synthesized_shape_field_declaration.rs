@@ -34,7 +34,7 @@ impl Resolve<SynthesizedShapeFieldDeclarationResolved> for SynthesizedShapeField
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SynthesizedShapeFieldDeclarationResolved {
     pub decorator_full_path: Option<Vec<String>>,
 }
\ No newline at end of file
@@ -0,0 +1,72 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use maplit::btreemap;
+use crate::ast::doc_comment::DocComment;
+use crate::ast::generics::{GenericsConstraint, GenericsDeclaration};
+use crate::ast::identifier::Identifier;
+use crate::ast::type_expr::TypeExpr;
+use crate::{declare_container_node, impl_container_node_defaults, node_child_fn, node_optional_child_fn};
+use crate::format::Writer;
+use crate::r#type::Type;
+use crate::traits::info_provider::InfoProvider;
+use crate::traits::resolved::Resolve;
+use crate::traits::write::Write;
+
+declare_container_node!(TypeAliasDeclaration, named, availability,
+    pub(crate) comment: Option<usize>,
+    pub(crate) identifier: usize,
+    pub(crate) generics_declaration: Option<usize>,
+    pub(crate) generics_constraint: Option<usize>,
+    pub(crate) type_expr: usize,
+    pub(crate) resolved: RefCell<Option<Type>>,
+);
+
+impl_container_node_defaults!(TypeAliasDeclaration, named, availability);
+
+impl TypeAliasDeclaration {
+
+    node_optional_child_fn!(comment, DocComment);
+
+    node_child_fn!(identifier, Identifier);
+
+    node_optional_child_fn!(generics_declaration, GenericsDeclaration);
+
+    node_optional_child_fn!(generics_constraint, GenericsConstraint);
+
+    node_child_fn!(type_expr, TypeExpr);
+
+    pub fn calculate_generics_map(&self, types: &Vec<Type>) -> BTreeMap<String, Type> {
+        if let Some(generics_declaration) = self.generics_declaration() {
+            generics_declaration.calculate_generics_map(types)
+        } else {
+            btreemap!{}
+        }
+    }
+
+    /// The aliased type with the generics of this declaration replaced by `types`.
+    pub fn type_from_generics(&self, types: &Vec<Type>) -> Type {
+        self.resolved().replace_generics(&self.calculate_generics_map(types))
+    }
+}
+
+impl InfoProvider for TypeAliasDeclaration {
+    fn namespace_skip(&self) -> usize {
+        1
+    }
+}
+
+impl Resolve<Type> for TypeAliasDeclaration {
+    fn resolved_ref_cell(&self) -> &RefCell<Option<Type>> {
+        &self.resolved
+    }
+}
+
+impl Write for TypeAliasDeclaration {
+    fn write<'a>(&'a self, writer: &mut Writer<'a>) {
+        writer.write_children(self, self.children.values());
+    }
+
+    fn is_block_level_element(&self) -> bool {
+        true
+    }
+}
@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
 use crate::ast::arity::Arity;
 use crate::ast::identifier_path::IdentifierPath;
-use crate::ast::literals::EnumVariantLiteral;
+use crate::ast::literals::{BoolLiteral, EnumVariantLiteral, NumericLiteral, StringLiteral};
 use crate::ast::span::Span;
 use crate::{declare_container_node, impl_container_node_defaults, impl_node_defaults, node_child_fn, node_children_iter, node_children_iter_fn, node_optional_child_fn};
 use crate::ast::identifier::Identifier;
@@ -223,7 +223,7 @@ impl Write for TypedShape {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum TypeExprKind {
     Expr(Box<TypeExprKind>),
     BinaryOp(TypeBinaryOperation),
@@ -234,6 +234,9 @@ pub enum TypeExprKind {
     FieldName(EnumVariantLiteral),
     TypedEnum(TypedEnum),
     TypedShape(TypedShape),
+    StringLiteral(StringLiteral),
+    NumericLiteral(NumericLiteral),
+    BoolLiteral(BoolLiteral),
 }
 
 impl TypeExprKind {
@@ -249,6 +252,9 @@ impl TypeExprKind {
             TypeExprKind::FieldName(n) => n,
             TypeExprKind::TypedEnum(n) => n,
             TypeExprKind::TypedShape(n) => n,
+            TypeExprKind::StringLiteral(n) => n,
+            TypeExprKind::NumericLiteral(n) => n,
+            TypeExprKind::BoolLiteral(n) => n,
         }
     }
 
@@ -281,7 +287,7 @@ impl NodeTrait for TypeExprKind {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TypeExpr {
     pub kind: TypeExprKind,
     pub resolved: RefCell<Option<Type>>,
@@ -7,6 +7,7 @@ use crate::traits::write::Write;
 declare_container_node!(Unit,
     pub(crate) expressions: Vec<usize>,
     pub(crate) empty_dot: Option<usize>,
+    pub(crate) optional_chained: Vec<usize>,
 );
 
 impl_container_node_defaults!(Unit);
@@ -23,6 +24,11 @@ impl Unit {
         self.expressions.get(idx).map(|idx| self.children.get(idx)).flatten().map(|n| n.as_expression()).flatten()
     }
 
+    /// Whether the expression at `idx` is accessed with `?.`.
+    pub fn is_optional_chained_at(&self, idx: usize) -> bool {
+        self.expressions.get(idx).map_or(false, |id| self.optional_chained.contains(id))
+    }
+
     pub fn last_expression(&self) -> Option<&Expression> {
         self.expressions.last().map(|idx| self.children.get(idx)).flatten().map(|n| n.as_expression()).flatten()
     }
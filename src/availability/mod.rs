@@ -59,6 +59,14 @@ impl Availability {
     pub fn bi_and(&self, other: Availability) -> Availability {
         Self(self.0 & other.0)
     }
+
+    pub fn bi_or(&self, other: Availability) -> Availability {
+        Self(self.0 | other.0)
+    }
+
+    pub fn bi_not(&self) -> Availability {
+        Self(!self.0 & ALL)
+    }
 }
 
 impl Default for Availability {
@@ -14,6 +14,7 @@ pub(super) fn collect_reference_completion_in_source(schema: &Schema, source: &S
 fn collect_reference_completion_in_source_internal<'a>(schema: &'a Schema, source: &'a Source, namespace_path: &Vec<&str>, user_typed_prefix: &Vec<&str>, filter: &Arc<dyn Fn(&Node) -> bool>, examined_sources: &mut Vec<&'a str>, availability: Availability) -> Vec<Vec<usize>> {
     examined_sources.push(&source.file_path);
     let mut result = vec![];
+    let mut aliased = vec![];
     let mut namespace_path_mut = namespace_path.clone();
     loop {
         let mut combined = namespace_path_mut.clone();
@@ -34,9 +35,35 @@ fn collect_reference_completion_in_source_internal<'a>(schema: &'a Schema, sourc
                 }
             }
         } else if let Some(import) = top.as_import() {
-            if !examined_sources.contains(&import.file_path.as_str()) {
-                if let Some(source) = schema.source_at_path(import.file_path.as_str()) {
-                    result.extend(collect_reference_completion_in_source_internal(schema, source, namespace_path, user_typed_prefix, filter, examined_sources, availability));
+            if let Some(alias) = import.alias() {
+                // aliased imports are only reachable through the alias
+                if user_typed_prefix.is_empty() {
+                    if schema.import_sources(import).iter().any(|source| source.children().iter().any(|t| filter(t))) {
+                        result.push(import.path.clone());
+                    }
+                } else if user_typed_prefix.first() == Some(&alias) {
+                    for source in schema.import_sources(import) {
+                        if !examined_sources.contains(&source.file_path.as_str()) {
+                            aliased.extend(collect_reference_completion_in_source_internal(schema, source, &vec![], &user_typed_prefix[1..].to_vec(), filter, examined_sources, availability).into_iter().filter(|path| {
+                                !schema.source(*path.first().unwrap()).unwrap().builtin
+                            }));
+                        }
+                    }
+                }
+            } else {
+                for source in schema.import_sources(import) {
+                    if !examined_sources.contains(&source.file_path.as_str()) {
+                        let imported = collect_reference_completion_in_source_internal(schema, source, namespace_path, user_typed_prefix, filter, examined_sources, availability);
+                        if let Some(first) = user_typed_prefix.first() {
+                            if import.imports_name(first) {
+                                result.extend(imported);
+                            }
+                        } else {
+                            result.extend(imported.into_iter().filter(|path| {
+                                schema.find_top_by_path(path).and_then(|top| top.name()).map_or(false, |name| import.imports_name(name))
+                            }));
+                        }
+                    }
                 }
             }
         } else {
@@ -57,6 +84,14 @@ fn collect_reference_completion_in_source_internal<'a>(schema: &'a Schema, sourc
             }
         }
     }
+    if !user_typed_prefix.is_empty() {
+        let user_typed = user_typed_prefix.join(".");
+        result.retain(|path| {
+            let actual = schema.find_top_by_path(path).unwrap().str_path().unwrap().join(".");
+            actual.starts_with(&user_typed) && actual != user_typed
+        });
+    }
+    result.extend(aliased);
     result
 }
 
@@ -6,6 +6,7 @@ use crate::ast::data_set::DataSet;
 use crate::ast::decorator_declaration::DecoratorDeclaration;
 use crate::ast::field::Field;
 use crate::ast::handler::HandlerGroupDeclaration;
+use crate::ast::import::Import;
 use crate::ast::interface::InterfaceDeclaration;
 use crate::ast::middleware::MiddlewareDeclaration;
 use crate::ast::model::Model;
@@ -15,13 +16,15 @@ use crate::ast::pipeline_item_declaration::PipelineItemDeclaration;
 use crate::ast::r#enum::Enum;
 use crate::ast::struct_declaration::StructDeclaration;
 use crate::ast::synthesized_shape_declaration::SynthesizedShapeDeclaration;
+use crate::ast::type_alias_declaration::TypeAliasDeclaration;
+use crate::utils::identifier::escape_identifier;
 use crate::completion::completion_item::CompletionItem;
 use crate::traits::named_identifiable::NamedIdentifiable;
 use crate::utils::output::readable_namespace_path;
 
 pub(super) fn completion_item_from_top(top: &Node) -> CompletionItem {
     match top {
-        Node::Import(_) => unreachable!(),
+        Node::Import(i) => completion_item_from_import(i),
         Node::Config(c) => completion_item_from_config(c),
         Node::ConfigDeclaration(c) => completion_item_from_config_declaration(c),
         Node::ConstantDeclaration(c) => completion_item_from_constant(c),
@@ -37,28 +40,54 @@ pub(super) fn completion_item_from_top(top: &Node) -> CompletionItem {
         Node::StructDeclaration(s) => completion_item_from_struct_declaration(s),
         Node::UseMiddlewaresBlock(_) => unreachable!(),
         Node::SynthesizedShapeDeclaration(s) => completion_item_from_synthesized_shape_declaration(s),
+        Node::TypeAliasDeclaration(t) => completion_item_from_type_alias_declaration(t),
         _ => unreachable!(),
     }
 }
 
 pub(crate) fn documentation_from_comment(comment: Option<&DocComment>) -> Option<String> {
     comment.map(|c| {
-        format!("{}{}", c.name.as_ref().map_or("".to_owned(), |n| format!("**{}**\n", n)), c.desc.as_ref().map_or("", |s| s.as_str()))
+        let mut documentation = format!("{}{}", c.name.as_ref().map_or("".to_owned(), |n| format!("**{}**\n", n)), c.desc.as_ref().map_or("", |s| s.as_str()));
+        if let Some(deprecated) = c.deprecated() {
+            documentation.push_str(&format!("\n\n*Deprecated*{}", if deprecated.is_empty() { "".to_owned() } else { format!(": {}", deprecated) }));
+        }
+        for (name, desc) in c.params() {
+            documentation.push_str(&format!("\n\n`{}` {}", name, desc));
+        }
+        for example in c.examples() {
+            documentation.push_str(&format!("\n\n```teo\n{}\n```", example));
+        }
+        for see in c.see() {
+            documentation.push_str(&format!("\n\nSee {}", see));
+        }
+        if let Some(since) = c.since() {
+            documentation.push_str(&format!("\n\nSince {}", since));
+        }
+        documentation
     })
 }
 
 pub(super) fn completion_item_from_namespace(namespace: &Namespace) -> CompletionItem {
     CompletionItem {
-        label: namespace.identifier().name.clone(),
+        label: escape_identifier(namespace.identifier().name()),
         namespace_path: Some(readable_namespace_path(&namespace.string_path)),
         documentation: documentation_from_comment(namespace.comment()),
         detail: None,
     }
 }
 
+pub(super) fn completion_item_from_import(import: &Import) -> CompletionItem {
+    CompletionItem {
+        label: import.alias().map_or_else(|| import.source().display.clone(), |alias| alias.to_owned()),
+        namespace_path: None,
+        documentation: None,
+        detail: Some(import.file_paths.join(", ")),
+    }
+}
+
 pub(super) fn completion_item_from_decorator_declaration(decorator_declaration: &DecoratorDeclaration) -> CompletionItem {
     CompletionItem {
-        label: decorator_declaration.identifier().name.clone(),
+        label: escape_identifier(decorator_declaration.identifier().name()),
         namespace_path: Some(readable_namespace_path(&decorator_declaration.string_path)),
         documentation: documentation_from_comment(decorator_declaration.comment()),
         detail: None,
@@ -67,7 +96,7 @@ pub(super) fn completion_item_from_decorator_declaration(decorator_declaration:
 
 pub(super) fn completion_item_from_field(field: &Field) -> CompletionItem {
     CompletionItem {
-        label: field.identifier().name.clone(),
+        label: escape_identifier(field.identifier().name()),
         namespace_path: Some(readable_namespace_path(&field.string_path)),
         documentation: documentation_from_comment(field.comment()),
         detail: None,
@@ -85,7 +114,7 @@ pub(super) fn completion_item_from_config(config: &Config) -> CompletionItem {
 
 pub(super) fn completion_item_from_config_declaration(config_declaration: &ConfigDeclaration) -> CompletionItem {
     CompletionItem {
-        label: config_declaration.identifier().name.clone(),
+        label: escape_identifier(config_declaration.identifier().name()),
         namespace_path: Some(readable_namespace_path(&config_declaration.string_path)),
         documentation: documentation_from_comment(config_declaration.comment()),
         detail: None,
@@ -94,7 +123,7 @@ pub(super) fn completion_item_from_config_declaration(config_declaration: &Confi
 
 pub(super) fn completion_item_from_constant(constant: &ConstantDeclaration) -> CompletionItem {
     CompletionItem {
-        label: constant.identifier().name.clone(),
+        label: escape_identifier(constant.identifier().name()),
         namespace_path: Some(readable_namespace_path(&constant.string_path)),
         documentation: None,
         detail: None,
@@ -103,7 +132,7 @@ pub(super) fn completion_item_from_constant(constant: &ConstantDeclaration) -> C
 
 pub(super) fn completion_item_from_enum(e: &Enum) -> CompletionItem {
     CompletionItem {
-        label: e.identifier().name.clone(),
+        label: escape_identifier(e.identifier().name()),
         namespace_path: Some(readable_namespace_path(&e.string_path)),
         documentation: documentation_from_comment(e.comment()),
         detail: None,
@@ -112,7 +141,7 @@ pub(super) fn completion_item_from_enum(e: &Enum) -> CompletionItem {
 
 pub(super) fn completion_item_from_model(model: &Model) -> CompletionItem {
     CompletionItem {
-        label: model.identifier().name.clone(),
+        label: escape_identifier(model.identifier().name()),
         namespace_path: Some(readable_namespace_path(&model.string_path)),
         documentation: documentation_from_comment(model.comment()),
         detail: None,
@@ -121,7 +150,7 @@ pub(super) fn completion_item_from_model(model: &Model) -> CompletionItem {
 
 pub(super) fn completion_item_from_data_set(data_set: &DataSet) -> CompletionItem {
     CompletionItem {
-        label: data_set.identifier().name.clone(),
+        label: escape_identifier(data_set.identifier().name()),
         namespace_path: Some(readable_namespace_path(&data_set.string_path)),
         documentation: None,
         detail: None,
@@ -130,7 +159,7 @@ pub(super) fn completion_item_from_data_set(data_set: &DataSet) -> CompletionIte
 
 pub(super) fn completion_item_from_middleware(middleware: &MiddlewareDeclaration) -> CompletionItem {
     CompletionItem {
-        label: middleware.identifier().name.clone(),
+        label: escape_identifier(middleware.identifier().name()),
         namespace_path: Some(readable_namespace_path(&middleware.string_path)),
         documentation: None,
         detail: None,
@@ -139,7 +168,7 @@ pub(super) fn completion_item_from_middleware(middleware: &MiddlewareDeclaration
 
 pub(super) fn completion_item_from_handler_group(handler_group: &HandlerGroupDeclaration) -> CompletionItem {
     CompletionItem {
-        label: handler_group.identifier().name.clone(),
+        label: escape_identifier(handler_group.identifier().name()),
         namespace_path: Some(readable_namespace_path(&handler_group.string_path)),
         documentation: documentation_from_comment(handler_group.comment()),
         detail: None,
@@ -148,7 +177,7 @@ pub(super) fn completion_item_from_handler_group(handler_group: &HandlerGroupDec
 
 pub(super) fn completion_item_from_interface(interface_declaration: &InterfaceDeclaration) -> CompletionItem {
     CompletionItem {
-        label: interface_declaration.identifier().name.clone(),
+        label: escape_identifier(interface_declaration.identifier().name()),
         namespace_path: Some(readable_namespace_path(&interface_declaration.string_path)),
         documentation: documentation_from_comment(interface_declaration.comment()),
         detail: None,
@@ -157,7 +186,7 @@ pub(super) fn completion_item_from_interface(interface_declaration: &InterfaceDe
 
 pub(super) fn completion_item_from_pipeline_item_declaration(pipeline_item_declaration: &PipelineItemDeclaration) -> CompletionItem {
     CompletionItem {
-        label: pipeline_item_declaration.identifier().name.clone(),
+        label: escape_identifier(pipeline_item_declaration.identifier().name()),
         namespace_path: Some(readable_namespace_path(&pipeline_item_declaration.string_path)),
         documentation: documentation_from_comment(pipeline_item_declaration.comment()),
         detail: None,
@@ -166,7 +195,7 @@ pub(super) fn completion_item_from_pipeline_item_declaration(pipeline_item_decla
 
 pub(super) fn completion_item_from_struct_declaration(struct_declaration: &StructDeclaration) -> CompletionItem {
     CompletionItem {
-        label: struct_declaration.identifier().name.clone(),
+        label: escape_identifier(struct_declaration.identifier().name()),
         namespace_path: Some(readable_namespace_path(&struct_declaration.string_path)),
         documentation: documentation_from_comment(struct_declaration.comment()),
         detail: None,
@@ -175,7 +204,16 @@ pub(super) fn completion_item_from_struct_declaration(struct_declaration: &Struc
 
 pub(super) fn completion_item_from_synthesized_shape_declaration(declaration: &SynthesizedShapeDeclaration) -> CompletionItem {
     CompletionItem {
-        label: declaration.identifier().name.clone(),
+        label: escape_identifier(declaration.identifier().name()),
+        namespace_path: Some(readable_namespace_path(&declaration.string_path)),
+        documentation: documentation_from_comment(declaration.comment()),
+        detail: None,
+    }
+}
+
+pub(super) fn completion_item_from_type_alias_declaration(declaration: &TypeAliasDeclaration) -> CompletionItem {
+    CompletionItem {
+        label: escape_identifier(declaration.identifier().name()),
         namespace_path: Some(readable_namespace_path(&declaration.string_path)),
         documentation: documentation_from_comment(declaration.comment()),
         detail: None,
@@ -8,11 +8,13 @@ use crate::completion::find_completion_in_data_set_declaration::find_completion_
 use crate::completion::find_completion_in_decorator_declaration::find_completion_in_decorator_declaration;
 use crate::completion::find_completion_in_enum_declaration::find_completion_in_enum_declaration;
 use crate::completion::find_completion_in_handler_group::find_completion_in_handler_group_declaration;
+use crate::completion::find_completion_in_import::find_completion_in_import;
 use crate::completion::find_completion_in_interface::find_completion_in_interface;
 use crate::completion::find_completion_in_middleware_declaration::find_completion_in_middleware_declaration;
 use crate::completion::find_completion_in_model::find_completion_in_model;
 use crate::completion::find_completion_in_pipeline_item_declaration::find_completion_in_pipeline_item_declaration;
 use crate::completion::find_completion_in_struct_declaration::find_completion_in_struct_declaration;
+use crate::completion::find_completion_in_type_alias_declaration::find_completion_in_type_alias_declaration;
 use crate::completion::find_completion_in_use_middleware_block::find_completion_in_use_middleware_block;
 use crate::search::search_top::search_top;
 
@@ -20,6 +22,9 @@ pub fn find_completion(schema: &Schema, file_path: &str, line_col: (usize, usize
     if let Some(source) = schema.source_at_path(file_path) {
         if let Some(top) = search_top(schema, file_path, line_col) {
             match top {
+                Node::Import(i) => {
+                    return find_completion_in_import(schema, source, i, line_col);
+                }
                 Node::Model(m) => {
                     return find_completion_in_model(schema, source, m, line_col);
                 }
@@ -56,6 +61,9 @@ pub fn find_completion(schema: &Schema, file_path: &str, line_col: (usize, usize
                 Node::PipelineItemDeclaration(p) => {
                     return find_completion_in_pipeline_item_declaration(schema, source, p, line_col);
                 }
+                Node::TypeAliasDeclaration(t) => {
+                    return find_completion_in_type_alias_declaration(schema, source, t, line_col);
+                }
                 Node::UseMiddlewaresBlock(u) => {
                     return find_completion_in_use_middleware_block(schema, source, u, line_col);
                 }
@@ -6,6 +6,7 @@ use crate::ast::partial_argument::PartialArgument;
 use crate::availability::Availability;
 use crate::ast::schema::Schema;
 use crate::ast::source::Source;
+use crate::utils::identifier::escape_identifier;
 use crate::completion::completion_item::CompletionItem;
 use crate::completion::find_completion_in_expression::{find_completion_in_empty_expression, find_completion_in_expression};
 use crate::r#type::Type;
@@ -76,7 +77,7 @@ fn completion_items_from_names(names: &Vec<Vec<&str>>) -> Vec<CompletionItem> {
         }
     }
     result.iter().map(|name| CompletionItem {
-        label: name.to_string(),
+        label: escape_identifier(name),
         namespace_path: None,
         documentation: None,
         detail: None,
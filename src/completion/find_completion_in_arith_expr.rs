@@ -27,5 +27,14 @@ pub(super) fn find_completion_in_arith_expr(schema: &Schema, source: &Source, ar
         } else {
             vec![]
         }
+        ArithExpr::TernaryOperation(t) => if t.condition().span().contains_line_col(line_col) {
+            find_completion_in_arith_expr(schema, source, t.condition(), line_col, namespace_path, &Type::Bool, availability)
+        } else if t.consequent().span().contains_line_col(line_col) {
+            find_completion_in_expression(schema, source, t.consequent(), line_col, namespace_path, expect, availability)
+        } else if t.alternative().span().contains_line_col(line_col) {
+            find_completion_in_expression(schema, source, t.alternative(), line_col, namespace_path, expect, availability)
+        } else {
+            vec![]
+        }
     }
 }
@@ -3,6 +3,7 @@ use crate::ast::literals::EnumVariantLiteral;
 use crate::ast::reference_space::ReferenceSpace;
 use crate::ast::schema::Schema;
 use crate::ast::source::Source;
+use crate::utils::identifier::escape_identifier;
 use crate::availability::Availability;
 use crate::completion::collect_argument_list_names::collect_argument_list_names_from_argument_list_declaration;
 use crate::completion::completion_item::CompletionItem;
@@ -50,7 +51,7 @@ pub(super) fn find_completion_in_enum_variant_literal(schema: &Schema, source: &
 
 fn completion_item_from_synthesized_enum(synthesized_enum: &SynthesizedEnum) -> Vec<CompletionItem> {
     synthesized_enum.members.values().map(|member| CompletionItem {
-        label: member.name.clone(),
+        label: escape_identifier(&member.name),
         namespace_path: None,
         documentation: documentation_from_comment(member.comment.as_ref()),
         detail: None,
@@ -62,7 +63,7 @@ pub(super) fn find_completion_in_empty_enum_variant_literal(schema: &Schema, sou
         Type::EnumVariant(reference) => {
             let enum_definition = schema.find_top_by_path(reference.path()).unwrap().as_enum().unwrap();
             enum_definition.members().map(|member| CompletionItem {
-                label: member.name().to_owned(),
+                label: escape_identifier(member.name()),
                 namespace_path: Some(enum_definition.str_path().join(".")),
                 documentation: documentation_from_comment(member.comment()),
                 detail: None,
@@ -91,7 +92,7 @@ pub(super) fn find_completion_in_empty_enum_variant_literal(schema: &Schema, sou
             ).iter().map(|node| node.as_data_set().unwrap()).collect();
             let mut records = vec![];
             for data_set in data_sets_found {
-                if let Some(group) = data_set.groups().find(|g| g.resolved() == model_reference) {
+                if let Some(group) = data_set.groups().find(|g| g.is_resolved() && g.resolved() == model_reference) {
                     for record in group.records() {
                         records.push(CompletionItem {
                             label: record.identifier().name().to_owned(),
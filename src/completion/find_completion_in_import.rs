@@ -0,0 +1,45 @@
+use crate::ast::import::Import;
+use crate::ast::schema::Schema;
+use crate::ast::source::Source;
+use crate::completion::completion_item::CompletionItem;
+
+pub(super) fn find_completion_in_import(schema: &Schema, source: &Source, import: &Import, line_col: (usize, usize)) -> Vec<CompletionItem> {
+    let literal = import.source();
+    if !literal.span.contains_line_col(line_col) || literal.span.start_position.0 != line_col.0 {
+        return vec![];
+    }
+    // the part of the path in front of the cursor, without the opening quote
+    let typed: String = literal.display.chars().skip(1).take(line_col.1.saturating_sub(literal.span.start_position.1 + 1)).collect();
+    let directory = match typed.rfind('/') {
+        Some(index) => &typed[0..=index],
+        None => ".",
+    };
+    let file_system = schema.file_system();
+    let directory_path = file_system.import_path(&source.file_path, directory);
+    let mut result = vec![];
+    for entry in file_system.list_dir(&directory_path) {
+        if entry == source.file_path {
+            continue
+        }
+        let name = entry.rsplit(|c| c == '/' || c == '\\').next().unwrap();
+        if name.starts_with('.') {
+            continue
+        }
+        if file_system.is_dir(&entry) {
+            result.push(CompletionItem {
+                label: name.to_owned(),
+                namespace_path: None,
+                documentation: None,
+                detail: Some(entry.clone()),
+            });
+        } else if let Some(name) = name.strip_suffix(".teo") {
+            result.push(CompletionItem {
+                label: name.to_owned(),
+                namespace_path: None,
+                documentation: None,
+                detail: Some(entry.clone()),
+            });
+        }
+    }
+    result
+}
@@ -18,7 +18,7 @@ pub(super) fn find_completion_in_model(schema: &Schema, source: &Source, model:
             return find_completion_for_empty_type_item(schema, source, &model.namespace_str_path(), TypeExprFilter::Model, model.availability());
         }
     }
-    for field in model.fields() {
+    for field in model.declared_fields() {
         if field.span.contains_line_col(line_col) {
             return find_completion_in_field(schema, source, field, line_col, &vec![]);
         }
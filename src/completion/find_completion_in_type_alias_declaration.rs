@@ -0,0 +1,22 @@
+use crate::ast::schema::Schema;
+use crate::ast::source::Source;
+use crate::ast::type_alias_declaration::TypeAliasDeclaration;
+use crate::completion::completion_item::CompletionItem;
+use crate::completion::find_completion_in_type_expr::{find_completion_in_type_expr, TypeExprFilter};
+use crate::traits::info_provider::InfoProvider;
+use crate::traits::node_trait::NodeTrait;
+
+pub(super) fn find_completion_in_type_alias_declaration(schema: &Schema, source: &Source, type_alias_declaration: &TypeAliasDeclaration, line_col: (usize, usize)) -> Vec<CompletionItem> {
+    let generics = type_alias_declaration.generics_declaration().into_iter().collect();
+    if type_alias_declaration.type_expr().span().contains_line_col(line_col) {
+        return find_completion_in_type_expr(schema, source, type_alias_declaration.type_expr(), line_col, &type_alias_declaration.namespace_str_path(), &generics, TypeExprFilter::None, type_alias_declaration.define_availability);
+    }
+    if let Some(generics_constraint) = type_alias_declaration.generics_constraint() {
+        for item in generics_constraint.items() {
+            if item.type_expr().span().contains_line_col(line_col) {
+                return find_completion_in_type_expr(schema, source, item.type_expr(), line_col, &type_alias_declaration.namespace_str_path(), &generics, TypeExprFilter::None, type_alias_declaration.define_availability);
+            }
+        }
+    }
+    vec![]
+}
@@ -9,6 +9,7 @@ use crate::completion::completion_item::CompletionItem;
 use crate::completion::find_top_completion_with_filter::find_top_completion_with_filter;
 use crate::r#type::synthesized_enum_reference::SynthesizedEnumReferenceKind;
 use crate::r#type::synthesized_shape_reference::SynthesizedShapeReferenceKind;
+use crate::r#type::shape_operation::ShapeOperationKind;
 use crate::traits::node_trait::NodeTrait;
 use crate::utils::top_filter::top_filter_for_type_expr_filter;
 
@@ -57,6 +58,7 @@ fn find_completion_in_type_expr_kind(schema: &Schema, source: &Source, kind: &Ty
         TypeExprKind::TypeSubscript(subscript) => find_completion_in_type_subscript(schema, source, subscript, line_col, namespace_path, generics, filter, availability),
         TypeExprKind::FieldName(_) => vec![],
         TypeExprKind::TypedEnum(_) => vec![],
+        TypeExprKind::StringLiteral(_) | TypeExprKind::NumericLiteral(_) | TypeExprKind::BoolLiteral(_) => vec![],
         TypeExprKind::TypedShape(typed_shape) => find_completion_in_typed_shape(schema, source, typed_shape, line_col, namespace_path, generics, filter, availability),
     }
 }
@@ -162,6 +164,9 @@ fn builtin_types(filter: TypeExprFilter) -> Vec<CompletionItem> {
         for kind in SynthesizedShapeReferenceKind::iter() {
             result.push(builtin_type(&kind.to_string()));
         }
+        for kind in ShapeOperationKind::iter() {
+            result.push(builtin_type(&kind.to_string()));
+        }
     }
     if filter.is_none() {
         result.push(builtin_type("Ignored"));
@@ -5,6 +5,7 @@ use crate::availability::Availability;
 use crate::ast::schema::Schema;
 use crate::ast::source::Source;
 use crate::ast::unit::Unit;
+use crate::utils::identifier::escape_identifier;
 use crate::completion::collect_argument_list_names::collect_argument_list_names_from_argument_list_declaration;
 use crate::completion::completion_item::CompletionItem;
 use crate::completion::completion_item_from_top::documentation_from_comment;
@@ -29,7 +30,8 @@ pub(super) fn find_completion_in_unit(schema: &Schema, source: &Source, unit: &U
         }
         return vec![];
     }
-    let mut previous_resolved = &ExprInfo::undetermined();
+    let undetermined = ExprInfo::undetermined();
+    let mut previous_resolved = &undetermined;
     for (index, expression) in unit.expressions().enumerate() {
         if expression.span().contains_line_col(line_col) {
             if index == 0 {
@@ -82,6 +84,18 @@ pub(super) fn find_completion_in_unit(schema: &Schema, source: &Source, unit: &U
                         );
                     },
                     ExpressionKind::Subscript(subscript) => if subscript.expression().span().contains_line_col(line_col) {
+                        if let Some(environment) = schema.environment() {
+                            if previous_resolved.r#type().as_struct_object().map_or(false, |(reference, _)| reference.str_path() == vec!["std", "EnvVars"]) {
+                                // names are quoted unless they're typed inside a string literal
+                                let in_string_literal = subscript.expression().is_single_string_literal();
+                                return environment.keys().map(|name| CompletionItem {
+                                    label: if in_string_literal { name.clone() } else { format!("\"{}\"", name) },
+                                    namespace_path: Some("std.EnvVars".to_owned()),
+                                    documentation: None,
+                                    detail: None,
+                                }).collect();
+                            }
+                        }
                         return find_completion_in_expression(schema, source, subscript.expression(), line_col, namespace_path, &Type::Undetermined, availability);
                     } else {
                         return vec![];
@@ -102,7 +116,7 @@ pub(super) fn find_completion_in_unit(schema: &Schema, source: &Source, unit: &U
                 }
             }
         } else {
-            previous_resolved = expression.resolved();
+            previous_resolved = if expression.is_resolved() { expression.resolved() } else { &undetermined };
         }
     }
     if let Some(empty_dot) = unit.empty_dot() {
@@ -129,7 +143,7 @@ fn completion_items_in_unit_for_identifier_or_int_subscript_with_previous_resolv
                 config_declaration.dictionary_literal().expressions().filter_map(|named_expression| {
                     if let Some(key) = named_expression.key().named_key_without_resolving() {
                         Some(CompletionItem {
-                            label: key.to_string(),
+                            label: escape_identifier(key),
                             namespace_path: Some(format!("{}", named_expression.value().resolved().r#type())),
                             documentation: None,
                             detail: None,
@@ -149,7 +163,7 @@ fn completion_items_in_unit_for_identifier_or_int_subscript_with_previous_resolv
             ReferenceType::Enum => {
                 let enum_definition = schema.find_top_by_path(reference_info.reference.path()).unwrap().as_enum().unwrap();
                 enum_definition.members().map(|member| CompletionItem {
-                    label: member.name().to_owned(),
+                    label: escape_identifier(member.name()),
                     namespace_path: Some(enum_definition.str_path().join(".")),
                     documentation: documentation_from_comment(member.comment()),
                     detail: None,
@@ -158,7 +172,7 @@ fn completion_items_in_unit_for_identifier_or_int_subscript_with_previous_resolv
             ReferenceType::Model => {
                 let model_definition = schema.find_top_by_path(reference_info.reference.path()).unwrap().as_model().unwrap();
                 model_definition.fields().map(|field| CompletionItem {
-                    label: field.name().to_owned(),
+                    label: escape_identifier(field.name()),
                     namespace_path: Some(model_definition.str_path().join(".")),
                     documentation: documentation_from_comment(field.comment()),
                     detail: None,
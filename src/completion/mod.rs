@@ -15,7 +15,9 @@ mod find_completion_in_pipeline_item_declaration;
 mod find_completion_in_handler_group;
 mod find_completion_in_middleware_declaration;
 mod find_completion_in_use_middleware_block;
+mod find_completion_in_import;
 mod find_completion_in_constant_declaration;
+mod find_completion_in_type_alias_declaration;
 pub(crate) mod find_completion_in_type_expr;
 mod find_completion_in_argument_list_declaration;
 mod find_completion_in_expression;
@@ -15,6 +15,7 @@ use crate::definition::jump_to_definition_in_middleware_declaration::jump_to_def
 use crate::definition::jump_to_definition_in_model::jump_to_definition_in_model;
 use crate::definition::jump_to_definition_in_pipeline_item_declaration::jump_to_definition_in_pipeline_item_declaration;
 use crate::definition::jump_to_definition_in_struct_declaration::jump_to_definition_in_struct_declaration;
+use crate::definition::jump_to_definition_in_type_alias_declaration::jump_to_definition_in_type_alias_declaration;
 use crate::search::search_top::search_top;
 
 pub fn jump_to_definition(schema: &Schema, file_path: &str, line_col: (usize, usize)) -> Vec<Definition> {
@@ -34,6 +35,7 @@ pub fn jump_to_definition(schema: &Schema, file_path: &str, line_col: (usize, us
                 Node::Enum(e) => jump_to_definition_in_enum_declaration(schema, source, e, line_col),
                 Node::MiddlewareDeclaration(m) => jump_to_definition_in_middleware_declaration(schema, source, m, line_col),
                 Node::SynthesizedShapeDeclaration(f) => jump_to_definition_in_declared_synthesized_shape(schema, source, f, line_col),
+                Node::TypeAliasDeclaration(t) => jump_to_definition_in_type_alias_declaration(schema, source, t, line_col),
                 Node::HandlerDeclaration(h) => jump_to_definition_in_handler_declaration(schema, source, h, line_col, true),
                 Node::HandlerTemplateDeclaration(h) => jump_to_definition_in_handler_template_declaration(schema, source, h, line_col),
                 Node::DataSet(_) => vec![],
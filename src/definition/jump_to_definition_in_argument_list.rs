@@ -5,6 +5,7 @@ use crate::ast::identifier::Identifier;
 use crate::ast::schema::Schema;
 use crate::ast::source::Source;
 use crate::definition::definition::Definition;
+use crate::r#type::r#type::Type;
 use crate::definition::jump_to_definition_in_expression::jump_to_definition_in_expression;
 use crate::traits::node_trait::NodeTrait;
 use crate::traits::resolved::Resolve;
@@ -62,7 +63,7 @@ pub(super) fn jump_to_definition_in_argument<'a>(
             argument.value(),
             namespace_path,
             line_col,
-            argument.value().resolved().r#type(),
+            if argument.value().is_resolved() { argument.value().resolved().r#type() } else { &Type::Undetermined },
             availability,
         );
     }
@@ -75,5 +75,38 @@ pub(super) fn jump_to_definition_in_arith_expr<'a>(
         } else {
             vec![]
         }
+        ArithExpr::TernaryOperation(t) => if t.condition().span().contains_line_col(line_col) {
+            jump_to_definition_in_arith_expr(
+                schema,
+                source,
+                t.condition(),
+                namespace_path,
+                line_col,
+                &Type::Bool,
+                availability,
+            )
+        } else if t.consequent().span().contains_line_col(line_col) {
+            jump_to_definition_in_expression(
+                schema,
+                source,
+                t.consequent(),
+                namespace_path,
+                line_col,
+                expect,
+                availability,
+            )
+        } else if t.alternative().span().contains_line_col(line_col) {
+            jump_to_definition_in_expression(
+                schema,
+                source,
+                t.alternative(),
+                namespace_path,
+                line_col,
+                expect,
+                availability,
+            )
+        } else {
+            vec![]
+        }
     }
 }
\ No newline at end of file
@@ -27,7 +27,7 @@ pub(super) fn jump_to_definition_in_dictionary_literal<'a>(
                 key_expression,
                 namespace_path,
                 line_col,
-                key_expression.resolved().r#type(),
+                if key_expression.is_resolved() { key_expression.resolved().r#type() } else { &Type::Undetermined },
                 availability
             );
         }
@@ -38,7 +38,7 @@ pub(super) fn jump_to_definition_in_dictionary_literal<'a>(
                 value_expression,
                 namespace_path,
                 line_col,
-                value_expression.resolved().r#type(),
+                if value_expression.is_resolved() { value_expression.resolved().r#type() } else { &Type::Undetermined },
                 availability,
             );
         }
@@ -36,7 +36,7 @@ pub(super) fn jump_to_definition_in_enum_variant_literal<'a>(
                     let model = schema.find_top_by_path(reference.path()).unwrap().as_model().unwrap();
                     if let Some(field) = model.fields().find(|f| f.identifier().name() == enum_variant_literal.identifier().name()) {
                         vec![Definition {
-                            path: schema.source(field.source_id()).unwrap().file_path.clone(),
+                            path: schema.source(field.origin_source_id()).unwrap().file_path.clone(),
                             selection_span: enum_variant_literal.identifier().span,
                             target_span: field.span,
                             identifier_span: field.identifier().span,
@@ -67,6 +67,12 @@ pub(super) fn jump_to_definition_in_identifier<'a>(
                 target_span: c.span,
                 identifier_span: c.identifier().map_or(c.keyword().span, |i| i.span),
             }],
+            Node::Import(i) => vec![Definition {
+                path: schema.source(*reference.get(0).unwrap()).unwrap().file_path.clone(),
+                selection_span: identifier.span,
+                target_span: i.span,
+                identifier_span: i.as_identifier().unwrap().span,
+            }],
             _ => unreachable!()
         }
     } else {
@@ -2,22 +2,43 @@ use crate::ast::import::Import;
 use crate::ast::schema::Schema;
 use crate::ast::source::Source;
 use crate::ast::span::Span;
+use crate::availability::Availability;
 use crate::definition::definition::Definition;
+use crate::search::search_identifier_path::search_identifier_path_names_with_filter_to_top;
+use crate::traits::identifiable::Identifiable;
+use crate::traits::node_trait::NodeTrait;
+use crate::utils::top_filter::top_filter_for_import;
 
-pub(super) fn jump_to_definition_in_import(_schema: &Schema, _source: &Source, import: &Import, line_col: (usize, usize)) -> Vec<Definition> {
-    if import.source.span.contains_line_col(line_col) {
-        if !import.file_path.starts_with("(builtin)") {
-            vec![
-                Definition {
-                    path: import.file_path.clone(),
-                    selection_span: import.source.span,
-                    target_span: Span::default(),
-                    identifier_span: Span::default(),
+pub(super) fn jump_to_definition_in_import(schema: &Schema, _source: &Source, import: &Import, line_col: (usize, usize)) -> Vec<Definition> {
+    if import.source().span.contains_line_col(line_col) || import.as_identifier().map_or(false, |identifier| identifier.span.contains_line_col(line_col)) {
+        let selection_span = if import.source().span.contains_line_col(line_col) { import.source().span } else { import.as_identifier().unwrap().span };
+        import.file_paths.iter().filter(|file_path| !file_path.starts_with("(builtin)")).map(|file_path| Definition {
+            path: file_path.clone(),
+            selection_span,
+            target_span: Span::default(),
+            identifier_span: Span::default(),
+        }).collect()
+    } else if let Some(identifier) = import.identifiers().find(|i| i.span.contains_line_col(line_col)) {
+        for imported_source in schema.import_sources(import) {
+            if let Some(top) = search_identifier_path_names_with_filter_to_top(
+                &vec![identifier.name()],
+                schema,
+                imported_source,
+                &vec![],
+                &top_filter_for_import(),
+                Availability::default(),
+            ) {
+                if let Some(identifier_span) = top.identifier_span() {
+                    return vec![Definition {
+                        path: schema.source(top.source_id()).unwrap().file_path.clone(),
+                        selection_span: identifier.span,
+                        target_span: top.span(),
+                        identifier_span,
+                    }];
                 }
-            ]
-        } else {
-            vec![]
+            }
         }
+        vec![]
     } else {
         vec![]
     }
@@ -1,3 +1,4 @@
+use crate::ast::identifier_path::IdentifierPath;
 use crate::ast::model::Model;
 use crate::ast::reference_space::ReferenceSpace;
 use crate::ast::schema::Schema;
@@ -6,14 +7,22 @@ use crate::definition::definition::Definition;
 use crate::definition::jump_to_definition_in_decorator::jump_to_definition_in_decorator;
 use crate::definition::jump_to_definition_in_field::jump_to_definition_in_field;
 use crate::definition::jump_to_definition_in_handler_declaration::jump_to_definition_in_handler_declaration;
+use crate::availability::Availability;
 use crate::search::search_availability::search_availability;
-use crate::utils::top_filter::{top_filter_for_any_model_field_decorators, top_filter_for_reference_type};
+use crate::search::search_identifier_path::search_identifier_path_names_with_filter_to_path;
+use crate::traits::node_trait::NodeTrait;
+use crate::utils::top_filter::{top_filter_for_any_model_field_decorators, top_filter_for_model_extends, top_filter_for_reference_type};
 
 pub(super) fn jump_to_definition_in_model(schema: &Schema, source: &Source, model: &Model, line_col: (usize, usize)) -> Vec<Definition> {
     let mut namespace_path: Vec<_> = model.string_path.iter().map(|s| s.as_str()).collect();
     namespace_path.pop();
     let availability = search_availability(schema, source, &namespace_path);
-    for field in model.fields() {
+    for identifier_path in model.extends() {
+        if identifier_path.span.contains_line_col(line_col) {
+            return jump_to_definition_in_model_extends(schema, source, identifier_path, &namespace_path, line_col, availability);
+        }
+    }
+    for field in model.declared_fields() {
         if field.span.contains_line_col(line_col) {
             return jump_to_definition_in_field(schema, source, field, line_col, &vec![], availability);
         }
@@ -40,4 +49,31 @@ pub(super) fn jump_to_definition_in_model(schema: &Schema, source: &Source, mode
         }
     }
     vec![]
-}
\ No newline at end of file
+}
+
+fn jump_to_definition_in_model_extends(schema: &Schema, source: &Source, identifier_path: &IdentifierPath, namespace_path: &Vec<&str>, line_col: (usize, usize), availability: Availability) -> Vec<Definition> {
+    let mut user_typed_spaces = vec![];
+    let mut selector_span = None;
+    for identifier in identifier_path.identifiers() {
+        user_typed_spaces.push(identifier.name());
+        if identifier.span.contains_line_col(line_col) {
+            selector_span = Some(identifier.span);
+            break
+        }
+    }
+    let Some(selector_span) = selector_span else {
+        return vec![]
+    };
+    match search_identifier_path_names_with_filter_to_path(&user_typed_spaces, schema, source, namespace_path, &top_filter_for_model_extends(), availability) {
+        Some(path) => {
+            let top = schema.find_top_by_path(&path).unwrap();
+            vec![Definition {
+                path: schema.source(*path.get(0).unwrap()).unwrap().file_path.clone(),
+                selection_span: selector_span,
+                target_span: top.span(),
+                identifier_span: top.identifier_span().unwrap_or(top.span()),
+            }]
+        },
+        None => vec![],
+    }
+}
@@ -25,7 +25,7 @@ pub(super) fn jump_to_definition_in_tuple_literal<'a>(
                 expression,
                 namespace_path,
                 line_col,
-                expression.resolved().r#type(),
+                if expression.is_resolved() { expression.resolved().r#type() } else { &Type::Undetermined },
                 availability,
             );
         }
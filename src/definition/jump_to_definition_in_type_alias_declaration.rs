@@ -0,0 +1,49 @@
+use crate::ast::schema::Schema;
+use crate::ast::source::Source;
+use crate::ast::type_alias_declaration::TypeAliasDeclaration;
+use crate::definition::definition::Definition;
+use crate::definition::jump_to_definition_in_type_expr::jump_to_definition_in_type_expr_kind;
+use crate::search::search_availability::search_availability;
+use crate::traits::info_provider::InfoProvider;
+use crate::traits::node_trait::NodeTrait;
+
+pub(super) fn jump_to_definition_in_type_alias_declaration<'a>(
+    schema: &'a Schema,
+    source: &'a Source,
+    type_alias_declaration: &'a TypeAliasDeclaration,
+    line_col: (usize, usize),
+) -> Vec<Definition> {
+    let namespace_path: Vec<&str> = type_alias_declaration.namespace_str_path();
+    let availability = search_availability(schema, source, &namespace_path);
+    let mut generics_declarations = vec![];
+    if let Some(generics_declaration) = type_alias_declaration.generics_declaration() {
+        generics_declarations.push(generics_declaration);
+    }
+    if type_alias_declaration.type_expr().span().contains_line_col(line_col) {
+        return jump_to_definition_in_type_expr_kind(
+            schema,
+            source,
+            &type_alias_declaration.type_expr().kind,
+            &namespace_path,
+            line_col,
+            &generics_declarations,
+            availability,
+        );
+    }
+    if let Some(generics_constraint) = type_alias_declaration.generics_constraint() {
+        for item in generics_constraint.items() {
+            if item.type_expr().span().contains_line_col(line_col) {
+                return jump_to_definition_in_type_expr_kind(
+                    schema,
+                    source,
+                    &item.type_expr().kind,
+                    &namespace_path,
+                    line_col,
+                    &generics_declarations,
+                    availability,
+                );
+            }
+        }
+    }
+    vec![]
+}
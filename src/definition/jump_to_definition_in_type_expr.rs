@@ -116,6 +116,7 @@ pub(super) fn jump_to_definition_in_type_expr_kind(
             availability
         ),
         TypeExprKind::TypedEnum(_) => vec![],
+        TypeExprKind::StringLiteral(_) | TypeExprKind::NumericLiteral(_) | TypeExprKind::BoolLiteral(_) => vec![],
         TypeExprKind::TypedShape(typed_shape) => jump_to_definition_in_typed_shape(
             schema,
             source,
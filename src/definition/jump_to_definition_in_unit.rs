@@ -117,7 +117,7 @@ pub(super) fn jump_to_definition_in_unit<'a>(
                     Node::Model(model) => if let Some(identifier) = identifier_name {
                         let field = model.fields().find(|i| i.identifier().name() == identifier).unwrap();
                         vec![Definition {
-                            path: schema.source(field.source_id()).unwrap().file_path.clone(),
+                            path: schema.source(field.origin_source_id()).unwrap().file_path.clone(),
                             selection_span: span,
                             target_span: field.span,
                             identifier_span: field.identifier().span,
@@ -162,6 +162,12 @@ pub(super) fn jump_to_definition_in_unit<'a>(
                             identifier_span: namespace.identifier().span,
                         }]
                     }
+                    Node::Import(import) => vec![Definition {
+                        path: schema.source(import.source_id()).unwrap().file_path.clone(),
+                        selection_span: span,
+                        target_span: import.span,
+                        identifier_span: import.as_identifier().unwrap().span,
+                    }],
                     Node::StructDeclaration(struct_declaration) => if let Some(identifier) = identifier_name {
                         let method = struct_declaration.function_declarations().find(|f| f.identifier().name() == identifier).unwrap();
                         vec![Definition {
@@ -27,5 +27,6 @@ pub(crate) mod jump_to_definition_in_argument_list_declaration;
 pub(crate) mod jump_to_definition_in_enum_declaration;
 pub(crate) mod jump_to_definition_in_middleware_declaration;
 pub(crate) mod jump_to_definition_in_declared_synthesized_shape;
+pub(crate) mod jump_to_definition_in_type_alias_declaration;
 pub(crate) mod jump_to_definition_in_handler_template_declaration;
 pub(crate) mod jump_to_definition_in_include_handler_from_template;
\ No newline at end of file
@@ -207,6 +207,11 @@ impl Diagnostics {
             self.errors.push(item.into_error());
         }
     }
+
+    pub(crate) fn truncate(&mut self, errors: usize, warnings: usize) {
+        self.errors.truncate(errors);
+        self.warnings.truncate(warnings);
+    }
 }
 
 impl Default for Diagnostics {
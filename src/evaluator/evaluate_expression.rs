@@ -0,0 +1,85 @@
+use indexmap::IndexMap;
+use crate::ast::arith_expr::ArithExpr;
+use crate::ast::expression::{Expression, ExpressionKind};
+use crate::ast::literals::DictionaryLiteral;
+use crate::ast::unit::Unit;
+use crate::evaluator::evaluate_operation::{evaluate_binary_operation, evaluate_force_unwrap, evaluate_ternary_operation, evaluate_unary_operation};
+use crate::traits::has_availability::HasAvailability;
+use crate::traits::resolved::Resolve;
+use crate::value::Value;
+
+/// Evaluate a resolved expression at compile time. Compound expressions are evaluated from their
+/// parts, anything else takes the value the resolver assigned. `None` means that the value is
+/// only known at runtime.
+pub(crate) fn evaluate_expression(expression: &Expression) -> Option<Value> {
+    let evaluated = match &expression.kind {
+        ExpressionKind::Group(group) => evaluate_expression(group.expression()),
+        ExpressionKind::ArithExpr(arith_expr) => evaluate_arith_expr(arith_expr),
+        ExpressionKind::Unit(unit) => evaluate_unit(unit),
+        ExpressionKind::TupleLiteral(tuple) => tuple.expressions().map(evaluate_expression).collect::<Option<Vec<Value>>>().map(Value::Tuple),
+        ExpressionKind::ArrayLiteral(array) => array.expressions().map(evaluate_expression).collect::<Option<Vec<Value>>>().map(Value::Array),
+        ExpressionKind::DictionaryLiteral(dictionary) => evaluate_dictionary_literal(dictionary),
+        _ => None,
+    };
+    evaluated.or_else(|| resolved_value(expression))
+}
+
+fn resolved_value(expression: &Expression) -> Option<Value> {
+    if expression.is_resolved() {
+        expression.resolved().value.clone()
+    } else {
+        None
+    }
+}
+
+fn evaluate_dictionary_literal(dictionary: &DictionaryLiteral) -> Option<Value> {
+    let mut values = IndexMap::new();
+    for named_expression in dictionary.expressions() {
+        if !named_expression.is_available() {
+            continue
+        }
+        let key = match resolved_value(named_expression.key()) {
+            Some(Value::String(key)) => key,
+            _ => named_expression.key().named_key_without_resolving()?.to_owned(),
+        };
+        values.insert(key, evaluate_expression(named_expression.value())?);
+    }
+    Some(Value::Dictionary(values))
+}
+
+fn evaluate_unit(unit: &Unit) -> Option<Value> {
+    let mut current = evaluate_expression(unit.expression_at(0)?)?;
+    for (index, expression) in unit.expressions().enumerate().skip(1) {
+        if current.is_null() && unit.is_optional_chained_at(index) {
+            return Some(Value::Null);
+        }
+        current = match &expression.kind {
+            ExpressionKind::Identifier(identifier) => current.as_dictionary()?.get(identifier.name())?.clone(),
+            ExpressionKind::IntSubscript(int_subscript) => current.get(int_subscript.index)?.clone(),
+            ExpressionKind::Subscript(subscript) => match evaluate_expression(subscript.expression())? {
+                Value::String(key) => current.get(key.as_str())?.clone(),
+                index => current.get(index.to_usize()?)?.clone(),
+            },
+            _ => return None,
+        };
+    }
+    Some(current)
+}
+
+fn evaluate_arith_expr(arith_expr: &ArithExpr) -> Option<Value> {
+    match arith_expr {
+        ArithExpr::Expression(expression) => evaluate_expression(expression),
+        ArithExpr::UnaryOperation(unary) => evaluate_unary_operation(unary.op, evaluate_arith_expr(unary.rhs()).as_ref()),
+        ArithExpr::UnaryPostfixOperation(unary) => evaluate_force_unwrap(evaluate_arith_expr(unary.lhs()).as_ref()),
+        ArithExpr::BinaryOperation(binary) => evaluate_binary_operation(
+            binary.op,
+            evaluate_arith_expr(binary.lhs()).as_ref(),
+            evaluate_arith_expr(binary.rhs()).as_ref(),
+        ),
+        ArithExpr::TernaryOperation(ternary) => evaluate_ternary_operation(
+            evaluate_arith_expr(ternary.condition()).as_ref(),
+            evaluate_expression(ternary.consequent()).as_ref(),
+            evaluate_expression(ternary.alternative()).as_ref(),
+        ),
+    }
+}
@@ -0,0 +1,97 @@
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
+use crate::ast::arith_expr::ArithExprOperator;
+use crate::value::range::Range;
+use crate::value::Value;
+
+// The operator semantics shared by the resolver and the evaluator. An operand of `None` is only
+// known at runtime, so is the result unless the other operand decides it.
+
+/// An integer operation whose result is out of range of its type.
+pub(crate) struct IntegerOverflow;
+
+pub(crate) fn evaluate_unary_operation(op: ArithExprOperator, rhs: Option<&Value>) -> Option<Value> {
+    checked_unary_operation(op, rhs).unwrap_or(None)
+}
+
+pub(crate) fn checked_unary_operation(op: ArithExprOperator, rhs: Option<&Value>) -> Result<Option<Value>, IntegerOverflow> {
+    let Some(rhs) = rhs else {
+        return Ok(None)
+    };
+    match op {
+        ArithExprOperator::Neg => check_integer_overflow(rhs.neg(), rhs.is_any_int()),
+        ArithExprOperator::Not => Ok(Some(rhs.normal_not())),
+        ArithExprOperator::BitNeg => check_integer_overflow(rhs.not(), rhs.is_any_int()),
+        _ => Ok(None),
+    }
+}
+
+pub(crate) fn evaluate_force_unwrap(lhs: Option<&Value>) -> Option<Value> {
+    lhs.filter(|value| !value.is_null()).cloned()
+}
+
+pub(crate) fn evaluate_binary_operation(op: ArithExprOperator, lhs: Option<&Value>, rhs: Option<&Value>) -> Option<Value> {
+    checked_binary_operation(op, lhs, rhs).unwrap_or(None)
+}
+
+pub(crate) fn checked_binary_operation(op: ArithExprOperator, lhs: Option<&Value>, rhs: Option<&Value>) -> Result<Option<Value>, IntegerOverflow> {
+    let Some(lhs) = lhs else {
+        return Ok(None)
+    };
+    // short circuiting operators don't need the right hand side when the left hand side decides
+    match op {
+        ArithExprOperator::NullishCoalescing => return Ok(if lhs.is_null() { rhs.cloned() } else { Some(lhs.clone()) }),
+        ArithExprOperator::And => return Ok(if lhs.normal_not().is_false() { rhs.cloned() } else { Some(lhs.clone()) }),
+        ArithExprOperator::Or => return Ok(if lhs.normal_not().is_false() { Some(lhs.clone()) } else { rhs.cloned() }),
+        _ => (),
+    }
+    let Some(rhs) = rhs else {
+        return Ok(None)
+    };
+    let integers = lhs.is_any_int() && rhs.is_any_int();
+    Ok(match op {
+        ArithExprOperator::Div | ArithExprOperator::Mod if is_integer_zero(rhs) => None,
+        ArithExprOperator::Add => check_integer_overflow(lhs.add(rhs), integers)?,
+        ArithExprOperator::Sub => check_integer_overflow(lhs.sub(rhs), integers)?,
+        ArithExprOperator::Mul => check_integer_overflow(lhs.mul(rhs), integers)?,
+        ArithExprOperator::Div => check_integer_overflow(lhs.div(rhs), integers)?,
+        ArithExprOperator::Mod => check_integer_overflow(lhs.rem(rhs), integers)?,
+        ArithExprOperator::BitLS => check_integer_overflow(lhs.shl(rhs), integers)?,
+        ArithExprOperator::BitRS => check_integer_overflow(lhs.shr(rhs), integers)?,
+        ArithExprOperator::BitAnd => lhs.bitand(rhs).ok(),
+        ArithExprOperator::BitXor => lhs.bitxor(rhs).ok(),
+        ArithExprOperator::BitOr => lhs.bitor(rhs).ok(),
+        ArithExprOperator::Gt => Some(Value::Bool(lhs > rhs)),
+        ArithExprOperator::Gte => Some(Value::Bool(lhs >= rhs)),
+        ArithExprOperator::Lt => Some(Value::Bool(lhs < rhs)),
+        ArithExprOperator::Lte => Some(Value::Bool(lhs <= rhs)),
+        ArithExprOperator::Eq => Some(Value::Bool(lhs == rhs)),
+        ArithExprOperator::Neq => Some(Value::Bool(lhs != rhs)),
+        ArithExprOperator::RangeOpen | ArithExprOperator::RangeClose => Some(Value::Range(Range {
+            closed: op == ArithExprOperator::RangeClose,
+            start: Box::new(lhs.clone()),
+            end: Box::new(rhs.clone()),
+        })),
+        _ => None,
+    })
+}
+
+// integer operands which pass the type check only fail when the result overflows
+fn check_integer_overflow(result: teo_result::Result<Value>, integers: bool) -> Result<Option<Value>, IntegerOverflow> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(_) if integers => Err(IntegerOverflow),
+        Err(_) => Ok(None),
+    }
+}
+
+pub(crate) fn evaluate_ternary_operation(condition: Option<&Value>, consequent: Option<&Value>, alternative: Option<&Value>) -> Option<Value> {
+    match condition? {
+        Value::Bool(true) => consequent.cloned(),
+        Value::Bool(false) => alternative.cloned(),
+        _ => None,
+    }
+}
+
+pub(crate) fn is_integer_zero(value: &Value) -> bool {
+    value.is_any_int() && value.to_int64() == Some(0)
+}
@@ -0,0 +1,2 @@
+pub(crate) mod evaluate_expression;
+pub(crate) mod evaluate_operation;
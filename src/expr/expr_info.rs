@@ -26,6 +26,22 @@ impl ExprInfo {
         self.reference_info.as_ref()
     }
 
+    /// Whether this is assignable to `expected`, literal types are checked against the value.
+    pub fn test(&self, expected: &Type) -> bool {
+        expected.test_with_value(self.r#type(), self.value())
+    }
+
+    /// The type reported as found when this isn't assignable to `expected`. It's the literal type
+    /// of the value when literal types are expected.
+    pub fn found_type(&self, expected: &Type) -> Type {
+        if expected.contains_literals() {
+            if let Some(literal) = self.value().and_then(|value| self.r#type().literal_type_of_value(value)) {
+                return literal;
+            }
+        }
+        self.r#type().clone()
+    }
+
     pub fn is_undetermined(&self) -> bool {
         self.r#type().is_undetermined()
     }
@@ -20,4 +20,5 @@ pub enum ReferenceType {
     FunctionDeclaration,
     Namespace,
     DeclaredSynthesizedShape,
+    TypeAlias,
 }
\ No newline at end of file
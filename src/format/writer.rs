@@ -1,4 +1,3 @@
-use std::collections::btree_map::Values;
 use crate::ast::node::Node;
 use crate::format::command::Command;
 use crate::format::flusher::Flusher;
@@ -27,7 +26,7 @@ impl<'a> Writer<'a> {
         }
     }
 
-    pub fn write_children(&mut self, node: &'a dyn Write, children: Values<'a, usize, Node>) {
+    pub fn write_children(&mut self, node: &'a dyn Write, children: impl Iterator<Item = &'a Node>) {
         if !self.can_write {
             panic!("writer can only write only once in one call");
         }
@@ -7,6 +7,7 @@ pub mod diagnostics;
 pub mod utils;
 pub(crate) mod completion;
 pub(crate) mod definition;
+pub(crate) mod evaluator;
 pub mod search;
 pub mod traits;
 pub mod availability;
@@ -17,34 +18,80 @@ pub mod value;
 pub mod macros;
 
 use std::collections::BTreeMap;
+use std::sync::Arc;
 use crate::ast::schema::Schema;
 use crate::completion::completion_item::CompletionItem;
 use crate::definition::definition::Definition;
 use crate::diagnostics::diagnostics::Diagnostics;
 use crate::diagnostics::formatter::format_to_json;
-use crate::utils::path::FileUtility;
+pub use crate::parser::parse_options::{ParseOptions, ReparseOptions};
+use crate::utils::path::{InMemoryFileSystem, LocalFileSystem, SchemaFileSystem};
 
-pub fn parse(
-    main: impl AsRef<str>,
-    mut file_util: Option<FileUtility>,
-    unsaved_files: Option<BTreeMap<String, String>>,
+pub fn parse(main: impl AsRef<str>, options: ParseOptions) -> (Schema, Diagnostics) {
+    let file_system: Arc<dyn SchemaFileSystem> = match options.file_system {
+        Some(file_system) => Arc::from(file_system),
+        None => Arc::new(LocalFileSystem),
+    };
+    let (mut schema, mut diagnostics) = parser::parse::parse(
+        main,
+        file_system,
+        options.unsaved_files,
+        options.library_roots,
+        options.profiles,
+        options.environment,
+    );
+    resolver::resolve::resolve(&mut schema, &mut diagnostics);
+    (schema, diagnostics)
+}
+
+/// Parse `source` as the file at `virtual_path`. Other files are read from the file system of
+/// `options`, which is an empty in-memory one by default.
+pub fn parse_str(
+    source: impl AsRef<str>,
+    virtual_path: impl AsRef<str>,
+    mut options: ParseOptions,
 ) -> (Schema, Diagnostics) {
-    if file_util.is_none() {
-        file_util = Some(FileUtility::default());
+    if options.file_system.is_some() {
+        options.unsaved_files.get_or_insert_with(BTreeMap::new).insert(virtual_path.as_ref().to_owned(), source.as_ref().to_owned());
+    } else {
+        let mut file_system = InMemoryFileSystem::new(BTreeMap::new());
+        file_system.insert(virtual_path.as_ref(), source.as_ref());
+        options.file_system = Some(Box::new(file_system));
     }
-    let (schema, mut diagnostics) = parser::parse::parse(
-        main,
-        file_util.unwrap(),
-        unsaved_files,
+    parse(virtual_path, options)
+}
+
+pub fn reparse(
+    previous: Schema,
+    changed_paths: Vec<String>,
+    options: ReparseOptions,
+) -> (Schema, Diagnostics) {
+    let file_system = match options.file_system {
+        Some(file_system) => Arc::from(file_system),
+        None => previous.file_system.base().clone(),
+    };
+    let library_roots = options.library_roots.unwrap_or_else(|| previous.library_roots.clone());
+    let (mut schema, mut diagnostics) = parser::parse::reparse(
+        previous,
+        changed_paths,
+        file_system,
+        options.unsaved_files,
+        library_roots,
     );
-    resolver::resolve::resolve(&schema, &mut diagnostics);
+    resolver::resolve::resolve_user_sources(&mut schema, &mut diagnostics);
     (schema, diagnostics)
 }
 
+#[deprecated(note = "code is read from the local file system, use `print_schema_diagnostics_to_terminal`")]
 pub fn print_to_terminal(diagnostics: &Diagnostics) {
     diagnostics::printer::print_diagnostics(diagnostics, true);
 }
 
+/// Print the diagnostics of the schema with code read through the schema's file system.
+pub fn print_schema_diagnostics_to_terminal(schema: &Schema, diagnostics: &Diagnostics) {
+    diagnostics::printer::print_diagnostics_with_file_system(diagnostics, true, schema.file_system());
+}
+
 pub fn generate_json_diagnostics(diagnostics: &Diagnostics, include_warnings: bool) -> String {
     format_to_json(diagnostics, include_warnings)
 }
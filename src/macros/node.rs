@@ -1,18 +1,18 @@
 #[macro_export]
 macro_rules! declare_node {
     ($struct_name:ident) => {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         pub struct $struct_name {
             pub(crate) span: crate::ast::span::Span,
             pub(crate) path: Vec<usize>,
         }
     };
-    ($struct_name:ident, $($vis: vis $element: ident: $ty: ty),* $(,)?) => {
-        #[derive(Debug)]
+    ($struct_name:ident, $($(#[$meta: meta])* $vis: vis $element: ident: $ty: ty),* $(,)?) => {
+        #[derive(Debug, Clone)]
         pub struct $struct_name {
             pub(crate) span: crate::ast::span::Span,
             pub(crate) path: Vec<usize>,
-            $($vis $element: $ty),*
+            $($(#[$meta])* $vis $element: $ty),*
         }
     };
 }
@@ -20,7 +20,7 @@ macro_rules! declare_node {
 #[macro_export]
 macro_rules! declare_container_node {
     ($struct_name:ident) => {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         pub struct $struct_name {
             pub(crate) span: Span,
             pub(crate) path: Vec<usize>,
@@ -28,7 +28,7 @@ macro_rules! declare_container_node {
         }
     };
     ($struct_name:ident, named) => {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         pub struct $struct_name {
             pub(crate) span: Span,
             pub(crate) path: Vec<usize>,
@@ -37,7 +37,7 @@ macro_rules! declare_container_node {
         }
     };
     ($struct_name:ident, availability) => {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         pub struct $struct_name {
             pub(crate) span: Span,
             pub(crate) path: Vec<usize>,
@@ -46,8 +46,8 @@ macro_rules! declare_container_node {
             pub(crate) actual_availability: std::cell::RefCell<crate::availability::Availability>,
         }
     };
-    ($struct_name:ident, named, availability, $($vis: vis $element: ident: $ty: ty),* $(,)?) => {
-        #[derive(Debug)]
+    ($struct_name:ident, named, availability, $($(#[$meta: meta])* $vis: vis $element: ident: $ty: ty),* $(,)?) => {
+        #[derive(Debug, Clone)]
         pub struct $struct_name {
             pub(crate) span: crate::ast::span::Span,
             pub(crate) path: Vec<usize>,
@@ -55,37 +55,37 @@ macro_rules! declare_container_node {
             pub(crate) children: std::collections::btree_map::BTreeMap<usize, crate::ast::node::Node>,
             pub(crate) define_availability: crate::availability::Availability,
             pub(crate) actual_availability: std::cell::RefCell<crate::availability::Availability>,
-            $($vis $element: $ty),*
+            $($(#[$meta])* $vis $element: $ty),*
         }
     };
-    ($struct_name:ident, named, $($vis: vis $element: ident: $ty: ty),* $(,)?) => {
-        #[derive(Debug)]
+    ($struct_name:ident, named, $($(#[$meta: meta])* $vis: vis $element: ident: $ty: ty),* $(,)?) => {
+        #[derive(Debug, Clone)]
         pub struct $struct_name {
             pub(crate) span: crate::ast::span::Span,
             pub(crate) path: Vec<usize>,
             pub(crate) string_path: Vec<String>,
             pub(crate) children: std::collections::btree_map::BTreeMap<usize, crate::ast::node::Node>,
-            $($vis $element: $ty),*
+            $($(#[$meta])* $vis $element: $ty),*
         }
     };
-    ($struct_name:ident, availability, $($vis: vis $element: ident: $ty: ty),* $(,)?) => {
-        #[derive(Debug)]
+    ($struct_name:ident, availability, $($(#[$meta: meta])* $vis: vis $element: ident: $ty: ty),* $(,)?) => {
+        #[derive(Debug, Clone)]
         pub struct $struct_name {
             pub(crate) span: crate::ast::span::Span,
             pub(crate) path: Vec<usize>,
             pub(crate) children: std::collections::btree_map::BTreeMap<usize, crate::ast::node::Node>,
             pub(crate) define_availability: crate::availability::Availability,
             pub(crate) actual_availability: std::cell::RefCell<crate::availability::Availability>,
-            $($vis $element: $ty),*
+            $($(#[$meta])* $vis $element: $ty),*
         }
     };
-    ($struct_name:ident, $($vis: vis $element: ident: $ty: ty),* $(,)?) => {
-        #[derive(Debug)]
+    ($struct_name:ident, $($(#[$meta: meta])* $vis: vis $element: ident: $ty: ty),* $(,)?) => {
+        #[derive(Debug, Clone)]
         pub struct $struct_name {
             pub(crate) span: crate::ast::span::Span,
             pub(crate) children: std::collections::btree_map::BTreeMap<usize, crate::ast::node::Node>,
             pub(crate) path: Vec<usize>,
-            $($vis $element: $ty),*
+            $($(#[$meta])* $vis $element: $ty),*
         }
     };
 }
@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+use crate::ast::field::Field;
+use crate::ast::model::Model;
+use crate::ast::node::Node;
+use crate::ast::schema::Schema;
+use crate::parser::parser_context::ParserContext;
+use crate::search::search_identifier_path::search_identifier_path_names_with_filter_to_top;
+use crate::traits::identifiable::Identifiable;
+use crate::traits::info_provider::InfoProvider;
+use crate::utils::top_filter::top_filter_for_model_extends;
+
+/// Copy the fields of the abstract models and interfaces a model extends into the model. A copy
+/// gets its own path in the model, its span and children are the ones of its origin. Sources
+/// reused from a previous schema still contain the copies of the previous parse, these are
+/// replaced. Model decorators like `@map` and `@index` of abstract models aren't copied.
+pub(super) fn extend_models(schema: &mut Schema, context: &ParserContext) {
+    let mut copies = BTreeMap::new();
+    for model in schema.models().into_iter().chain(schema.abstract_models()) {
+        if model.extends.is_empty() {
+            continue
+        }
+        let fields = with_declared_fields(model, inherited_fields(schema, model, &mut vec![model.path.clone()]));
+        let copied = fields.into_iter().filter(|field| !field.path.starts_with(&model.path)).map(|field| {
+            let id = context.next_id();
+            (id, copy_field(field, model, id))
+        }).collect::<Vec<_>>();
+        copies.insert(model.path.clone(), copied);
+    }
+    for source in schema.sources.values_mut() {
+        for_each_model(&mut source.children, &mut |model| {
+            let stale: Vec<usize> = model.fields.iter().filter(|id| model.is_mixin_field(model.children.get(id).unwrap().as_field().unwrap())).cloned().collect();
+            for id in &stale {
+                model.children.remove(id);
+            }
+            model.fields.retain(|id| !stale.contains(id));
+            if let Some(fields) = copies.remove(&model.path) {
+                let ids: Vec<usize> = fields.iter().map(|(id, _)| *id).collect();
+                for (id, field) in fields {
+                    model.children.insert(id, Node::Field(field));
+                }
+                model.fields.splice(0..0, ids);
+            }
+        });
+    }
+}
+
+fn copy_field(field: &Field, model: &Model, id: usize) -> Field {
+    let mut copy = field.clone();
+    copy.path = model.path.clone();
+    copy.path.push(id);
+    copy.string_path = model.string_path.clone();
+    copy.string_path.push(field.identifier().name().to_owned());
+    copy.origin = field.path.clone();
+    copy
+}
+
+fn inherited_fields<'a>(schema: &'a Schema, model: &'a Model, visited: &mut Vec<Vec<usize>>) -> Vec<&'a Field> {
+    let mut fields: Vec<&Field> = vec![];
+    let Some(source) = schema.source(model.source_id()) else {
+        return fields
+    };
+    for identifier_path in model.extends() {
+        let Some(top) = search_identifier_path_names_with_filter_to_top(
+            &identifier_path.names(),
+            schema,
+            source,
+            &model.namespace_str_path(),
+            &top_filter_for_model_extends(),
+            model.define_availability,
+        ) else {
+            continue
+        };
+        // circular extending is reported by the resolver
+        if visited.contains(top.path()) {
+            continue
+        }
+        let base_fields = match top {
+            Node::Model(base) if base.r#abstract => {
+                visited.push(base.path.clone());
+                let base_fields = with_declared_fields(base, inherited_fields(schema, base, visited));
+                visited.pop();
+                base_fields
+            },
+            Node::InterfaceDeclaration(interface) if interface.generics_declaration().is_none() => interface.fields().collect(),
+            _ => vec![],
+        };
+        for field in base_fields {
+            if !fields.iter().any(|f| f.identifier().name() == field.identifier().name()) {
+                fields.push(field);
+            }
+        }
+    }
+    fields
+}
+
+// declared fields override the inherited ones
+fn with_declared_fields<'a>(model: &'a Model, mut inherited: Vec<&'a Field>) -> Vec<&'a Field> {
+    inherited.retain(|field| !model.declared_fields().any(|declared| declared.identifier().name() == field.identifier().name()));
+    inherited.extend(model.declared_fields());
+    inherited
+}
+
+fn for_each_model<F>(children: &mut BTreeMap<usize, Node>, f: &mut F) where F: FnMut(&mut Model) {
+    for node in children.values_mut() {
+        match node {
+            Node::Model(model) => f(model),
+            Node::Namespace(namespace) => for_each_model(&mut namespace.children, f),
+            _ => (),
+        }
+    }
+}
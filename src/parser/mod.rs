@@ -35,6 +35,7 @@ mod parse_handler_group;
 mod parse_struct_declaration;
 mod parse_function_declaration;
 mod parse_availability_flag;
+mod parse_availability_else;
 mod parse_availability_end;
 mod parse_use_middlewares_block;
 mod parse_named_expression;
@@ -49,5 +50,9 @@ mod parse_empty_decorator;
 mod parse_include_handler_from_template;
 mod parse_handler_template_declaration;
 mod parse_synthesized_shape_declaration;
+mod parse_type_alias_declaration;
 mod parse_type_as_value_expression;
-pub(super) mod parse;
\ No newline at end of file
+mod parse_syntax_error;
+mod extend_models;
+pub(super) mod parse;
+pub mod parse_options;
\ No newline at end of file
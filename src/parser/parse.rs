@@ -1,60 +1,301 @@
-use std::collections::{BTreeMap};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
 use maplit::btreemap;
+use pathdiff::diff_paths;
+use crate::ast::import::Import;
 use crate::ast::schema::{Schema, SchemaReferences};
 use crate::ast::source::Source;
+use crate::ast::span::Span;
 use crate::builtin::STD_TEO;
-use crate::diagnostics::diagnostics::Diagnostics;
+use crate::diagnostics::diagnostics::{Diagnostics, DiagnosticsError, DiagnosticsWarning};
+use crate::parser::extend_models::extend_models;
 use crate::parser::parse_builtin_source_file::parse_builtin_source_file;
 use crate::parser::parse_source_file::parse_source_file;
 use crate::parser::parser_context::ParserContext;
-use crate::utils::path::FileUtility;
+use crate::resolver::resolver_context::ResolverState;
+use crate::utils::environment::Environment;
+use crate::utils::path::{SchemaFileSystem, UnsavedFileSystem};
 
 pub fn parse(
     main: impl AsRef<str>,
-    file_util: FileUtility,
-    unsaved_files: Option<BTreeMap<String, String>>
+    file_system: Arc<dyn SchemaFileSystem>,
+    unsaved_files: Option<BTreeMap<String, String>>,
+    library_roots: Vec<String>,
+    profiles: BTreeSet<String>,
+    environment: Option<Environment>,
 ) -> (Schema, Diagnostics) {
-    let mut parser_context = ParserContext::new(Diagnostics::new(), SchemaReferences::new(), file_util, unsaved_files);
-    let mut sources = btreemap!{};
+    let file_system = Arc::new(UnsavedFileSystem::new(file_system, unsaved_files.unwrap_or_default()));
+    let mut unreadable_environment_file = None;
+    let environment_origin = environment;
+    let environment = environment_origin.as_ref().map(|environment| environment.load(file_system.as_ref(), main.as_ref()).unwrap_or_else(|file_path| {
+        unreadable_environment_file = Some(file_path);
+        BTreeMap::new()
+    }));
+    let mut parser_context = ParserContext::new(Diagnostics::new(), SchemaReferences::new(), file_system, library_roots, profiles, environment);
+    let mut collector = SourceCollector::new(None);
     if !main.as_ref().ends_with("builtin/std.teo") {
         // std library
-        let std_source = parse_builtin_source_file(
+        collector.parse_source(&parser_context, |context| parse_builtin_source_file(
             STD_TEO,
             "(builtin)std.teo",
-            &mut parser_context
-        );
-        sources.insert(std_source.id, std_source);
+            context
+        ));
     }
     // user schema
     parse_user_source(
-        &mut sources,
+        &mut collector,
         main.as_ref(),
-        &(parser_context.file_util.parent_directory)(main.as_ref()),
+        &parser_context.file_system.parent(main.as_ref()),
         &mut parser_context
     );
-    let schema = Schema { sources, references: parser_context.schema_references_mut().clone() };
-    let x = (schema, parser_context.diagnostics().clone());
-    x
+    let (schema, mut diagnostics) = collector.build_schema(&parser_context, environment_origin);
+    if let Some(file_path) = unreadable_environment_file {
+        diagnostics.insert(DiagnosticsError::new(
+            Span::default(),
+            format!("cannot read environment file {}", file_path),
+            main.as_ref(),
+        ));
+    }
+    (schema, diagnostics)
+}
+
+/// Parse the schema of `previous` again after the files at `changed_paths` and the files in
+/// `unsaved_files` are changed. Sources of the other files, including the builtin ones, are
+/// taken over from `previous` instead of being read and parsed again. The active profiles and
+/// the environment of `previous` are kept, a changed `.env` file is read again, the sources
+/// with environment conditions are parsed again and the sources reading `ENV` are resolved again. Every user source is parsed again when the library roots
+/// differ from the ones of `previous`.
+pub fn reparse(
+    mut previous: Schema,
+    changed_paths: Vec<String>,
+    file_system: Arc<dyn SchemaFileSystem>,
+    unsaved_files: Option<BTreeMap<String, String>>,
+    library_roots: Vec<String>,
+) -> (Schema, Diagnostics) {
+    let profiles = previous.profiles.clone();
+    let file_system = Arc::new(UnsavedFileSystem::new(file_system, unsaved_files.unwrap_or_default()));
+    let main = previous.main_source().file_path.clone();
+    let mut changed: BTreeSet<String> = changed_paths.into_iter().collect();
+    changed.extend(file_system.unsaved_files().keys().cloned());
+    let environment_origin = previous.environment_origin.take();
+    let mut unreadable_environment_file = None;
+    let mut environment_changed = false;
+    let environment = match environment_origin.as_ref() {
+        Some(origin) if origin.file_path(file_system.as_ref(), &main).map_or(false, |file_path| changed.contains(&file_path)) => {
+            let environment = origin.load(file_system.as_ref(), &main).unwrap_or_else(|file_path| {
+                unreadable_environment_file = Some(file_path);
+                BTreeMap::new()
+            });
+            environment_changed = previous.environment.as_ref() != Some(&environment);
+            if environment_changed {
+                let readers = std::mem::take(&mut previous.resolver_state.environment_readers);
+                previous.resolver_state.resolved_sources.retain(|id| !readers.contains(id));
+            }
+            Some(environment)
+        }
+        _ => previous.environment.clone(),
+    };
+    let mut parser_context = ParserContext::new(Diagnostics::new(), SchemaReferences::new(), file_system, library_roots, profiles, environment);
+    parser_context.skip_ids_until(previous.last_id);
+    // adding or removing a file may change what an import resolves to
+    let added_or_removed: BTreeSet<&String> = changed.iter().filter(|file_path| {
+        previous.source_at_path(file_path).is_none() || !parser_context.file_system.exists(file_path)
+    }).collect();
+    let library_roots_changed = parser_context.library_roots != previous.library_roots;
+    // conditional blocks of environment variables are evaluated while parsing
+    let environment_conditioned: BTreeSet<usize> = if environment_changed {
+        previous.references.environment_flags.iter().map(|path| path[0]).collect()
+    } else {
+        BTreeSet::new()
+    };
+    let reusable = previous.sources().iter().filter(|source| {
+        source.builtin || (!library_roots_changed && !environment_conditioned.contains(&source.id) && is_source_reusable(source, &changed, &added_or_removed, parser_context.file_system.as_ref()))
+    }).map(|source| (source.file_path.clone(), source.id)).collect();
+    let builtin_source_ids = previous.references.builtin_sources.clone();
+    let mut collector = SourceCollector::new(Some((previous, reusable)));
+    for source_id in builtin_source_ids {
+        collector.reuse_source(source_id, &parser_context);
+    }
+    parse_user_source(
+        &mut collector,
+        &main,
+        &parser_context.file_system.parent(&main),
+        &mut parser_context
+    );
+    let (schema, mut diagnostics) = collector.build_schema(&parser_context, environment_origin);
+    if let Some(file_path) = unreadable_environment_file {
+        diagnostics.insert(DiagnosticsError::new(
+            Span::default(),
+            format!("cannot read environment file {}", file_path),
+            main,
+        ));
+    }
+    (schema, diagnostics)
+}
+
+fn is_source_reusable(source: &Source, changed: &BTreeSet<String>, added_or_removed: &BTreeSet<&String>, file_system: &dyn SchemaFileSystem) -> bool {
+    if changed.contains(&source.file_path) {
+        return false
+    }
+    added_or_removed.is_empty() || source.imports().iter().all(|import| {
+        is_single_file_import(import, &source.file_path, file_system) && !added_or_removed.contains(&import.file_paths[0])
+    })
+}
+
+// imports of globs, directories and library files may resolve to other files after files are
+// added or removed
+fn is_single_file_import(import: &Import, source_path: &str, file_system: &dyn SchemaFileSystem) -> bool {
+    let Some(import_path) = import.source().value() else {
+        return false
+    };
+    let file_path = file_system.import_path(source_path, import_path);
+    import.file_paths.len() == 1 && (import.file_paths[0] == file_path || import.file_paths[0] == format!("{file_path}.teo"))
+}
+
+struct SourceCollector {
+    sources: BTreeMap<usize, Source>,
+    parser_diagnostics: BTreeMap<usize, Diagnostics>,
+    previous: Option<(Schema, BTreeMap<String, usize>)>,
+}
+
+impl SourceCollector {
+
+    fn new(previous: Option<(Schema, BTreeMap<String, usize>)>) -> Self {
+        Self { sources: btreemap!{}, parser_diagnostics: btreemap!{}, previous }
+    }
+
+    fn reusable_source_id(&self, path: &str) -> Option<usize> {
+        self.previous.as_ref().and_then(|(_, reusable)| reusable.get(path).cloned())
+    }
+
+    fn parse_source<F>(&mut self, parser_context: &ParserContext, parse: F) -> usize where F: FnOnce(&ParserContext) -> Source {
+        let errors = parser_context.diagnostics().errors().len();
+        let warnings = parser_context.diagnostics().warnings().len();
+        let source = parse(parser_context);
+        let mut diagnostics = Diagnostics::new();
+        for error in &parser_context.diagnostics().errors()[errors..] {
+            diagnostics.insert(error.clone());
+        }
+        for warning in &parser_context.diagnostics().warnings()[warnings..] {
+            diagnostics.insert(warning.clone());
+        }
+        let source_id = source.id;
+        self.parser_diagnostics.insert(source_id, diagnostics);
+        self.sources.insert(source_id, source);
+        source_id
+    }
+
+    fn reuse_source(&mut self, source_id: usize, parser_context: &ParserContext) -> usize {
+        let (previous, _) = self.previous.as_mut().unwrap();
+        let source = previous.sources.remove(&source_id).unwrap();
+        parser_context.reuse_source(source_id, source.file_path.clone(), &previous.references);
+        let diagnostics = previous.parser_diagnostics.remove(&source_id).unwrap_or_default();
+        for error in diagnostics.errors() {
+            parser_context.diagnostics_mut().insert(error.clone());
+        }
+        for warning in diagnostics.warnings() {
+            parser_context.diagnostics_mut().insert(warning.clone());
+        }
+        self.parser_diagnostics.insert(source_id, diagnostics);
+        self.sources.insert(source_id, source);
+        source_id
+    }
+
+    fn build_schema(self, parser_context: &ParserContext, environment_origin: Option<Environment>) -> (Schema, Diagnostics) {
+        let mut schema = Schema {
+            sources: self.sources,
+            references: parser_context.schema_references().clone(),
+            last_id: 0,
+            parser_diagnostics: self.parser_diagnostics,
+            file_system: parser_context.file_system.clone(),
+            library_roots: parser_context.library_roots.clone(),
+            profiles: parser_context.profiles.clone(),
+            environment: parser_context.environment.clone(),
+            environment_origin,
+            resolver_state: self.previous.map_or_else(ResolverState::default, |(previous, _)| previous.resolver_state),
+        };
+        extend_models(&mut schema, parser_context);
+        schema.last_id = parser_context.last_id();
+        let mut diagnostics = parser_context.diagnostics().clone();
+        insert_circular_import_warnings(&schema, parser_context, &mut diagnostics);
+        (schema, diagnostics)
+    }
 }
 
 fn parse_user_source(
-    sources: &mut BTreeMap<usize, Source>,
+    collector: &mut SourceCollector,
     path: impl AsRef<str>,
     base: &str,
     parser_context: &mut ParserContext
 ) {
-    let source = parse_source_file(path, base, parser_context);
-    let source_id = source.id;
-    sources.insert(source.id, source);
+    let source_id = match collector.reusable_source_id(path.as_ref()) {
+        Some(source_id) => collector.reuse_source(source_id, parser_context),
+        None => collector.parse_source(parser_context, |context| parse_source_file(path, base, context)),
+    };
     if parser_context.schema_references_mut().main_source.is_none() {
         parser_context.schema_references_mut().main_source = Some(source_id);
     }
-    let import_paths: Vec<String> = sources.get(&source_id).unwrap().imports().iter().map(|i| i.file_path.clone()).collect();
+    let import_paths: Vec<String> = collector.sources.get(&source_id).unwrap().imports().iter().flat_map(|i| i.file_paths.clone()).collect();
     for import in import_paths {
         if !parser_context.is_source_parsing_or_parsed(&import) {
-            if (parser_context.file_util.file_exists)(&import) && (!(parser_context.file_util.file_is_directory)(&import)) {
-                parse_user_source(sources, &import, base, parser_context);
+            if parser_context.file_system.exists(&import) && !parser_context.file_system.is_dir(&import) {
+                parse_user_source(collector, &import, base, parser_context);
             }
         }
     }
-}
\ No newline at end of file
+}
+
+fn insert_circular_import_warnings(schema: &Schema, parser_context: &ParserContext, diagnostics: &mut Diagnostics) {
+    if schema.references.main_source.is_none() {
+        return
+    }
+    let graph = schema.import_graph();
+    let base = parser_context.file_system.parent(&schema.main_source().file_path);
+    let mut examined = BTreeSet::new();
+    let mut reported: BTreeSet<Vec<String>> = BTreeSet::new();
+    let mut stack = vec![];
+    find_circular_imports(&schema.main_source().file_path, &graph, &mut stack, &mut examined, &mut |cycle: Vec<String>| {
+        // a cycle is reported once no matter which file it's entered from
+        let mut normalized = cycle[0..cycle.len() - 1].to_vec();
+        let start = normalized.iter().enumerate().min_by_key(|(_, p)| *p).map(|(i, _)| i).unwrap();
+        normalized.rotate_left(start);
+        if !reported.insert(normalized) {
+            return
+        }
+        let importer = &cycle[cycle.len() - 2];
+        let imported = &cycle[cycle.len() - 1];
+        let source = schema.source_at_path(importer).unwrap();
+        let import = source.imports().into_iter().find(|import| import.file_paths.contains(imported)).unwrap();
+        let display: Vec<String> = cycle.iter().map(|file_path| diff_paths(file_path, &base).map_or(file_path.clone(), |p| p.to_str().unwrap().to_owned())).collect();
+        diagnostics.insert(DiagnosticsWarning::new(
+            import.span,
+            format!("circular import: {}", display.join(" -> ")),
+            importer.clone(),
+        ));
+    });
+}
+
+fn find_circular_imports<'a, F>(
+    file_path: &'a String,
+    graph: &'a BTreeMap<String, Vec<String>>,
+    stack: &mut Vec<&'a String>,
+    examined: &mut BTreeSet<&'a String>,
+    report: &mut F,
+) where F: FnMut(Vec<String>) {
+    if let Some(index) = stack.iter().position(|p| *p == file_path) {
+        let mut cycle: Vec<String> = stack[index..].iter().map(|p| (*p).clone()).collect();
+        cycle.push(file_path.clone());
+        report(cycle);
+        return
+    }
+    if !examined.insert(file_path) {
+        return
+    }
+    stack.push(file_path);
+    if let Some(imported) = graph.get(file_path) {
+        for imported_path in imported {
+            find_circular_imports(imported_path, graph, stack, examined, report);
+        }
+    }
+    stack.pop();
+}
@@ -5,7 +5,8 @@ use crate::ast::argument::Argument;
 use crate::ast::argument_list::ArgumentList;
 
 
-use crate::{parse_insert_punctuation, parse_container_node_variables, parse_insert, parse_set, parse_set_optional, parse_container_node_variables_cleanup};
+use crate::{parse_append, parse_insert_punctuation, parse_container_node_variables, parse_insert, parse_set, parse_set_optional, parse_container_node_variables_cleanup};
+use crate::parser::parse_code_comment::parse_code_comment;
 use crate::parser::parse_expression::parse_expression;
 use crate::parser::parse_identifier::parse_identifier;
 use crate::parser::parse_partial_argument::parse_partial_argument;
@@ -25,6 +26,7 @@ pub(super) fn parse_argument_list(pair: Pair<'_>, context: &ParserContext) -> Ar
             Rule::PAREN_OPEN => parse_insert_punctuation!(context, current, children, "("),
             Rule::PAREN_CLOSE => parse_insert_punctuation!(context, current, children, ")"),
             Rule::COMMA => parse_insert_punctuation!(context, current, children, ","),
+            Rule::double_comment_block => parse_append!(parse_code_comment(current, context), children),
             _ => context.insert_unparsed(parse_span(&current)),
         }
     }
@@ -1,4 +1,5 @@
-use crate::ast::arith_expr::{ArithExpr, BinaryOperation, ArithExprOperator, UnaryOperation, UnaryPostfixOperation};
+use crate::ast::arith_expr::{ArithExpr, BinaryOperation, ArithExprOperator, TernaryOperation, UnaryOperation, UnaryPostfixOperation};
+use crate::ast::span::Span;
 use crate::{parse_container_node_variables_cleanup, parse_container_node_variables_without_span, parse_insert_operator};
 use crate::parser::parse_expression::parse_expression;
 use crate::parser::parse_span::parse_span;
@@ -8,6 +9,45 @@ use crate::traits::identifiable::Identifiable;
 
 pub(super) fn parse_arith_expr(pair: Pair<'_>, context: &ParserContext) -> ArithExpr {
     let span = parse_span(&pair);
+    let mut pairs: Vec<Pair<'_>> = pair.into_inner().collect();
+    let Some(then_index) = pairs.iter().position(|p| p.as_rule() == Rule::TERNARY_THEN) else {
+        return parse_operations(pairs, span, context);
+    };
+    let branches = pairs.split_off(then_index);
+    let condition_span = parse_span(pairs.first().unwrap()).merge(&parse_span(pairs.last().unwrap()));
+    let (path, mut children) = parse_container_node_variables_without_span!(context);
+    let condition = parse_operations(pairs, condition_span, context);
+    let condition_id = condition.id();
+    children.insert(condition_id, condition.into());
+    let mut consequent = 0;
+    let mut alternative = 0;
+    for current in branches {
+        match current.as_rule() {
+            Rule::TERNARY_THEN | Rule::TERNARY_ELSE => parse_insert_operator!(context, current, children, current.as_str()),
+            Rule::expression => {
+                let expression = parse_expression(current, context);
+                if consequent == 0 {
+                    consequent = expression.id();
+                } else {
+                    alternative = expression.id();
+                }
+                children.insert(expression.id(), expression.into());
+            },
+            _ => context.insert_unparsed(parse_span(&current)),
+        }
+    }
+    parse_container_node_variables_cleanup!(context);
+    ArithExpr::TernaryOperation(TernaryOperation {
+        span,
+        path,
+        children,
+        condition: condition_id,
+        consequent,
+        alternative,
+    })
+}
+
+fn parse_operations(pairs: Vec<Pair<'_>>, span: Span, context: &ParserContext) -> ArithExpr {
     let result = EXPR_PRATT_PARSER.map_primary(|primary| match primary.as_rule() {
         Rule::operand => {
             let expression = parse_expression(primary, context);
@@ -96,6 +136,6 @@ pub(super) fn parse_arith_expr(pair: Pair<'_>, context: &ParserContext) -> Arith
         };
         parse_container_node_variables_cleanup!(context);
         ArithExpr::UnaryPostfixOperation(operation)
-    }).parse(pair.into_inner());
+    }).parse(pairs.into_iter());
     result
 }
\ No newline at end of file
@@ -0,0 +1,18 @@
+use crate::ast::availability_flag_else::AvailabilityFlagElse;
+use crate::parse_node_variables;
+use crate::parser::parse_span::parse_span;
+use crate::parser::parser_context::ParserContext;
+use crate::parser::pest_parser::Pair;
+
+pub(super) fn parse_availability_else(pair: Pair<'_>, context: &ParserContext) -> AvailabilityFlagElse {
+    let (span, path) = parse_node_variables!(pair, context);
+    if let Some(flag) = context.else_availability_flag(span) {
+        if flag.is_none() {
+            context.insert_error(span, "unreachable availability else");
+        }
+    }
+    AvailabilityFlagElse {
+        span,
+        path,
+    }
+}
@@ -7,64 +7,31 @@ use crate::parser::pest_parser::{Pair, Rule};
 
 pub(super) fn parse_availability_flag(pair: Pair<'_>, context: &ParserContext) -> AvailabilityFlag {
     let (span, path) = parse_node_variables!(pair, context);
-    let mut name = String::new();
+    let mut condition = String::new();
+    let mut expression = String::new();
     for current in pair.into_inner() {
         match current.as_rule() {
-            Rule::identifier => {
-                name = current.as_str().to_owned();
-                match current.as_str() {
-                    "noDatabase" => {
-                        let flag = Availability::no_database();
-                        let result = context.push_availability_flag(flag);
-                        if result.is_none() {
-                            context.insert_error(parse_span(&current), "unreachable availability flag");
-                        }
-                    }
-                    "database" => {
-                        let flag = Availability::database();
-                        let result = context.push_availability_flag(flag);
-                        if result.is_none() {
-                            context.insert_error(parse_span(&current), "unreachable availability flag");
-                        }
-                    }
-                    "mongo" => {
-                        let flag = Availability::mongo();
-                        let result = context.push_availability_flag(flag);
-                        if result.is_none() {
-                            context.insert_error(parse_span(&current), "unreachable availability flag");
-                        }
-                    },
-                    "sql" => {
-                        let flag = Availability::sql();
-                        let result = context.push_availability_flag(flag);
-                        if result.is_none() {
-                            context.insert_error(parse_span(&current), "unreachable availability flag");
-                        }
-                    },
-                    "mysql" => {
-                        let flag = Availability::mysql();
-                        let result = context.push_availability_flag(flag);
-                        if result.is_none() {
-                            context.insert_error(parse_span(&current), "unreachable availability flag");
-                        }
-                    },
-                    "postgres" => {
-                        let flag = Availability::postgres();
-                        let result = context.push_availability_flag(flag);
-                        if result.is_none() {
-                            context.insert_error(parse_span(&current), "unreachable availability flag");
-                        }
-                    },
-                    "sqlite" => {
-                        let flag = Availability::sqlite();
-                        let result = context.push_availability_flag(flag);
-                        if result.is_none() {
-                            context.insert_error(parse_span(&current), "unreachable availability flag");
-                        }
-                    },
-                    _ => {
-                        context.insert_error(parse_span(&current), "unknown availability flag");
-                        context.push_availability_flag(Availability::none());
+            Rule::availability_condition => condition = current.as_str().to_owned(),
+            Rule::availability_expression => {
+                let expression_span = parse_span(&current);
+                let kind = match condition.as_str() {
+                    "profile" => ConditionKind::Profile,
+                    "env" => ConditionKind::Environment,
+                    _ => ConditionKind::Database,
+                };
+                let mut has_unknown_flag = false;
+                let flag = parse_availability_expression(current, kind, &mut expression, &mut has_unknown_flag, context);
+                if kind == ConditionKind::Environment {
+                    context.schema_references_mut().environment_flags.push(path.clone());
+                }
+                if kind != ConditionKind::Database {
+                    // profile and environment expressions are evaluated with `default` as true
+                    // and `none` as false
+                    context.push_profile_flag(!flag.is_none(), span);
+                } else {
+                    let result = context.push_availability_flag(if has_unknown_flag { Availability::none() } else { flag });
+                    if result.is_none() && !has_unknown_flag {
+                        context.insert_error(expression_span, "unreachable availability flag");
                     }
                 }
             }
@@ -74,6 +41,102 @@ pub(super) fn parse_availability_flag(pair: Pair<'_>, context: &ParserContext) -
     AvailabilityFlag {
         span,
         path,
-        name,
+        condition,
+        expression,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ConditionKind {
+    Database,
+    Profile,
+    Environment,
+}
+
+fn parse_availability_expression(pair: Pair<'_>, kind: ConditionKind, expression: &mut String, has_unknown_flag: &mut bool, context: &ParserContext) -> Availability {
+    let mut flag = Availability::none();
+    for current in pair.into_inner() {
+        match current.as_rule() {
+            Rule::availability_conjunction => flag = flag.bi_or(parse_availability_conjunction(current, kind, expression, has_unknown_flag, context)),
+            Rule::OR => expression.push_str(" || "),
+            _ => (),
+        }
+    }
+    flag
+}
+
+fn parse_availability_conjunction(pair: Pair<'_>, kind: ConditionKind, expression: &mut String, has_unknown_flag: &mut bool, context: &ParserContext) -> Availability {
+    let mut flag = Availability::default();
+    for current in pair.into_inner() {
+        match current.as_rule() {
+            Rule::availability_operand => flag = flag.bi_and(parse_availability_operand(current, kind, expression, has_unknown_flag, context)),
+            Rule::AND => expression.push_str(" && "),
+            _ => (),
+        }
+    }
+    flag
+}
+
+fn parse_availability_operand(pair: Pair<'_>, kind: ConditionKind, expression: &mut String, has_unknown_flag: &mut bool, context: &ParserContext) -> Availability {
+    let mut negated = false;
+    let mut flag = Availability::none();
+    for current in pair.into_inner() {
+        match current.as_rule() {
+            Rule::NOT => {
+                expression.push('!');
+                negated = !negated;
+            },
+            Rule::PAREN_OPEN => expression.push('('),
+            Rule::PAREN_CLOSE => expression.push(')'),
+            Rule::availability_expression => flag = parse_availability_expression(current, kind, expression, has_unknown_flag, context),
+            Rule::identifier => {
+                expression.push_str(current.as_str());
+                flag = match kind {
+                    ConditionKind::Database => database_flag(current, has_unknown_flag, context),
+                    ConditionKind::Profile => profile_flag(current.as_str(), context),
+                    ConditionKind::Environment => environment_flag(current.as_str(), context),
+                };
+            },
+            _ => (),
+        }
+    }
+    if negated {
+        flag.bi_not()
+    } else {
+        flag
+    }
+}
+
+fn profile_flag(name: &str, context: &ParserContext) -> Availability {
+    if context.profiles.contains(name) {
+        Availability::default()
+    } else {
+        Availability::none()
+    }
+}
+
+// a variable is true when it's set, variables of an unknown environment are never set
+fn environment_flag(name: &str, context: &ParserContext) -> Availability {
+    if context.environment.as_ref().map_or(false, |environment| environment.contains_key(name)) {
+        Availability::default()
+    } else {
+        Availability::none()
+    }
+}
+
+fn database_flag(pair: Pair<'_>, has_unknown_flag: &mut bool, context: &ParserContext) -> Availability {
+    match pair.as_str() {
+        "noDatabase" => Availability::no_database(),
+        "database" => Availability::database(),
+        "mongo" => Availability::mongo(),
+        "sql" => Availability::sql(),
+        "mysql" => Availability::mysql(),
+        "postgres" => Availability::postgres(),
+        "sqlite" => Availability::sqlite(),
+        _ => {
+            context.insert_error(parse_span(&pair), "unknown availability flag");
+            *has_unknown_flag = true;
+            Availability::none()
+        }
     }
-}
\ No newline at end of file
+}
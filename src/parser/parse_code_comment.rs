@@ -13,6 +13,9 @@ pub(super) fn parse_code_comment(pair: Pair<'_>, context: &ParserContext) -> Cod
             Rule::double_comment => {
                 lines.push(parse_comment_line(current, context));
             },
+            Rule::block_comment => {
+                lines.push(current.as_str().trim_start().to_owned());
+            },
             _ => context.insert_unparsed(parse_span(&current)),
         }
     }
@@ -1,6 +1,7 @@
 use crate::ast::config::Config;
 use crate::ast::keyword::Keyword;
 use crate::{parse_append, parse_container_node_variables, parse_container_node_variables_cleanup, parse_insert_punctuation, parse_node_variables, parse_set, parse_set_optional};
+use crate::parser::parse_availability_else::parse_availability_else;
 use crate::parser::parse_availability_end::parse_availability_end;
 use crate::parser::parse_availability_flag::parse_availability_flag;
 use crate::parser::parse_code_comment::parse_code_comment;
@@ -8,6 +9,7 @@ use crate::parser::parse_doc_comment::parse_doc_comment;
 use crate::parser::parse_identifier::parse_identifier;
 use crate::parser::parse_literals::parse_dictionary_literal;
 use crate::parser::parse_span::parse_span;
+use crate::parser::parse_syntax_error::SyntaxErrorRecovery;
 use crate::parser::parser_context::ParserContext;
 use crate::parser::pest_parser::{Pair, Rule};
 
@@ -25,6 +27,7 @@ pub(super) fn parse_config_block(pair: Pair<'_>, context: &ParserContext) -> Con
     let mut dictionary_literal = 0;
     let mut inside_block = false;
     let mut unattached_identifiers = vec![];
+    let mut recovery = SyntaxErrorRecovery::new();
     for current in pair.into_inner() {
         match current.as_rule() {
             Rule::BLOCK_OPEN => {
@@ -43,8 +46,9 @@ pub(super) fn parse_config_block(pair: Pair<'_>, context: &ParserContext) -> Con
             Rule::triple_comment_block => parse_append!(parse_doc_comment(current, context), children),
             Rule::double_comment_block => parse_append!(parse_code_comment(current, context), children),
             Rule::availability_start => parse_append!(parse_availability_flag(current, context), children),
+            Rule::availability_else => parse_append!(parse_availability_else(current, context), children),
             Rule::availability_end => parse_append!(parse_availability_end(current, context), children),
-            Rule::BLOCK_LEVEL_CATCH_ALL => context.insert_unparsed(parse_span(&current)),
+            Rule::BLOCK_LEVEL_CATCH_ALL => recovery.insert_syntax_error(&current, &[Rule::named_expression], context),
             _ => context.insert_unparsed(parse_span(&current)),
         }
     }
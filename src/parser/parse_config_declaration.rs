@@ -1,5 +1,6 @@
 use crate::ast::config_declaration::ConfigDeclaration;
 use crate::{parse_append, parse_container_node_variables, parse_container_node_variables_cleanup, parse_insert, parse_insert_keyword, parse_insert_punctuation, parse_set_optional};
+use crate::parser::parse_availability_else::parse_availability_else;
 use crate::parser::parse_availability_end::parse_availability_end;
 use crate::parser::parse_availability_flag::parse_availability_flag;
 use crate::parser::parse_code_comment::parse_code_comment;
@@ -8,6 +9,7 @@ use crate::parser::parse_field::parse_field;
 use crate::parser::parse_identifier::parse_identifier;
 use crate::parser::parse_partial_field::parse_partial_field;
 use crate::parser::parse_span::parse_span;
+use crate::parser::parse_syntax_error::SyntaxErrorRecovery;
 use crate::parser::parser_context::ParserContext;
 use crate::parser::pest_parser::{Pair, Rule};
 use crate::traits::identifiable::Identifiable;
@@ -27,6 +29,7 @@ pub(super) fn parse_config_declaration(pair: Pair<'_>, context: &ParserContext)
     let mut fields: Vec<usize> = vec![];
     let mut partial_fields: Vec<usize> = vec![];
     let mut inside_block = false;
+    let mut recovery = SyntaxErrorRecovery::new();
     for current in pair.into_inner() {
         match current.as_rule() {
             Rule::triple_comment_block => if !inside_block {
@@ -55,8 +58,9 @@ pub(super) fn parse_config_declaration(pair: Pair<'_>, context: &ParserContext)
             Rule::field_declaration => parse_insert!(parse_field(current, context), children, fields),
             Rule::partial_field => parse_insert!(parse_partial_field(current, context), children, partial_fields),
             Rule::availability_start => parse_append!(parse_availability_flag(current, context), children),
+            Rule::availability_else => parse_append!(parse_availability_else(current, context), children),
             Rule::availability_end => parse_append!(parse_availability_end(current, context), children),
-            Rule::BLOCK_LEVEL_CATCH_ALL => context.insert_unparsed(parse_span(&current)),
+            Rule::BLOCK_LEVEL_CATCH_ALL => recovery.insert_syntax_error(&current, &[Rule::field_declaration], context),
             _ => context.insert_unparsed(parse_span(&current)),
         }
     }
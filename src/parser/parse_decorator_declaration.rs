@@ -7,6 +7,7 @@ use crate::parser::parse_doc_comment::parse_doc_comment;
 use crate::parser::parse_code_comment::parse_code_comment;
 use crate::parser::parse_generics::{parse_generics_constraint, parse_generics_declaration};
 use crate::parser::parse_span::parse_span;
+use crate::parser::parse_syntax_error::SyntaxErrorRecovery;
 use crate::parser::parser_context::ParserContext;
 use crate::parser::pest_parser::{Pair, Rule};
 
@@ -36,6 +37,7 @@ pub(super) fn parse_decorator_declaration(pair: Pair<'_>, context: &ParserContex
     let mut generics_constraint = None;
     let mut variants = vec![];
     let mut inside_block = false;
+    let mut recovery = SyntaxErrorRecovery::new();
     for current in pair.into_inner() {
         match current.as_rule() {
             Rule::BLOCK_OPEN => {
@@ -108,6 +110,7 @@ pub(super) fn parse_decorator_declaration(pair: Pair<'_>, context: &ParserContex
             Rule::argument_list_declaration => parse_set_optional!(parse_argument_list_declaration(current, context), children, argument_list_declaration),
             Rule::generics_constraint => parse_set_optional!(parse_generics_constraint(current, context), children, generics_constraint),
             Rule::decorator_variant_declaration => parse_insert!(parse_decorator_variant_declaration(current, context), children, variants),
+            Rule::BLOCK_LEVEL_CATCH_ALL => recovery.insert_syntax_error(&current, &[Rule::decorator_variant_declaration], context),
             _ => context.insert_unparsed(parse_span(&current)),
         }
     }
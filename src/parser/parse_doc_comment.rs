@@ -8,16 +8,41 @@ pub(super) fn parse_doc_comment(pair: Pair<'_>, context: &ParserContext) -> DocC
     let path = context.next_path();
     let mut name = None;
     let mut desc = "".to_owned();
+    let mut params = vec![];
+    let mut examples: Vec<String> = vec![];
+    let mut deprecated = None;
+    let mut see = vec![];
+    let mut since = None;
+    let mut inside_example = false;
     for current in pair.into_inner() {
         match current.as_rule() {
             Rule::triple_comment => {
                 let (token, doc) = parse_comment_line(current, context);
                 if let Some(token) = token {
-                    if &token == "@name" {
-                        name = Some(doc);
-                    } else if &token == "@description" {
-                        desc = append(desc, doc)
+                    inside_example = false;
+                    match token.as_str() {
+                        "@name" => name = Some(doc),
+                        "@description" => desc = append(desc, doc),
+                        "@param" => if let Some((param_name, param_desc)) = doc.split_once(char::is_whitespace) {
+                            params.push((param_name.to_owned(), param_desc.trim().to_owned()));
+                        } else if !doc.is_empty() {
+                            params.push((doc, "".to_owned()));
+                        },
+                        "@example" => {
+                            examples.push(doc);
+                            inside_example = true;
+                        },
+                        "@deprecated" => deprecated = Some(doc),
+                        "@see" => see.push(doc),
+                        "@since" => since = Some(doc),
+                        _ => (),
                     }
+                } else if inside_example {
+                    let example = examples.last_mut().unwrap();
+                    if !example.is_empty() {
+                        example.push('\n');
+                    }
+                    example.push_str(&doc);
                 } else {
                     desc = append(desc, doc)
                 }
@@ -32,6 +57,11 @@ pub(super) fn parse_doc_comment(pair: Pair<'_>, context: &ParserContext) -> DocC
         path,
         name,
         desc: if desc.is_empty() { None } else { Some(desc) },
+        params,
+        examples,
+        deprecated,
+        see,
+        since,
     }
 }
 
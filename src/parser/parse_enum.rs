@@ -9,6 +9,7 @@ use crate::parser::parse_doc_comment::parse_doc_comment;
 use crate::parser::parse_decorator::parse_decorator;
 use crate::parser::parse_literals::{parse_numeric_literal, parse_string_literal};
 use crate::parser::parse_span::parse_span;
+use crate::parser::parse_syntax_error::SyntaxErrorRecovery;
 use crate::parser::parser_context::ParserContext;
 use crate::parser::pest_parser::{Pair, Rule};
 
@@ -28,6 +29,7 @@ pub(super) fn parse_enum_declaration(pair: Pair<'_>, context: &ParserContext) ->
     let mut identifier = 0;
     let mut members = vec![];
     let mut inside_block = false;
+    let mut recovery = SyntaxErrorRecovery::new();
     for current in pair.into_inner() {
         match current.as_rule() {
             Rule::ENUM_KEYWORD => parse_insert_keyword!(context, current, children, "enum"),
@@ -56,6 +58,7 @@ pub(super) fn parse_enum_declaration(pair: Pair<'_>, context: &ParserContext) ->
             Rule::empty_decorator => (),
             Rule::identifier => parse_set_identifier_and_string_path!(context, current, children, identifier, string_path),
             Rule::enum_member_declaration => parse_insert!(parse_enum_member(current, context, interface, option), children, members),
+            Rule::BLOCK_LEVEL_CATCH_ALL => recovery.insert_syntax_error(&current, &[Rule::enum_member_declaration], context),
             _ => context.insert_unparsed(parse_span(&current)),
         }
     }
@@ -7,7 +7,7 @@ use crate::parser::parse_empty_dot::parse_empty_dot;
 use crate::parser::parse_empty_pipeline::parse_empty_pipeline;
 use crate::parser::parse_group::parse_group;
 use crate::parser::parse_identifier::parse_identifier;
-use crate::parser::parse_literals::{parse_array_literal, parse_bool_literal, parse_dictionary_literal, parse_enum_variant_literal, parse_null_literal, parse_numeric_literal, parse_regex_literal, parse_string_literal, parse_tuple_literal};
+use crate::parser::parse_literals::{parse_array_literal, parse_bool_literal, parse_date_literal, parse_dictionary_literal, parse_enum_variant_literal, parse_null_literal, parse_numeric_literal, parse_regex_literal, parse_string_literal, parse_tuple_literal};
 use crate::parser::parse_pipeline::parse_pipeline;
 use crate::parser::parse_span::parse_span;
 use crate::parser::parse_subscript::{parse_int_subscript, parse_subscript};
@@ -37,9 +37,14 @@ pub(super) fn parse_unit(pair: Pair<'_>, context: &ParserContext) -> Unit {
     ) = parse_container_node_variables!(pair, context);
     let mut expressions = vec![];
     let mut empty_dot = None;
+    let mut optional_chained_indices = vec![];
     for current in pair.into_inner() {
         match current.as_rule() {
             Rule::DOT => parse_insert_punctuation!(context, current, children, "."),
+            Rule::OPTIONAL_CHAINING => {
+                parse_insert_punctuation!(context, current, children, "?.");
+                optional_chained_indices.push(expressions.len());
+            },
             Rule::empty_dot => parse_set_optional!(parse_empty_dot(current, context), children, empty_dot),
             Rule::group => parse_insert!(Expression::new(ExpressionKind::Group(parse_group(current, context))), children, expressions),
             Rule::null_literal => parse_insert!(Expression::new(ExpressionKind::NullLiteral(parse_null_literal(&current, context))), children, expressions),
@@ -47,6 +52,7 @@ pub(super) fn parse_unit(pair: Pair<'_>, context: &ParserContext) -> Unit {
             Rule::numeric_literal => parse_insert!(Expression::new(ExpressionKind::NumericLiteral(parse_numeric_literal(&current, context))), children, expressions),
             Rule::string_literal => parse_insert!(Expression::new(ExpressionKind::StringLiteral(parse_string_literal(&current, context))), children, expressions),
             Rule::regex_literal => parse_insert!(Expression::new(ExpressionKind::RegexLiteral(parse_regex_literal(current, context))), children, expressions),
+            Rule::date_literal => parse_insert!(Expression::new(ExpressionKind::DateLiteral(parse_date_literal(&current, context))), children, expressions),
             Rule::enum_variant_literal => parse_insert!(Expression::new(ExpressionKind::EnumVariantLiteral(parse_enum_variant_literal(current, context))), children, expressions),
             Rule::tuple_literal => parse_insert!(Expression::new(ExpressionKind::TupleLiteral(parse_tuple_literal(current, context))), children, expressions),
             Rule::array_literal => parse_insert!(Expression::new(ExpressionKind::ArrayLiteral(parse_array_literal(current, context))), children, expressions),
@@ -58,6 +64,7 @@ pub(super) fn parse_unit(pair: Pair<'_>, context: &ParserContext) -> Unit {
             _ => context.insert_unparsed(parse_span(&current)),
         }
     }
+    let optional_chained = optional_chained_indices.into_iter().filter_map(|index| expressions.get(index).cloned()).collect();
     parse_container_node_variables_cleanup!(context);
-    Unit { span, children, path, expressions, empty_dot }
+    Unit { span, children, path, expressions, empty_dot, optional_chained }
 }
\ No newline at end of file
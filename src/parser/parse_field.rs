@@ -38,6 +38,7 @@ pub(super) fn parse_field(pair: Pair<'_>, context: &ParserContext) -> Field {
     parse_container_node_variables_cleanup!(context, named);
     Field {
         span,
+        origin: path.clone(),
         path,
         string_path,
         children,
@@ -5,6 +5,7 @@ use crate::parser::parse_doc_comment::parse_doc_comment;
 use crate::parser::parse_decorator::parse_decorator;
 use crate::parser::parse_empty_decorator::parse_empty_decorator;
 use crate::parser::parse_span::parse_span;
+use crate::parser::parse_syntax_error::SyntaxErrorRecovery;
 use crate::parser::parse_type_expression::parse_type_expression;
 use crate::parser::parser_context::ParserContext;
 use crate::parser::pest_parser::{Pair, Rule};
@@ -25,6 +26,7 @@ pub(super) fn parse_handler_group_declaration(pair: Pair<'_>, context: &ParserCo
     let mut empty_decorators = vec![];
     let mut unattached_decorators = vec![];
     let mut decorators = vec![];
+    let mut recovery = SyntaxErrorRecovery::new();
     for current in pair.into_inner() {
         match current.as_rule() {
             Rule::DECLARE_KEYWORD => parse_insert_keyword!(context, current, children, "declare"),
@@ -51,6 +53,7 @@ pub(super) fn parse_handler_group_declaration(pair: Pair<'_>, context: &ParserCo
                 parse_insert!(parse_decorator(current, context), children, decorators)
             },
             Rule::empty_decorator => parse_insert!(parse_empty_decorator(current, context), children, empty_decorators),
+            Rule::BLOCK_LEVEL_CATCH_ALL => recovery.insert_syntax_error(&current, &[Rule::handler_declaration, Rule::decorator], context),
             _ => context.insert_unparsed(parse_span(&current)),
         }
     }
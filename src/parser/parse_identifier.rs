@@ -4,9 +4,11 @@ use crate::parser::parser_context::ParserContext;
 use super::pest_parser::Pair;
 
 pub(super) fn parse_identifier(pair: &Pair<'_>, context: &ParserContext) -> Identifier {
+    let escaped = pair.as_str().starts_with('`');
     Identifier {
         span: parse_span(pair),
         path: context.next_path(),
-        name: pair.as_str().to_owned(),
+        name: if escaped { pair.as_str().trim_matches('`').to_owned() } else { pair.as_str().to_owned() },
+        escaped,
     }
-}
\ No newline at end of file
+}
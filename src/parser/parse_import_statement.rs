@@ -1,54 +1,184 @@
+use std::collections::BTreeSet;
 use crate::availability::Availability;
 
 use crate::ast::import::Import;
-use crate::ast::literals::StringLiteral;
-
+use crate::{parse_container_node_variables, parse_container_node_variables_cleanup, parse_insert, parse_insert_keyword, parse_insert_punctuation, parse_set, parse_set_optional};
+use crate::parser::parse_identifier::parse_identifier;
 use crate::parser::parse_literals::parse_string_literal;
 use crate::parser::parse_span::parse_span;
 use crate::parser::parser_context::ParserContext;
 use crate::parser::pest_parser::{Pair, Rule};
+use crate::utils::glob::{is_glob_pattern, path_segments, segment_matches};
+use crate::utils::path::SchemaFileSystem;
 
 pub(super) fn parse_import_statement(pair: Pair<'_>, source_path: &str, context: &ParserContext) -> Import {
-    let span = parse_span(&pair);
+    let (
+        span,
+        path,
+        mut children,
+    ) = parse_container_node_variables!(pair, context);
     if context.current_availability_flag() != Availability::default() {
         context.insert_error(span, "import statement is placed in availability flag");
     }
-    let mut source: Option<StringLiteral> = None;
+    let mut source = 0;
+    let mut identifiers = None;
+    let mut as_identifier = None;
     for current in pair.into_inner() {
         match current.as_rule() {
-            Rule::string_literal => source = Some(parse_string_literal(&current, context)),
-            Rule::IMPORT_KEYWORD => (),
+            Rule::string_literal => parse_set!(parse_string_literal(&current, context), children, source),
+            Rule::IMPORT_KEYWORD => parse_insert_keyword!(context, current, children, "import"),
+            Rule::FROM_KEYWORD => parse_insert_keyword!(context, current, children, "from"),
+            Rule::AS_KEYWORD => parse_insert_keyword!(context, current, children, "as"),
+            Rule::identifier => parse_set_optional!(parse_identifier(&current, context), children, as_identifier),
+            Rule::import_identifier_list => {
+                let mut list = vec![];
+                for item in current.into_inner() {
+                    match item.as_rule() {
+                        Rule::BLOCK_OPEN => parse_insert_punctuation!(context, item, children, "{"),
+                        Rule::BLOCK_CLOSE => parse_insert_punctuation!(context, item, children, "}"),
+                        Rule::COMMA => parse_insert_punctuation!(context, item, children, ","),
+                        Rule::identifier => parse_insert!(parse_identifier(&item, context), children, list),
+                        _ => context.insert_unparsed(parse_span(&item)),
+                    }
+                }
+                identifiers = Some(list);
+            }
             _ => context.insert_unparsed(parse_span(&current)),
         }
     }
-    let mut file_path = context.file_util.import_path(source_path, source.as_ref().unwrap().value.as_str());
-    if let Some(file_found) = match_import_file(&file_path, context) {
-        file_path = file_found;
+    let source_literal = children.get(&source).unwrap().as_string_literal().unwrap();
+    let mut file_paths = vec![];
+    if let Some(import_path) = source_literal.value() {
+        let file_path = context.file_system.import_path(source_path, import_path);
+        if is_glob_pattern(&file_path) {
+            file_paths = expand_import_pattern(&file_path, source_path, context);
+            if file_paths.is_empty() {
+                context.insert_error(source_literal.span, "ImportError: no file matches the pattern")
+            }
+        } else if let Some(file_found) = match_import_file(&file_path, context) {
+            file_paths.push(file_found);
+        } else if context.file_system.is_dir(&file_path) {
+            file_paths = list_schema_files_in_directory(&file_path, source_path, context);
+            if file_paths.is_empty() {
+                context.insert_error(source_literal.span, "ImportError: directory doesn't contain any schema file")
+            }
+        } else if let Some(file_found) = match_library_import_file(import_path, context) {
+            file_paths.push(file_found);
+        } else if is_library_import(import_path, context) && !context.library_roots.is_empty() {
+            context.insert_error(span, format!("ImportError: file doesn't exist, searched library roots: {}", context.library_roots.join(", ")))
+        } else {
+            context.insert_error(span, "ImportError: file doesn't exist")
+        }
     } else {
-        context.insert_error(source.as_ref().unwrap().span.clone(), "ImportError: file doesn't exist")
+        context.insert_error(source_literal.span, "ImportError: import path cannot be interpolated")
     }
+    if let Some(identifiers) = &identifiers {
+        let mut examined_names: Vec<&str> = vec![];
+        for identifier_id in identifiers {
+            let identifier = children.get(identifier_id).unwrap().as_identifier().unwrap();
+            if examined_names.contains(&identifier.name()) {
+                context.insert_error(identifier.span, "Duplicated import name");
+            } else {
+                examined_names.push(identifier.name());
+            }
+        }
+    }
+    parse_container_node_variables_cleanup!(context);
     Import {
-        path: context.next_path(),
-        source: source.unwrap(),
         span,
-        file_path,
+        path,
+        children,
+        source,
+        identifiers,
+        as_identifier,
+        file_paths,
     }
 }
 
+/// The file at the path, the path with the `.teo` extension, or the `index.teo` of the directory
+/// at the path. A directory with an index file is imported through the index file only.
 fn match_import_file(original: &str, context: &ParserContext) -> Option<String> {
-    if (context.file_util.file_exists)(original) && !(context.file_util.file_is_directory)(original) {
+    if context.file_system.exists(original) && !context.file_system.is_dir(original) {
         Some(original.to_string())
     } else {
         let append_extension = format!("{original}.teo");
-        if (context.file_util.file_exists)(&append_extension) && !(context.file_util.file_is_directory)(&append_extension) {
+        if context.file_system.exists(&append_extension) && !context.file_system.is_dir(&append_extension) {
             Some(append_extension)
         } else {
-            let index_teo = (context.file_util.path_join)(original, "index.teo");
-            if (context.file_util.file_exists)(&index_teo) && !(context.file_util.file_is_directory)(&index_teo) {
+            let index_teo = context.file_system.join(original, "index.teo");
+            if context.file_system.exists(&index_teo) && !context.file_system.is_dir(&index_teo) {
                 Some(index_teo)
             } else {
                 None
             }
         }
     }
-}
\ No newline at end of file
+}
+
+fn is_library_import(import: &str, context: &ParserContext) -> bool {
+    !import.starts_with('.') && !context.file_system.is_absolute(import)
+}
+
+/// Non-relative imports like `@acme/auth` are looked up in each library root in order.
+fn match_library_import_file(import: &str, context: &ParserContext) -> Option<String> {
+    if !is_library_import(import, context) {
+        return None;
+    }
+    context.library_roots.iter().find_map(|root| match_import_file(&context.file_system.join(root, import), context))
+}
+
+fn is_schema_file(file_path: &str, source_path: &str, context: &ParserContext) -> bool {
+    file_path != source_path && file_path.ends_with(".teo") && !context.file_system.is_dir(file_path)
+}
+
+/// Every schema file directly inside the directory, sorted by path.
+fn list_schema_files_in_directory(directory: &str, source_path: &str, context: &ParserContext) -> Vec<String> {
+    let mut result: Vec<String> = context.file_system.list_dir(directory).into_iter().filter(|file_path| is_schema_file(file_path, source_path, context)).collect();
+    result.sort();
+    result
+}
+
+/// Every schema file matching the pattern, sorted by path. `*` and `?` match within a path
+/// segment, `**` matches any number of nested directories.
+fn expand_import_pattern(pattern: &str, source_path: &str, context: &ParserContext) -> Vec<String> {
+    let segments = path_segments(pattern);
+    let first_pattern_index = segments.iter().position(|segment| is_glob_pattern(segment)).unwrap();
+    let mut base = segments[0..first_pattern_index].join("/");
+    if base.is_empty() {
+        base = ".".to_owned();
+    }
+    let mut result = BTreeSet::new();
+    expand_import_pattern_segments(&base, &segments[first_pattern_index..], context, &mut BTreeSet::new(), &mut result);
+    result.into_iter().filter(|file_path| is_schema_file(file_path, source_path, context)).collect()
+}
+
+// `visited` holds the canonical directories `**` has been expanded in with the number of
+// remaining segments, so that links back to a parent directory are not followed again
+fn expand_import_pattern_segments(directory: &str, segments: &[&str], context: &ParserContext, visited: &mut BTreeSet<(String, usize)>, result: &mut BTreeSet<String>) {
+    let Some(segment) = segments.first() else {
+        return
+    };
+    if *segment == "**" {
+        if !visited.insert((context.file_system.canonicalize(directory), segments.len())) {
+            return
+        }
+        expand_import_pattern_segments(directory, &segments[1..], context, visited, result);
+        for entry in context.file_system.list_dir(directory) {
+            if context.file_system.is_dir(&entry) {
+                expand_import_pattern_segments(&entry, segments, context, visited, result);
+            }
+        }
+        return
+    }
+    for entry in context.file_system.list_dir(directory) {
+        let name = *path_segments(&entry).last().unwrap();
+        if !segment_matches(segment, name) {
+            continue
+        }
+        if segments.len() == 1 {
+            result.insert(entry);
+        } else if context.file_system.is_dir(&entry) {
+            expand_import_pattern_segments(&entry, &segments[1..], context, visited, result);
+        }
+    }
+}
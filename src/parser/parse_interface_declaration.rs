@@ -2,6 +2,7 @@ use std::cell::RefCell;
 use crate::ast::interface::{InterfaceDeclaration, InterfaceDeclarationResolved};
 use crate::parser::parse_decorator::parse_decorator;
 use crate::{parse_append, parse_container_node_variables, parse_container_node_variables_cleanup, parse_insert, parse_insert_keyword, parse_insert_punctuation, parse_set_identifier_and_string_path, parse_set_optional};
+use crate::parser::parse_availability_else::parse_availability_else;
 use crate::parser::parse_availability_end::parse_availability_end;
 use crate::parser::parse_availability_flag::parse_availability_flag;
 use crate::parser::parse_code_comment::parse_code_comment;
@@ -10,6 +11,7 @@ use crate::parser::parse_field::parse_field;
 use crate::parser::parse_generics::{parse_generics_constraint, parse_generics_declaration};
 use crate::parser::parse_partial_field::parse_partial_field;
 use crate::parser::parse_span::parse_span;
+use crate::parser::parse_syntax_error::SyntaxErrorRecovery;
 use crate::parser::parse_type_expression::parse_type_expression;
 use crate::parser::parser_context::ParserContext;
 use crate::parser::pest_parser::{Pair, Rule};
@@ -35,6 +37,7 @@ pub(super) fn parse_interface_declaration(pair: Pair<'_>, context: &ParserContex
     let mut fields = vec![];
     let mut partial_fields = vec![];
     let mut inside_block = false;
+    let mut recovery = SyntaxErrorRecovery::new();
     for current in pair.into_inner() {
         match current.as_rule() {
             Rule::BLOCK_OPEN => {
@@ -69,7 +72,9 @@ pub(super) fn parse_interface_declaration(pair: Pair<'_>, context: &ParserContex
             Rule::field_declaration => parse_insert!(parse_field(current, context), children, fields),
             Rule::partial_field => parse_insert!(parse_partial_field(current, context), children, partial_fields),
             Rule::availability_start => parse_append!(parse_availability_flag(current, context), children),
+            Rule::availability_else => parse_append!(parse_availability_else(current, context), children),
             Rule::availability_end => parse_append!(parse_availability_end(current, context), children),
+            Rule::BLOCK_LEVEL_CATCH_ALL => recovery.insert_syntax_error(&current, &[Rule::field_declaration], context),
             _ => context.insert_unparsed(parse_span(&current)),
         }
     }
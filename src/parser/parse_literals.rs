@@ -1,10 +1,13 @@
 use std::str::FromStr;
+use bigdecimal::{BigDecimal, Zero};
 use snailquote::unescape;
 use regex::Regex;
+use chrono::{DateTime, NaiveDate, Utc};
 use crate::value::Value;
-use crate::ast::literals::{ArrayLiteral, BoolLiteral, DictionaryLiteral, EnumVariantLiteral, NullLiteral, NumericLiteral, RegexLiteral, StringLiteral, TupleLiteral};
+use crate::ast::literals::{ArrayLiteral, BoolLiteral, DateLiteral, DictionaryLiteral, EnumVariantLiteral, NullLiteral, NumericLiteral, RegexLiteral, StringLiteral, StringSegment, TupleLiteral};
 use crate::{parse_append, parse_container_node_variables, parse_container_node_variables_cleanup, parse_insert, parse_insert_punctuation, parse_node_variables, parse_set, parse_set_optional};
 use crate::parser::parse_argument::parse_argument_list;
+use crate::parser::parse_availability_else::parse_availability_else;
 use crate::parser::parse_availability_end::parse_availability_end;
 use crate::parser::parse_availability_flag::parse_availability_flag;
 use crate::parser::parse_code_comment::parse_code_comment;
@@ -19,12 +22,69 @@ use crate::traits::identifiable::Identifiable;
 
 pub(super) fn parse_string_literal(pair: &Pair<'_>, context: &ParserContext) -> StringLiteral {
     let (span, path) = parse_node_variables!(pair, context);
+    let segments = parse_string_segments(pair, context);
     StringLiteral {
         span,
         path,
         display: pair.as_str().to_owned(),
-        value: unescape(pair.as_str()).unwrap(),
+        value: if segments.iter().any(|segment| segment.is_interpolation()) {
+            None
+        } else {
+            Some(segments.iter().filter_map(|segment| segment.as_text()).collect())
+        },
+        segments,
+    }
+}
+
+fn parse_string_segments(pair: &Pair<'_>, context: &ParserContext) -> Vec<StringSegment> {
+    let mut segments = vec![];
+    for current in pair.clone().into_inner() {
+        match current.as_rule() {
+            Rule::string_content => segments.push(StringSegment::Text(unescape_string_content(current.as_str(), current.as_str(), &current, context))),
+            Rule::multiline_string_content => {
+                // a line break right after the opening quotes is not a part of the string
+                let content = if segments.is_empty() { current.as_str().strip_prefix("\r\n").or_else(|| current.as_str().strip_prefix("\n")).unwrap_or(current.as_str()) } else { current.as_str() };
+                segments.push(StringSegment::Text(unescape_string_content(&escape_unescaped_quotes(content), content, &current, context)));
+            },
+            Rule::raw_string_literal => segments.push(StringSegment::Text(current.as_str()[2..current.as_str().len() - 1].to_owned())),
+            Rule::multiline_string_literal => segments = parse_string_segments(&current, context),
+            Rule::string_interpolation => {
+                let names = current.clone().into_inner().filter(|p| p.as_rule() == Rule::identifier).map(|p| p.as_str().trim_matches('`').to_owned()).collect();
+                segments.push(StringSegment::Interpolation(parse_span(&current), names));
+            },
+            _ => context.insert_unparsed(parse_span(&current)),
+        }
+    }
+    segments
+}
+
+// an invalid escape sequence is reported and the raw text is kept
+fn unescape_string_content(content: &str, raw: &str, pair: &Pair<'_>, context: &ParserContext) -> String {
+    match unescape(&format!("\"{}\"", content)) {
+        Ok(text) => text,
+        Err(_) => {
+            context.insert_error(parse_span(pair), "invalid escape sequence");
+            raw.to_owned()
+        }
+    }
+}
+
+fn escape_unescaped_quotes(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                result.push(c);
+                if let Some(escaped) = chars.next() {
+                    result.push(escaped);
+                }
+            },
+            '"' => result.push_str("\\\""),
+            _ => result.push(c),
+        }
     }
+    result
 }
 
 pub(super) fn parse_null_literal(pair: &Pair<'_>, context: &ParserContext) -> NullLiteral {
@@ -58,18 +118,74 @@ pub(super) fn parse_regex_literal(pair: Pair<'_>, context: &ParserContext) -> Re
     }
 }
 
+pub(super) fn parse_date_literal(pair: &Pair<'_>, context: &ParserContext) -> DateLiteral {
+    let (span, path) = parse_node_variables!(pair, context);
+    let display = pair.as_str().to_owned();
+    let content = &display[1..];
+    let value = if content.contains('T') {
+        match DateTime::parse_from_rfc3339(content) {
+            Ok(datetime) => Value::DateTime(datetime.with_timezone(&Utc)),
+            Err(_) => {
+                context.insert_error(span.clone(), "invalid datetime");
+                Value::Null
+            }
+        }
+    } else {
+        match NaiveDate::parse_from_str(content, "%Y-%m-%d") {
+            Ok(date) => Value::Date(date),
+            Err(_) => {
+                context.insert_error(span.clone(), "invalid date");
+                Value::Null
+            }
+        }
+    };
+    DateLiteral {
+        span,
+        path,
+        value,
+        display,
+    }
+}
+
 pub(super) fn parse_numeric_literal(pair: &Pair<'_>, context: &ParserContext) -> NumericLiteral {
     let (span, path) = parse_node_variables!(pair, context);
     let str_value = pair.as_str();
     NumericLiteral {
         span, path,
         display: str_value.to_owned(),
-        value: if str_value.contains(".") { // default to float64
-            Value::Float(f64::from_str(&str_value).unwrap())
-        } else if let Ok(i32v) = i32::from_str(str_value) {
+        value: parse_numeric_value(str_value),
+    }
+}
+
+// integers out of the range of Int64 and floats out of the range of Float are kept as decimals,
+// the resolver reports the overflow against the expected type
+fn parse_numeric_value(str_value: &str) -> Value {
+    let digits = str_value.replace("_", "");
+    let (negative, digits) = match digits.strip_prefix("-") {
+        Some(digits) => (true, digits.to_owned()),
+        None => (false, digits),
+    };
+    let radix = if digits.starts_with("0x") { 16 } else if digits.starts_with("0b") { 2 } else { 10 };
+    if radix == 10 && (digits.contains(".") || digits.contains(['e', 'E'])) { // default to float64
+        let float = f64::from_str(&digits).unwrap();
+        return if float.is_finite() {
+            Value::Float(if negative { -float } else { float })
+        } else {
+            let decimal = BigDecimal::from_str(&digits).unwrap();
+            Value::Decimal(if negative { -decimal } else { decimal })
+        }
+    }
+    let digits = if radix == 10 { digits.as_str() } else { &digits[2..] };
+    let signed = if negative { format!("-{}", digits) } else { digits.to_owned() };
+    match i64::from_str_radix(&signed, radix) {
+        Ok(i64v) => if let Ok(i32v) = i32::try_from(i64v) {
             Value::Int(i32v)
         } else {
-            Value::Int64(i64::from_str(str_value).unwrap())
+            Value::Int64(i64v)
+        },
+        Err(_) => {
+            let decimal = digits.chars().fold(BigDecimal::zero(), |acc, c| acc * BigDecimal::from(radix as u64) + BigDecimal::from(c.to_digit(radix).unwrap() as u64));
+            Value::Decimal(if negative { -decimal } else { decimal })
         }
     }
 }
@@ -160,6 +276,7 @@ pub(super) fn parse_dictionary_literal(pair: Pair<'_>, context: &ParserContext,
             },
             Rule::COMMA => parse_insert_punctuation!(context, current, children, ","),
             Rule::availability_start => parse_append!(parse_availability_flag(current, context), children),
+            Rule::availability_else => parse_append!(parse_availability_else(current, context), children),
             Rule::availability_end => parse_append!(parse_availability_end(current, context), children),
             Rule::triple_comment_block => parse_append!(parse_doc_comment(current, context), children),
             Rule::double_comment_block => parse_append!(parse_code_comment(current, context), children),
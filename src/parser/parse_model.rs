@@ -1,6 +1,7 @@
 use std::cell::RefCell;
 use crate::ast::model::Model;
 use crate::{parse_append, parse_container_node_variables, parse_container_node_variables_cleanup, parse_insert, parse_insert_keyword, parse_insert_punctuation, parse_set_identifier_and_string_path, parse_set_optional};
+use crate::parser::parse_availability_else::parse_availability_else;
 use crate::parser::parse_availability_end::parse_availability_end;
 use crate::parser::parse_availability_flag::parse_availability_flag;
 use crate::parser::parse_code_comment::parse_code_comment;
@@ -8,9 +9,11 @@ use crate::parser::parse_doc_comment::parse_doc_comment;
 use crate::parser::parse_decorator::parse_decorator;
 use crate::parser::parse_field::parse_field;
 use crate::parser::parse_handler_group::parse_handler_declaration;
+use crate::parser::parse_identifier_path::parse_identifier_path;
 use crate::parser::parse_include_handler_from_template::parse_include_handler_from_template;
 use crate::parser::parse_partial_field::parse_partial_field;
 use crate::parser::parse_span::parse_span;
+use crate::parser::parse_syntax_error::SyntaxErrorRecovery;
 use crate::parser::parser_context::ParserContext;
 use crate::parser::pest_parser::{Pair, Rule};
 
@@ -29,14 +32,24 @@ pub(super) fn parse_model_declaration(pair: Pair<'_>, context: &ParserContext) -
     let mut empty_decorator_spans = vec![];
     let mut empty_field_decorator_spans = vec![];
     let mut unattached_field_decorators = vec![];
+    let mut r#abstract = false;
     let mut identifier = 0;
+    let mut extends = vec![];
     let mut fields = vec![];
     let mut partial_fields = vec![];
     let mut handlers = vec![];
     let mut handler_inclusions = vec![];
+    let mut recovery = SyntaxErrorRecovery::new();
     for current in pair.into_inner() {
         match current.as_rule() {
+            Rule::ABSTRACT_KEYWORD => {
+                parse_insert_keyword!(context, current, children, "abstract");
+                r#abstract = true;
+            },
             Rule::MODEL_KEYWORD => parse_insert_keyword!(context, current, children, "model"),
+            Rule::EXTENDS_KEYWORD => parse_insert_keyword!(context, current, children, "extends"),
+            Rule::COMMA => parse_insert_punctuation!(context, current, children, ","),
+            Rule::identifier_path => parse_insert!(parse_identifier_path(current, context), children, extends),
             Rule::BLOCK_CLOSE => parse_insert_punctuation!(context, current, children, "}"),
             Rule::BLOCK_OPEN => {
                 parse_insert_punctuation!(context, current, children, "{");
@@ -64,8 +77,10 @@ pub(super) fn parse_model_declaration(pair: Pair<'_>, context: &ParserContext) -
             Rule::partial_field => parse_insert!(parse_partial_field(current, context), children, partial_fields),
             Rule::handler_declaration => parse_insert!(parse_handler_declaration(current, context, true), children, handlers),
             Rule::availability_start => parse_append!(parse_availability_flag(current, context), children),
+            Rule::availability_else => parse_append!(parse_availability_else(current, context), children),
             Rule::availability_end => parse_append!(parse_availability_end(current, context), children),
             Rule::include_handler_from_template => parse_insert!(parse_include_handler_from_template(current, context), children, handler_inclusions),
+            Rule::BLOCK_LEVEL_CATCH_ALL => recovery.insert_syntax_error(&current, &[Rule::field_declaration, Rule::handler_declaration, Rule::include_handler_from_template, Rule::decorator], context),
             _ => context.insert_unparsed(parse_span(&current)),
         }
     }
@@ -77,8 +92,10 @@ pub(super) fn parse_model_declaration(pair: Pair<'_>, context: &ParserContext) -
         children,
         define_availability,
         actual_availability,
+        r#abstract,
         comment,
         identifier,
+        extends,
         fields,
         partial_fields,
         decorators,
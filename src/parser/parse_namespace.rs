@@ -3,6 +3,7 @@ use crate::availability::Availability;
 use crate::ast::namespace::{Namespace, NamespaceReferences};
 use crate::ast::node::Node;
 use crate::{parse_append, parse_insert_keyword, parse_insert_punctuation, parse_set_optional};
+use crate::parser::parse_availability_else::parse_availability_else;
 use crate::parser::parse_availability_end::parse_availability_end;
 use crate::parser::parse_availability_flag::parse_availability_flag;
 use crate::parser::parse_code_comment::parse_code_comment;
@@ -23,8 +24,10 @@ use crate::parser::parse_middleware_declaration::parse_middleware_declaration;
 use crate::parser::parse_model::parse_model_declaration;
 use crate::parser::parse_pipeline_item_declaration::parse_pipeline_item_declaration;
 use crate::parser::parse_span::parse_span;
+use crate::parser::parse_syntax_error::{SyntaxErrorRecovery, NAMESPACE_LEVEL_RULES};
 use crate::parser::parse_struct_declaration::parse_struct_declaration;
 use crate::parser::parse_synthesized_shape_declaration::parse_synthesized_shape_declaration;
+use crate::parser::parse_type_alias_declaration::parse_type_alias_declaration;
 use crate::parser::parse_use_middlewares_block::parse_use_middlewares_block;
 use crate::parser::parser_context::ParserContext;
 use crate::parser::pest_parser::{Pair, Rule};
@@ -43,6 +46,7 @@ pub(super) fn parse_namespace(pair: Pair<'_>, context: &ParserContext) -> Namesp
     let mut references = NamespaceReferences::new();
     let mut children = btreemap!{};
     let mut inside_block = false;
+    let mut recovery = SyntaxErrorRecovery::new();
     for current in pair.into_inner() {
         match current.as_rule() {
             Rule::NAMESPACE_KEYWORD => parse_insert_keyword!(context, current, children, "namespace"),
@@ -167,6 +171,7 @@ pub(super) fn parse_namespace(pair: Pair<'_>, context: &ParserContext) -> Namesp
                 children.insert(struct_declaration.id(), Node::StructDeclaration(struct_declaration));
             },
             Rule::availability_start => parse_append!(parse_availability_flag(current, context), children),
+            Rule::availability_else => parse_append!(parse_availability_else(current, context), children),
             Rule::availability_end => parse_append!(parse_availability_end(current, context), children),
             Rule::empty_decorator => {
                 let empty_decorator = parse_empty_decorator(current, context);
@@ -184,13 +189,19 @@ pub(super) fn parse_namespace(pair: Pair<'_>, context: &ParserContext) -> Namesp
                 context.schema_references_mut().declared_shapes.push(synthesized_shape_declaration.path().clone());
                 children.insert(synthesized_shape_declaration.id(), Node::SynthesizedShapeDeclaration(synthesized_shape_declaration));
             },
+            Rule::type_alias_declaration => {
+                let type_alias_declaration = parse_type_alias_declaration(current, context);
+                references.type_alias_declarations.insert(type_alias_declaration.id());
+                context.schema_references_mut().type_aliases.push(type_alias_declaration.path().clone());
+                children.insert(type_alias_declaration.id(), Node::TypeAliasDeclaration(type_alias_declaration));
+            },
             Rule::handler_template_declaration => {
                 let handler_template_declaration = parse_handler_template_declaration(current, context);
                 references.handler_template_declarations.insert(handler_template_declaration.id());
                 context.schema_references_mut().handler_templates.push(handler_template_declaration.path().clone());
                 children.insert(handler_template_declaration.id(), Node::HandlerTemplateDeclaration(handler_template_declaration));
             },
-            Rule::BLOCK_LEVEL_CATCH_ALL => context.insert_unparsed(parse_span(&current)),
+            Rule::BLOCK_LEVEL_CATCH_ALL => recovery.insert_syntax_error(&current, NAMESPACE_LEVEL_RULES, context),
             _ => (),
         }
     }
@@ -0,0 +1,78 @@
+use std::collections::{BTreeMap, BTreeSet};
+use crate::utils::environment::Environment;
+use crate::utils::path::SchemaFileSystem;
+
+/// Options of `parse`. The default reads the local file system without any unsaved files,
+/// library roots, profiles or environment.
+#[derive(Debug, Default)]
+pub struct ParseOptions {
+    pub file_system: Option<Box<dyn SchemaFileSystem>>,
+    pub unsaved_files: Option<BTreeMap<String, String>>,
+    pub library_roots: Vec<String>,
+    pub profiles: BTreeSet<String>,
+    /// The variables of `ENV` and of `#if env(...)` blocks.
+    pub environment: Option<Environment>,
+}
+
+impl ParseOptions {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn file_system(mut self, file_system: Box<dyn SchemaFileSystem>) -> Self {
+        self.file_system = Some(file_system);
+        self
+    }
+
+    pub fn unsaved_files(mut self, unsaved_files: BTreeMap<String, String>) -> Self {
+        self.unsaved_files = Some(unsaved_files);
+        self
+    }
+
+    pub fn library_roots(mut self, library_roots: Vec<String>) -> Self {
+        self.library_roots = library_roots;
+        self
+    }
+
+    pub fn profiles(mut self, profiles: BTreeSet<String>) -> Self {
+        self.profiles = profiles;
+        self
+    }
+
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.environment = Some(environment);
+        self
+    }
+}
+
+/// Options of `reparse`. The default keeps the file system and the library roots of the previous
+/// schema and has no unsaved files.
+#[derive(Debug, Default)]
+pub struct ReparseOptions {
+    pub file_system: Option<Box<dyn SchemaFileSystem>>,
+    pub unsaved_files: Option<BTreeMap<String, String>>,
+    pub library_roots: Option<Vec<String>>,
+}
+
+impl ReparseOptions {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn file_system(mut self, file_system: Box<dyn SchemaFileSystem>) -> Self {
+        self.file_system = Some(file_system);
+        self
+    }
+
+    pub fn unsaved_files(mut self, unsaved_files: BTreeMap<String, String>) -> Self {
+        self.unsaved_files = Some(unsaved_files);
+        self
+    }
+
+    pub fn library_roots(mut self, library_roots: Vec<String>) -> Self {
+        self.library_roots = Some(library_roots);
+        self
+    }
+}
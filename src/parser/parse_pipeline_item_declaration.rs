@@ -6,6 +6,7 @@ use crate::parser::parse_doc_comment::parse_doc_comment;
 use crate::parser::parse_generics::{parse_generics_constraint, parse_generics_declaration};
 
 use crate::parser::parse_span::parse_span;
+use crate::parser::parse_syntax_error::SyntaxErrorRecovery;
 use crate::parser::parse_type_expression::parse_type_expression;
 use crate::parser::parser_context::ParserContext;
 use crate::parser::pest_parser::{Pair, Rule};
@@ -28,6 +29,7 @@ pub(super) fn parse_pipeline_item_declaration(pair: Pair<'_>, context: &ParserCo
     let mut output_type = None;
     let mut variants = vec![];
     let mut inside_block = false;
+    let mut recovery = SyntaxErrorRecovery::new();
     for current in pair.into_inner() {
         match current.as_rule() {
             Rule::DECLARE_KEYWORD => parse_insert_keyword!(context, current, children, "declare"),
@@ -57,6 +59,7 @@ pub(super) fn parse_pipeline_item_declaration(pair: Pair<'_>, context: &ParserCo
             } else {
                 parse_set_optional!(parse_type_expression(current, context), children, input_type);
             }
+            Rule::BLOCK_LEVEL_CATCH_ALL => recovery.insert_syntax_error(&current, &[Rule::pipeline_item_variant_declaration], context),
             _ => context.insert_unparsed(parse_span(&current)),
         }
     }
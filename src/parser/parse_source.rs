@@ -1,8 +1,10 @@
 use maplit::btreemap;
 use pest::Parser;
 use crate::ast::node::Node;
+use crate::ast::span::Span;
 use crate::ast::source::{Source, SourceReferences};
 use crate::parse_append;
+use crate::parser::parse_availability_else::parse_availability_else;
 use crate::parser::parse_availability_end::parse_availability_end;
 use crate::parser::parse_availability_flag::parse_availability_flag;
 use crate::parser::parse_code_comment::parse_code_comment;
@@ -24,8 +26,10 @@ use crate::parser::parse_model::parse_model_declaration;
 use crate::parser::parse_namespace::parse_namespace;
 use crate::parser::parse_pipeline_item_declaration::parse_pipeline_item_declaration;
 use crate::parser::parse_span::parse_span;
+use crate::parser::parse_syntax_error::{SyntaxErrorRecovery, TOP_LEVEL_RULES};
 use crate::parser::parse_struct_declaration::parse_struct_declaration;
 use crate::parser::parse_synthesized_shape_declaration::parse_synthesized_shape_declaration;
+use crate::parser::parse_type_alias_declaration::parse_type_alias_declaration;
 use crate::parser::parse_use_middlewares_block::parse_use_middlewares_block;
 use crate::parser::parser_context::ParserContext;
 use crate::parser::pest_parser::SchemaParser;
@@ -43,11 +47,13 @@ pub(super) fn parse_source(
     let mut children = btreemap!{};
     let mut references = SourceReferences::new();
     let mut pairs = match SchemaParser::parse(Rule::schema, &content) {
-        Ok(pairs) => pairs,
-        Err(err) => panic!("{}", err)
+        Ok(mut pairs) => pairs.next().map(|pair| pair.into_inner()).into_iter().flatten(),
+        Err(err) => {
+            context.insert_error(Span::default(), format!("SyntaxError: {}", err.variant.message()));
+            None.into_iter().flatten()
+        }
     };
-    let pairs = pairs.next().unwrap();
-    let mut pairs = pairs.into_inner().peekable();
+    let mut recovery = SyntaxErrorRecovery::new();
     while let Some(current) = pairs.next() {
         match current.as_rule() {
             Rule::triple_comment_block => {
@@ -57,14 +63,16 @@ pub(super) fn parse_source(
             Rule::double_comment_block => parse_append!(parse_code_comment(current, context), children),
             Rule::import_statement => { // import { a, b } from './some.schema'
                 let import = parse_import_statement(current, path.as_ref(), context);
-                let import_span = import.source.span;
-                let import_file_path = import.file_path.clone();
+                let import_span = import.source().span;
+                let import_file_paths = import.file_paths.clone();
                 references.imports.insert(import.id());
                 children.insert(import.id(), Node::Import(import));
-                if context.is_import_file_path_examined(&import_file_path) {
+                if !import_file_paths.is_empty() && import_file_paths.iter().all(|file_path| context.is_import_file_path_examined(file_path)) {
                     context.insert_error(import_span, "Duplicated import")
                 } else {
-                    context.add_examined_import_file(import_file_path);
+                    for file_path in import_file_paths {
+                        context.add_examined_import_file(file_path);
+                    }
                 }
             },
             Rule::constant_statement => { // let a = 5
@@ -161,6 +169,7 @@ pub(super) fn parse_source(
                 children.insert(struct_declaration.id(), Node::StructDeclaration(struct_declaration));
             }
             Rule::availability_start => parse_append!(parse_availability_flag(current, context), children),
+            Rule::availability_else => parse_append!(parse_availability_else(current, context), children),
             Rule::availability_end => parse_append!(parse_availability_end(current, context), children),
             Rule::empty_decorator => {
                 let empty_decorator = parse_empty_decorator(current, context);
@@ -178,14 +187,20 @@ pub(super) fn parse_source(
                 context.schema_references_mut().declared_shapes.push(synthesized_shape_declaration.path().clone());
                 children.insert(synthesized_shape_declaration.id(), Node::SynthesizedShapeDeclaration(synthesized_shape_declaration));
             },
+            Rule::type_alias_declaration => {
+                let type_alias_declaration = parse_type_alias_declaration(current, context);
+                references.type_alias_declarations.insert(type_alias_declaration.id());
+                context.schema_references_mut().type_aliases.push(type_alias_declaration.path().clone());
+                children.insert(type_alias_declaration.id(), Node::TypeAliasDeclaration(type_alias_declaration));
+            },
             Rule::handler_template_declaration => {
                 let handler_template_declaration = parse_handler_template_declaration(current, context);
                 references.handler_template_declarations.insert(handler_template_declaration.id());
                 context.schema_references_mut().handler_templates.push(handler_template_declaration.path().clone());
                 children.insert(handler_template_declaration.id(), Node::HandlerTemplateDeclaration(handler_template_declaration));
             }
-            Rule::CATCH_ALL => context.insert_unparsed(parse_span(&current)),
-            Rule::EOI => (),
+            Rule::CATCH_ALL => recovery.insert_syntax_error(&current, TOP_LEVEL_RULES, context),
+            Rule::EOI => context.end_inactive_profile_span(parse_span(&current)),
             _ => context.insert_unparsed(parse_span(&current)),
         }
     }
@@ -199,6 +214,7 @@ pub(super) fn parse_source(
         builtin || path.as_str().ends_with("builtin/std.teo"),
         path,
         children,
-        references
+        references,
+        context.inactive_profile_spans(),
     )
 }
\ No newline at end of file
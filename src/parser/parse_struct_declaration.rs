@@ -7,6 +7,7 @@ use crate::parser::parse_doc_comment::parse_doc_comment;
 use crate::parser::parse_function_declaration::parse_function_declaration;
 use crate::parser::parse_generics::{parse_generics_constraint, parse_generics_declaration};
 use crate::parser::parse_span::parse_span;
+use crate::parser::parse_syntax_error::SyntaxErrorRecovery;
 use crate::parser::parser_context::ParserContext;
 use crate::parser::pest_parser::{Pair, Rule};
 
@@ -25,6 +26,7 @@ pub(super) fn parse_struct_declaration(pair: Pair<'_>, context: &ParserContext)
     let mut generics_constraint = None;
     let mut function_declarations = vec![];
     let mut inside_block = false;
+    let mut recovery = SyntaxErrorRecovery::new();
     for current in pair.into_inner() {
         match current.as_rule() {
             Rule::DECLARE_KEYWORD => parse_insert_keyword!(context, current, children, "declare"),
@@ -45,6 +47,7 @@ pub(super) fn parse_struct_declaration(pair: Pair<'_>, context: &ParserContext)
             Rule::generics_declaration => parse_set_optional!(parse_generics_declaration(current, context), children, generics_declaration),
             Rule::generics_constraint => parse_set_optional!(parse_generics_constraint(current, context), children, generics_constraint),
             Rule::function_declaration => parse_insert!(parse_function_declaration(current, context, true), children, function_declarations),
+            Rule::BLOCK_LEVEL_CATCH_ALL => recovery.insert_syntax_error(&current, &[Rule::function_declaration], context),
             _ => context.insert_unparsed(parse_span(&current)),
         }
     }
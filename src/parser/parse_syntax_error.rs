@@ -0,0 +1,231 @@
+use pest::error::{ErrorVariant, InputLocation};
+use pest::{Parser, Position};
+use crate::ast::span::Span;
+use crate::parser::parser_context::ParserContext;
+use crate::parser::pest_parser::{Pair, Rule, SchemaParser};
+
+pub(super) const TOP_LEVEL_RULES: &[Rule] = &[
+    Rule::namespace, Rule::import_statement, Rule::config_block, Rule::use_middlewares_block,
+    Rule::struct_declaration, Rule::enum_declaration, Rule::model_declaration, Rule::constant_statement,
+    Rule::dataset_declaration, Rule::interface_declaration, Rule::middleware_declaration,
+    Rule::config_declaration, Rule::decorator_declaration, Rule::pipeline_item_declaration,
+    Rule::handler_group_declaration, Rule::handler_declaration, Rule::handler_template_declaration,
+    Rule::synthesized_shape_declaration, Rule::type_alias_declaration,
+];
+
+pub(super) const NAMESPACE_LEVEL_RULES: &[Rule] = &[
+    Rule::namespace, Rule::config_block, Rule::use_middlewares_block,
+    Rule::struct_declaration, Rule::enum_declaration, Rule::model_declaration, Rule::constant_statement,
+    Rule::dataset_declaration, Rule::interface_declaration, Rule::middleware_declaration,
+    Rule::config_declaration, Rule::decorator_declaration, Rule::pipeline_item_declaration,
+    Rule::handler_group_declaration, Rule::handler_declaration, Rule::handler_template_declaration,
+    Rule::synthesized_shape_declaration, Rule::type_alias_declaration,
+];
+
+struct SyntaxError {
+    span: Span,
+    message: String,
+    /// Where parsing the best matching candidate stopped.
+    end: usize,
+}
+
+/// Reports unparsed content in a block. Content covered by the error of a broken declaration
+/// and unparsed lines right after it are not reported again, the next line which starts a
+/// declaration is.
+pub(super) struct SyntaxErrorRecovery {
+    previous_end: usize,
+    reported_until: usize,
+}
+
+impl SyntaxErrorRecovery {
+
+    pub(super) fn new() -> Self {
+        Self { previous_end: usize::MAX, reported_until: 0 }
+    }
+
+    /// Insert an error for content which doesn't match the grammar. `candidates` are the
+    /// rules which are allowed at the position of the unparsed content.
+    pub(super) fn insert_syntax_error(&mut self, pair: &Pair<'_>, candidates: &[Rule], context: &ParserContext) {
+        let input = pair.as_span().get_input();
+        let start = pair.as_span().start();
+        let follows_unparsed = self.previous_end <= start && input[self.previous_end..start].trim().is_empty();
+        self.previous_end = pair.as_span().end();
+        if start < self.reported_until {
+            return
+        }
+        let error = syntax_error(pair, candidates);
+        if follows_unparsed && error.end == start {
+            return
+        }
+        self.reported_until = error.end;
+        context.insert_error(error.span, error.message);
+    }
+}
+
+/// Try the candidate rules from the start of the unparsed content up to the recovery boundary.
+/// The first candidate which makes progress decides the tokens named in the message, the
+/// tokens expected by all candidates are named if none does.
+fn syntax_error(pair: &Pair<'_>, candidates: &[Rule]) -> SyntaxError {
+    let input = pair.as_span().get_input();
+    let start = pair.as_span().start();
+    let boundary = recovery_boundary(input, start);
+    let mut end = start;
+    let mut expected: Vec<Rule> = vec![];
+    for candidate in candidates {
+        let (candidate_end, positives) = match SchemaParser::parse(*candidate, &input[start..boundary]) {
+            Ok(pairs) => (start + pairs.last().map_or(0, |pair| pair.as_span().end()), vec![]),
+            Err(error) => {
+                let position = match error.location {
+                    InputLocation::Pos(position) => position,
+                    InputLocation::Span((position, _)) => position,
+                };
+                let positives = match error.variant {
+                    ErrorVariant::ParsingError { positives, .. } => positives,
+                    ErrorVariant::CustomError { .. } => vec![],
+                };
+                (start + position, positives)
+            }
+        };
+        if candidate_end > end {
+            end = candidate_end;
+            expected = vec![];
+        }
+        if candidate_end == end {
+            for rule in positives {
+                if !expected.contains(&rule) && !is_hidden_rule(rule) {
+                    expected.push(rule);
+                }
+            }
+        }
+        if end > start {
+            break
+        }
+    }
+    let (span, found) = found_token(input, end);
+    let message = if end == start || expected.is_empty() {
+        format!("SyntaxError: unexpected {found}")
+    } else {
+        format!("SyntaxError: expected {}, found {found}", describe_rules(&expected))
+    };
+    SyntaxError { span, message, end }
+}
+
+// The content of a broken declaration ends before the next line which is indented no deeper
+// than its first line, or before the brace closing the enclosing block.
+fn recovery_boundary(input: &str, start: usize) -> usize {
+    let line_start = input[..start].rfind('\n').map_or(0, |index| index + 1);
+    let indentation = |line: &str| line.len() - line.trim_start().len();
+    let first_indentation = indentation(&input[line_start..]);
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut at_line_start = false;
+    for (index, c) in input[start..].char_indices() {
+        let position = start + index;
+        if at_line_start && depth == 0 && !in_string {
+            let line = input[position..].split('\n').next().unwrap();
+            if !line.trim().is_empty() && indentation(line) <= first_indentation {
+                return position
+            }
+        }
+        at_line_start = c == '\n';
+        if in_string {
+            match c {
+                '\\' => escaped = !escaped,
+                '"' if !escaped => in_string = false,
+                _ => escaped = false,
+            }
+            continue
+        }
+        match c {
+            '"' => in_string = true,
+            '{' | '(' | '[' => depth += 1,
+            '}' | ')' | ']' => if depth == 0 {
+                return position
+            } else {
+                depth -= 1;
+            },
+            _ => (),
+        }
+    }
+    input.len()
+}
+
+fn found_token(input: &str, position: usize) -> (Span, String) {
+    let mut start = position;
+    let rest = &input[position..];
+    let rest_of_line = rest.split('\n').next().unwrap().trim_end_matches('\r');
+    let (found, len) = if rest.trim().is_empty() {
+        start = input.trim_end().len();
+        ("end of file".to_owned(), 0)
+    } else if rest_of_line.trim().is_empty() {
+        ("end of line".to_owned(), 0)
+    } else {
+        let leading = rest_of_line.len() - rest_of_line.trim_start().len();
+        start += leading;
+        let token = rest_of_line.trim_start();
+        let word_len = token.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(token.len());
+        let len = if word_len == 0 { token.chars().next().unwrap().len_utf8() } else { word_len };
+        (format!("'{}'", &token[..len]), len)
+    };
+    let start_position = Position::new(input, start).unwrap();
+    let end_position = Position::new(input, start + len).unwrap();
+    (Span {
+        start,
+        end: start + len,
+        start_position: start_position.line_col(),
+        end_position: end_position.line_col(),
+    }, found)
+}
+
+fn is_hidden_rule(rule: Rule) -> bool {
+    matches!(rule,
+        Rule::CATCH_ALL | Rule::BLOCK_LEVEL_CATCH_ALL | Rule::triple_comment_block | Rule::double_comment_block |
+        Rule::triple_comment | Rule::double_comment | Rule::block_comment | Rule::HASH_TAG | Rule::availability_start | Rule::availability_else |
+        Rule::availability_end | Rule::empty_decorator | Rule::partial_field
+    )
+}
+
+fn describe_rules(rules: &Vec<Rule>) -> String {
+    let descriptions: Vec<String> = rules.iter().map(|rule| describe_rule(*rule)).collect();
+    match descriptions.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => String::new(),
+    }
+}
+
+fn describe_rule(rule: Rule) -> String {
+    let punctuation = match rule {
+        Rule::COLON => ":",
+        Rule::COMMA => ",",
+        Rule::DOT => ".",
+        Rule::ASSIGN => "=",
+        Rule::BLOCK_OPEN => "{",
+        Rule::BLOCK_CLOSE => "}",
+        Rule::PAREN_OPEN => "(",
+        Rule::PAREN_CLOSE => ")",
+        Rule::CHEVRON_OPEN => "<",
+        Rule::CHEVRON_CLOSE => ">",
+        Rule::BRACKET_OPEN => "[",
+        Rule::BRACKET_CLOSE => "]",
+        Rule::OPTIONAL => "?",
+        Rule::OPTIONAL_CHAINING => "?.",
+        Rule::TERNARY_THEN => "?",
+        Rule::TERNARY_ELSE => ":",
+        Rule::AT => "@",
+        Rule::ARROW => "->",
+        Rule::BAR => "|",
+        Rule::EOI => return "end of file".to_owned(),
+        _ => "",
+    };
+    if !punctuation.is_empty() {
+        return format!("'{punctuation}'");
+    }
+    let name = format!("{:?}", rule);
+    if let Some(keyword) = name.strip_suffix("_KEYWORD") {
+        format!("'{}'", keyword.to_lowercase().replace('_', " "))
+    } else {
+        name.to_lowercase().replace('_', " ")
+    }
+}
@@ -4,6 +4,7 @@ use maplit::btreemap;
 use crate::ast::synthesized_shape_declaration::{SynthesizedShapeDeclaration, SynthesizedShapeDeclarationResolved};
 use crate::{parse_append, parse_container_node_variables, parse_container_node_variables_cleanup, parse_insert, parse_insert_keyword, parse_insert_punctuation, parse_set, parse_set_identifier_and_string_path, parse_set_optional};
 use crate::ast::synthesized_shape_field_declaration::{SynthesizedShapeFieldDeclaration, SynthesizedShapeFieldDeclarationResolved};
+use crate::parser::parse_availability_else::parse_availability_else;
 use crate::parser::parse_availability_end::parse_availability_end;
 use crate::parser::parse_availability_flag::parse_availability_flag;
 use crate::parser::parse_code_comment::parse_code_comment;
@@ -12,6 +13,7 @@ use crate::parser::parse_field::parse_field;
 use crate::parser::parse_identifier_path::parse_identifier_path;
 use crate::parser::parse_partial_field::parse_partial_field;
 use crate::parser::parse_span::parse_span;
+use crate::parser::parse_syntax_error::SyntaxErrorRecovery;
 use crate::parser::parser_context::ParserContext;
 use crate::parser::pest_parser::{Pair, Rule};
 
@@ -31,6 +33,7 @@ pub(super) fn parse_synthesized_shape_declaration(pair: Pair<'_>, context: &Pars
     let mut dynamic_fields = vec![];
     let mut inside_block = false;
     let mut builtin = false;
+    let mut recovery = SyntaxErrorRecovery::new();
     for current in pair.into_inner() {
         match current.as_rule() {
             Rule::BLOCK_OPEN => {
@@ -57,7 +60,9 @@ pub(super) fn parse_synthesized_shape_declaration(pair: Pair<'_>, context: &Pars
             Rule::partial_field => parse_insert!(parse_partial_field(current, context), children, partial_static_fields),
             Rule::synthesized_shape_field_declaration => parse_insert!(parse_shape_field_declaration(current, context), children, dynamic_fields),
             Rule::availability_start => parse_append!(parse_availability_flag(current, context), children),
+            Rule::availability_else => parse_append!(parse_availability_else(current, context), children),
             Rule::availability_end => parse_append!(parse_availability_end(current, context), children),
+            Rule::BLOCK_LEVEL_CATCH_ALL => recovery.insert_syntax_error(&current, &[Rule::field_declaration, Rule::synthesized_shape_field_declaration], context),
             _ => context.insert_unparsed(parse_span(&current)),
         }
     }
@@ -0,0 +1,52 @@
+use std::cell::RefCell;
+use crate::ast::type_alias_declaration::TypeAliasDeclaration;
+use crate::{parse_container_node_variables, parse_container_node_variables_cleanup, parse_insert_keyword, parse_insert_punctuation, parse_set, parse_set_identifier_and_string_path, parse_set_optional};
+use crate::parser::parse_doc_comment::parse_doc_comment;
+use crate::parser::parse_generics::{parse_generics_constraint, parse_generics_declaration};
+use crate::parser::parse_span::parse_span;
+use crate::parser::parse_type_expression::parse_type_expression;
+use crate::parser::parser_context::ParserContext;
+use crate::parser::pest_parser::{Pair, Rule};
+
+pub(super) fn parse_type_alias_declaration(pair: Pair<'_>, context: &ParserContext) -> TypeAliasDeclaration {
+    let (
+        span,
+        path,
+        mut string_path,
+        mut children,
+        define_availability,
+        actual_availability
+    ) = parse_container_node_variables!(pair, context, named, availability);
+    let mut comment = None;
+    let mut identifier = 0;
+    let mut generics_declaration = None;
+    let mut generics_constraint = None;
+    let mut type_expr = 0;
+    for current in pair.into_inner() {
+        match current.as_rule() {
+            Rule::TYPE_KEYWORD => parse_insert_keyword!(context, current, children, "type"),
+            Rule::ASSIGN => parse_insert_punctuation!(context, current, children, "="),
+            Rule::triple_comment_block => parse_set_optional!(parse_doc_comment(current, context), children, comment),
+            Rule::identifier => parse_set_identifier_and_string_path!(context, current, children, identifier, string_path),
+            Rule::generics_declaration => parse_set_optional!(parse_generics_declaration(current, context), children, generics_declaration),
+            Rule::type_expression => parse_set!(parse_type_expression(current, context), children, type_expr),
+            Rule::generics_constraint => parse_set_optional!(parse_generics_constraint(current, context), children, generics_constraint),
+            _ => context.insert_unparsed(parse_span(&current)),
+        }
+    }
+    parse_container_node_variables_cleanup!(context, named);
+    TypeAliasDeclaration {
+        span,
+        path,
+        string_path,
+        children,
+        define_availability,
+        actual_availability,
+        comment,
+        identifier,
+        generics_declaration,
+        generics_constraint,
+        type_expr,
+        resolved: RefCell::new(None),
+    }
+}
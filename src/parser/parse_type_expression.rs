@@ -7,7 +7,7 @@ use crate::ast::literals::EnumVariantLiteral;
 use crate::{parse_container_node_variables, parse_container_node_variables_cleanup, parse_container_node_variables_without_span, parse_insert, parse_insert_operator, parse_insert_punctuation, parse_set, parse_set_optional};
 use crate::parser::parse_identifier::parse_identifier;
 use crate::parser::parse_identifier_path::parse_identifier_path;
-use crate::parser::parse_literals::parse_enum_variant_literal;
+use crate::parser::parse_literals::{parse_bool_literal, parse_enum_variant_literal, parse_numeric_literal, parse_string_literal};
 use crate::traits::identifiable::Identifiable;
 
 pub(super) fn parse_type_expression(pair: Pair<'_>, context: &ParserContext) -> TypeExpr {
@@ -18,6 +18,7 @@ pub(super) fn parse_type_expression(pair: Pair<'_>, context: &ParserContext) ->
         Rule::type_tuple => TypeExpr::new(TypeExprKind::TypeTuple(parse_type_tuple(primary, context))),
         Rule::type_subscript => TypeExpr::new(TypeExprKind::TypeSubscript(parse_type_subscript(primary, context))),
         Rule::type_reference => TypeExpr::new(TypeExprKind::FieldName(parse_type_reference(primary, context))),
+        Rule::type_literal => TypeExpr::new(parse_type_literal(primary, context)),
         Rule::typed_shape => TypeExpr::new(TypeExprKind::TypedShape(parse_typed_shape(primary, context))),
         Rule::typed_enum => TypeExpr::new(TypeExprKind::TypedEnum(parse_typed_enum(primary, context))),
         _ => {
@@ -342,4 +343,16 @@ fn parse_type_reference(pair: Pair<'_>, context: &ParserContext) -> EnumVariantL
         }
     }
     unreachable!()
+}
+
+fn parse_type_literal(pair: Pair<'_>, context: &ParserContext) -> TypeExprKind {
+    for current in pair.into_inner() {
+        match current.as_rule() {
+            Rule::string_literal => return TypeExprKind::StringLiteral(parse_string_literal(&current, context)),
+            Rule::numeric_literal => return TypeExprKind::NumericLiteral(parse_numeric_literal(&current, context)),
+            Rule::bool_literal => return TypeExprKind::BoolLiteral(parse_bool_literal(&current, context)),
+            _ => unreachable!()
+        }
+    }
+    unreachable!()
 }
\ No newline at end of file
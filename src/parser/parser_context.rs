@@ -1,17 +1,20 @@
 use std::cell::{Cell, Ref, RefCell, RefMut};
-use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use maplit::btreemap;
 use crate::availability::Availability;
 use crate::ast::schema::SchemaReferences;
 use crate::ast::span::Span;
 use crate::diagnostics::diagnostics::{Diagnostics, DiagnosticsError, DiagnosticsWarning};
-use crate::utils::path::FileUtility;
+use crate::utils::path::UnsavedFileSystem;
 
 pub(super) struct ParserContext {
     diagnostics: RefCell<Diagnostics>,
     schema_references: RefCell<SchemaReferences>,
-    pub(crate) file_util: FileUtility,
-    pub(crate) unsaved_files: Option<BTreeMap<String, String>>,
+    pub(crate) file_system: Arc<UnsavedFileSystem>,
+    pub(crate) library_roots: Vec<String>,
+    pub(crate) profiles: BTreeSet<String>,
+    pub(crate) environment: Option<BTreeMap<String, String>>,
     source_lookup: RefCell<BTreeMap<usize, String>>,
     current_source_id: Cell<usize>,
     current_id: Cell<usize>,
@@ -19,6 +22,10 @@ pub(super) struct ParserContext {
     current_string_path: RefCell<Vec<String>>,
     current_namespace_path: RefCell<Vec<usize>>,
     current_availability_flag_state: RefCell<Vec<Availability>>,
+    // `None` for availability blocks, whether the block is active for profile and environment blocks
+    current_profile_flag_state: RefCell<Vec<Option<bool>>>,
+    inactive_profile_start: Cell<Option<Span>>,
+    inactive_profile_spans: RefCell<Vec<Span>>,
     current_source_is_builtin: Cell<bool>,
     examined_import_file_paths: RefCell<Vec<String>>,
 }
@@ -28,14 +35,18 @@ impl ParserContext {
     pub(crate) fn new(
         diagnostics: Diagnostics,
         schema_references: SchemaReferences,
-        file_util: FileUtility,
-        unsaved_files: Option<BTreeMap<String, String>>,
+        file_system: Arc<UnsavedFileSystem>,
+        library_roots: Vec<String>,
+        profiles: BTreeSet<String>,
+        environment: Option<BTreeMap<String, String>>,
     ) -> ParserContext {
         Self {
             diagnostics: RefCell::new(diagnostics),
             schema_references: RefCell::new(schema_references),
-            file_util,
-            unsaved_files,
+            file_system,
+            library_roots,
+            profiles,
+            environment,
             source_lookup: RefCell::new(btreemap!{}),
             current_source_id: Cell::new(0),
             current_id: Cell::new(0),
@@ -43,6 +54,9 @@ impl ParserContext {
             current_string_path: RefCell::new(vec![]),
             current_namespace_path: RefCell::new(vec![]),
             current_availability_flag_state: RefCell::new(vec![Availability::default()]),
+            current_profile_flag_state: RefCell::new(vec![]),
+            inactive_profile_start: Cell::new(None),
+            inactive_profile_spans: RefCell::new(vec![]),
             current_source_is_builtin: Cell::new(false),
             examined_import_file_paths: RefCell::new(vec![]),
         }
@@ -76,15 +90,6 @@ impl ParserContext {
         self.schema_references.borrow_mut()
     }
 
-    pub(super) fn read_file(&self, file_path: &str) -> Option<String> {
-        if let Some(unsaved_files) = &self.unsaved_files {
-            if let Some(file_content) = unsaved_files.get(file_path) {
-                return Some(file_content.clone());
-            }
-        }
-        (self.file_util.read_file)(file_path)
-    }
-
     pub(super) fn start_next_source(&self, path: String) -> usize {
         let source_id = self.next_id();
         self.source_lookup.borrow_mut().insert(source_id, path);
@@ -92,11 +97,19 @@ impl ParserContext {
         *self.current_path.borrow_mut() = vec![source_id];
         *self.current_string_path.borrow_mut() = vec![];
         *self.current_availability_flag_state.borrow_mut() = vec![Availability::default()];
+        *self.current_profile_flag_state.borrow_mut() = vec![];
+        self.inactive_profile_start.set(None);
+        *self.inactive_profile_spans.borrow_mut() = vec![];
         *self.examined_import_file_paths.borrow_mut() = vec![];
         self.current_source_is_builtin.set(false);
         source_id
     }
 
+    pub(super) fn reuse_source(&self, id: usize, path: String, previous_references: &SchemaReferences) {
+        self.source_lookup.borrow_mut().insert(id, path);
+        self.schema_references_mut().add_source_references(previous_references, id);
+    }
+
     pub(super) fn set_is_builtin_source(&self) {
         self.current_source_is_builtin.set(true);
     }
@@ -110,6 +123,15 @@ impl ParserContext {
         self.current_id.get()
     }
 
+    pub(super) fn last_id(&self) -> usize {
+        self.current_id.get()
+    }
+
+    // ids of reused sources must not be allocated again
+    pub(super) fn skip_ids_until(&self, id: usize) {
+        self.current_id.set(id);
+    }
+
     pub(super) fn pop_parent_id(&self) {
         self.current_path.borrow_mut().pop();
     }
@@ -195,18 +217,90 @@ impl ParserContext {
     pub(super) fn push_availability_flag(&self, new_flag: Availability) -> Availability {
         let calculated_flag = self.current_availability_flag_state.borrow().last().unwrap().bi_and(new_flag);
         self.current_availability_flag_state.borrow_mut().push(calculated_flag);
+        self.current_profile_flag_state.borrow_mut().push(None);
         calculated_flag
     }
 
+    pub(super) fn push_profile_flag(&self, active: bool, span: Span) {
+        let calculated_flag = *self.current_availability_flag_state.borrow().last().unwrap();
+        self.current_availability_flag_state.borrow_mut().push(calculated_flag);
+        let was_active = self.is_profile_active();
+        self.current_profile_flag_state.borrow_mut().push(Some(was_active && active));
+        self.update_inactive_profile_spans(was_active, span);
+    }
+
     pub(super) fn pop_availability_flag(&self, span: Span) {
         if self.current_availability_flag_state.borrow().len() == 1 {
             self.insert_error(span,"unbalanced availability end")
         } else {
+            let was_active = self.is_profile_active();
             self.current_availability_flag_state.borrow_mut().pop();
+            self.current_profile_flag_state.borrow_mut().pop();
+            self.update_inactive_profile_spans(was_active, span);
         }
     }
 
+    /// The else branch is available where the parent is and the if branch isn't. Returns the
+    /// availability of the else branch if it belongs to an availability block.
+    pub(super) fn else_availability_flag(&self, span: Span) -> Option<Availability> {
+        if self.current_availability_flag_state.borrow().len() == 1 {
+            self.insert_error(span, "unbalanced availability else");
+            return None;
+        }
+        let if_profile_flag = self.current_profile_flag_state.borrow_mut().pop().unwrap();
+        match if_profile_flag {
+            Some(if_active) => {
+                let active = self.is_profile_active() && !if_active;
+                self.current_profile_flag_state.borrow_mut().push(Some(active));
+                self.update_inactive_profile_spans(if_active, span);
+                None
+            }
+            None => {
+                self.current_profile_flag_state.borrow_mut().push(None);
+                let mut state = self.current_availability_flag_state.borrow_mut();
+                let if_flag = state.pop().unwrap();
+                let calculated_flag = state.last().unwrap().bi_and(if_flag.bi_not());
+                state.push(calculated_flag);
+                Some(calculated_flag)
+            }
+        }
+    }
+
+    /// Nodes inside an inactive profile block are kept, but they are available nowhere.
     pub(super) fn current_availability_flag(&self) -> Availability {
-        *self.current_availability_flag_state.borrow().last().unwrap()
+        if self.is_profile_active() {
+            *self.current_availability_flag_state.borrow().last().unwrap()
+        } else {
+            Availability::none()
+        }
+    }
+
+    fn is_profile_active(&self) -> bool {
+        self.current_profile_flag_state.borrow().iter().rev().find_map(|active| *active).unwrap_or(true)
+    }
+
+    fn update_inactive_profile_spans(&self, was_active: bool, span: Span) {
+        let active = self.is_profile_active();
+        if was_active && !active {
+            self.inactive_profile_start.set(Some(span));
+        } else if !was_active && active {
+            self.end_inactive_profile_span(span);
+        }
+    }
+
+    /// Close the inactive block which is open, at the end of a block or at the end of the source.
+    pub(super) fn end_inactive_profile_span(&self, span: Span) {
+        if let Some(start) = self.inactive_profile_start.take() {
+            self.inactive_profile_spans.borrow_mut().push(Span {
+                start: start.end,
+                end: span.start,
+                start_position: start.end_position,
+                end_position: span.start_position,
+            });
+        }
+    }
+
+    pub(super) fn inactive_profile_spans(&self) -> Vec<Span> {
+        self.inactive_profile_spans.borrow().clone()
     }
 }
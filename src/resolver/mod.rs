@@ -1,5 +1,5 @@
 pub(super) mod resolve;
-mod resolver_context;
+pub(crate) mod resolver_context;
 mod resolve_enum;
 mod resolve_model;
 mod resolve_namespace;
@@ -28,6 +28,9 @@ mod resolve_unit;
 mod resolve_use_middlewares_block;
 mod resolve_model_shapes;
 mod resolve_declared_synthesized_shape;
+mod resolve_type_alias_declaration;
 mod resolve_handler_template_declaration;
 mod resolve_include_handler_from_template;
-mod resolve_type_as_value_expression;
\ No newline at end of file
+mod resolve_type_as_value_expression;
+mod resolve_import;
+mod unresolve_source;
\ No newline at end of file
@@ -1,15 +1,118 @@
+use std::collections::{BTreeMap, BTreeSet};
+use crate::ast::node::Node;
 use crate::ast::schema::Schema;
 use crate::ast::source::Source;
-use crate::diagnostics::diagnostics::Diagnostics;
+use crate::diagnostics::diagnostics::{Diagnostics, DiagnosticsLog};
 use crate::resolver::resolve_source::{resolve_source_constant_used_check, resolve_source_consumers, resolve_source_interface_shapes, resolve_source_model_declared_shapes, resolve_source_model_fields, resolve_source_model_shapes, resolve_source_references, resolve_source_types};
-use crate::resolver::resolver_context::ResolverContext;
+use crate::resolver::resolve_model::resolve_copied_field_diagnostics;
+use crate::resolver::resolve_type_expr::resolve_shape_operation_keys;
+use crate::resolver::resolver_context::{ResolverContext, ResolverState};
+use crate::resolver::unresolve_source::unresolve_source;
 
-pub(crate) fn resolve(schema: &Schema, diagnostics: &mut Diagnostics) {
-    let context = ResolverContext::new(diagnostics, schema);
-    // handle builtin
-    resolve_sources(&context, &schema.builtin_sources());
-    // handle user sources
-    resolve_sources(&context, &schema.user_sources());
+pub(crate) fn resolve(schema: &mut Schema, diagnostics: &mut Diagnostics) {
+    let mut resolver_diagnostics = Diagnostics::new();
+    let mut state = {
+        let context = ResolverContext::new(&mut resolver_diagnostics, schema, ResolverState::default());
+        // handle builtin
+        resolve_sources(&context, &schema.builtin_sources());
+        // handle user sources
+        resolve_sources(&context, &schema.user_sources());
+        context.take_state()
+    };
+    insert_active_diagnostics(schema, diagnostics, &resolver_diagnostics);
+    insert_active_diagnostics(schema, diagnostics, &check_constant_uses(schema, &state));
+    state.diagnostics = resolver_diagnostics;
+    schema.resolver_state = state;
+}
+
+/// Resolve the sources parsed again after a reparse and the sources importing them. The other
+/// sources, including the builtin ones, are kept resolved along with their diagnostics.
+pub(crate) fn resolve_user_sources(schema: &mut Schema, diagnostics: &mut Diagnostics) {
+    let mut state = std::mem::take(&mut schema.resolver_state);
+    let sources = sources_to_resolve_again(schema, &state);
+    for source in &sources {
+        unresolve_source(source);
+    }
+    let kept: BTreeSet<usize> = schema.sources.keys().filter(|id| !sources.iter().any(|source| source.id == **id)).cloned().collect();
+    let kept_paths: BTreeSet<&str> = kept.iter().map(|id| schema.source(*id).unwrap().file_path.as_str()).collect();
+    state.retain_sources(&kept, &kept_paths);
+    let mut resolver_diagnostics = std::mem::take(&mut state.diagnostics);
+    let mut state = {
+        let context = ResolverContext::new(&mut resolver_diagnostics, schema, state);
+        resolve_sources(&context, &sources);
+        context.take_state()
+    };
+    insert_active_diagnostics(schema, diagnostics, &resolver_diagnostics);
+    insert_active_diagnostics(schema, diagnostics, &check_constant_uses(schema, &state));
+    state.diagnostics = resolver_diagnostics;
+    schema.resolver_state = state;
+}
+
+// user sources which weren't resolved before, and the sources importing them directly or
+// through other sources
+fn sources_to_resolve_again<'a>(schema: &'a Schema, state: &ResolverState) -> Vec<&'a Source> {
+    let graph = schema.import_graph();
+    let mut paths: BTreeSet<&str> = schema.user_sources().iter().filter(|source| !state.resolved_sources.contains(&source.id)).map(|source| source.file_path.as_str()).collect();
+    loop {
+        let importers: Vec<&str> = graph.iter().filter(|(importer, imported)| {
+            !paths.contains(importer.as_str()) && imported.iter().any(|file_path| paths.contains(file_path.as_str()))
+        }).map(|(importer, _)| importer.as_str()).collect();
+        if importers.is_empty() {
+            break
+        }
+        paths.extend(importers);
+    }
+    schema.user_sources().into_iter().filter(|source| paths.contains(source.file_path.as_str())).collect()
+}
+
+// uses are counted over all sources, including the ones kept resolved from a previous schema
+fn check_constant_uses(schema: &Schema, state: &ResolverState) -> Diagnostics {
+    for source in schema.user_sources() {
+        reset_constant_use_counts(&source.children);
+    }
+    for path in state.constant_uses.values().flatten() {
+        if let Some(Node::ConstantDeclaration(constant)) = schema.find_top_by_path(path) {
+            constant.use_count.set(constant.use_count.get() + 1);
+        }
+    }
+    let mut diagnostics = Diagnostics::new();
+    {
+        let context = ResolverContext::new(&mut diagnostics, schema, ResolverState::default());
+        for source in schema.user_sources() {
+            if !source.builtin {
+                context.start_source(source);
+                resolve_source_constant_used_check(&context);
+            }
+        }
+    }
+    diagnostics
+}
+
+fn reset_constant_use_counts(children: &BTreeMap<usize, Node>) {
+    for node in children.values() {
+        match node {
+            Node::ConstantDeclaration(constant) => constant.use_count.set(0),
+            Node::Namespace(namespace) => reset_constant_use_counts(&namespace.children),
+            _ => (),
+        }
+    }
+}
+
+// nodes of inactive profiles may refer to each other, they are not checked
+fn insert_active_diagnostics(schema: &Schema, diagnostics: &mut Diagnostics, resolver_diagnostics: &Diagnostics) {
+    let is_active = |log: &dyn DiagnosticsLog| schema.source_at_path(log.source_path()).map_or(true, |source| {
+        !source.inactive_spans.iter().any(|span| span.contains(log.span().start))
+    });
+    for error in resolver_diagnostics.errors() {
+        if is_active(error) {
+            diagnostics.insert(error.clone());
+        }
+    }
+    for warning in resolver_diagnostics.warnings() {
+        if is_active(warning) {
+            diagnostics.insert(warning.clone());
+        }
+    }
 }
 
 fn resolve_sources<'a>(context: &'a ResolverContext<'a>, sources: &Vec<&'a Source>) {
@@ -41,10 +144,6 @@ fn resolve_sources<'a>(context: &'a ResolverContext<'a>, sources: &Vec<&'a Sourc
         context.start_source(source);
         resolve_source_consumers(context);
     }
-    for source in sources {
-        if !source.builtin {
-            context.start_source(source);
-            resolve_source_constant_used_check(context);
-        }
-    }
+    resolve_shape_operation_keys(context);
+    resolve_copied_field_diagnostics(context);
 }
\ No newline at end of file
@@ -150,9 +150,9 @@ fn try_resolve_argument_list_for_callable_variant<'a, 'b>(
                     let desired_type_original = argument_declaration.type_expr().resolved();
                     let mut desired_type = flatten_field_type_reference(desired_type_original.replace_keywords(keywords_map).replace_generics(&generics_map), context);
                     resolve_expression(named_argument.value(), context, &desired_type, keywords_map);
-                    if !desired_type.test(named_argument.value().resolved().r#type()) {
+                    if !named_argument.value().resolved().test(&desired_type) {
                         if !desired_type.is_undetermined() && !named_argument.value().resolved().r#type.is_undetermined() {
-                            errors.push(context.generate_diagnostics_error(named_argument.value().span(), format!("expect {}, found {}", desired_type, named_argument.value().resolved().r#type())))
+                            errors.push(context.generate_diagnostics_error(named_argument.value().span(), format!("expect {}, found {}", desired_type, named_argument.value().resolved().found_type(&desired_type))))
                         }
                     } else {
                         if desired_type.is_field_name() {
@@ -233,9 +233,9 @@ fn try_resolve_argument_list_for_callable_variant<'a, 'b>(
                         let desired_type_original = argument_declaration.type_expr().resolved();
                         let mut desired_type = flatten_field_type_reference(desired_type_original.replace_keywords(keywords_map).replace_generics(&generics_map), context);
                         resolve_expression(unnamed_argument.value(), context, &desired_type, keywords_map);
-                        if !desired_type.test(unnamed_argument.value().resolved().r#type()) {
+                        if !unnamed_argument.value().resolved().test(&desired_type) {
                             if !desired_type.is_undetermined() && !unnamed_argument.value().resolved().r#type().is_undetermined() {
-                                errors.push(context.generate_diagnostics_error(unnamed_argument.value().span(), format!("expect {}, found {}", desired_type, unnamed_argument.value().resolved().r#type())))
+                                errors.push(context.generate_diagnostics_error(unnamed_argument.value().span(), format!("expect {}, found {}", desired_type, unnamed_argument.value().resolved().found_type(&desired_type))))
                             }
                         } else {
                             if desired_type.is_field_name() {
@@ -46,10 +46,10 @@ pub(super) fn resolve_config_references<'a>(config: &'a Config, context: &'a Res
             if let Some(named_expression) = config.dictionary_literal().expressions().find(|named_expression| named_expression.key().named_key_without_resolving().is_some() && named_expression.key().named_key_without_resolving().unwrap() == field.identifier().name() && named_expression.is_available()) {
                 context.push_dependency(named_expression.value().path().clone());
                 resolve_expression(named_expression.value(), context, field.type_expr().resolved(), &btreemap! {});
-                let r#type = named_expression.value().resolved().r#type();
-                if !r#type.is_undetermined() {
-                    if !field.type_expr().resolved().test(r#type) {
-                        context.insert_diagnostics_error(named_expression.value().span(), format!("expect {}, found {}", field.type_expr().resolved(), r#type));
+                let resolved = named_expression.value().resolved();
+                if !resolved.r#type().is_undetermined() {
+                    if !resolved.test(field.type_expr().resolved()) {
+                        context.insert_diagnostics_error(named_expression.value().span(), format!("expect {}, found {}", field.type_expr().resolved(), resolved.found_type(field.type_expr().resolved())));
                     }
                 }
                 context.pop_dependency();
@@ -1,5 +1,6 @@
 use maplit::btreemap;
 use crate::ast::constant_declaration::{ConstantDeclaration};
+use crate::evaluator::evaluate_expression::evaluate_expression;
 use crate::expr::ExprInfo;
 use crate::r#type::r#type::Type;
 use crate::resolver::resolve_expression::resolve_expression;
@@ -16,9 +17,12 @@ pub(super) fn resolve_constant_references<'a>(constant: &'a ConstantDeclaration,
     }
     let undetermined = Type::Undetermined;
     context.push_dependency(constant.path.clone());
-    let resolved = resolve_expression(constant.expression(), context, constant.type_expr().map_or(&undetermined, |t| t.resolved()), &btreemap! {});
+    let mut resolved = resolve_expression(constant.expression(), context, constant.type_expr().map_or(&undetermined, |t| t.resolved()), &btreemap! {});
+    if !resolved.r#type().is_undetermined() {
+        resolved.value = evaluate_expression(constant.expression());
+    }
     if let Some(type_expr) = constant.type_expr() {
-        if type_expr.resolved().test(resolved.r#type()) {
+        if resolved.test(type_expr.resolved()) {
             constant.resolve(ExprInfo {
                 r#type: type_expr.resolved().clone(),
                 value: resolved.value().cloned(),
@@ -36,7 +40,7 @@ pub(super) fn resolve_constant_references<'a>(constant: &'a ConstantDeclaration,
                     reference_info: resolved.reference_info().cloned(),
                 });
             } else {
-                context.insert_diagnostics_error(constant.expression().span(), format!("expect {}, found {}", type_expr.resolved(), resolved.r#type()));
+                context.insert_diagnostics_error(constant.expression().span(), format!("expect {}, found {}", type_expr.resolved(), resolved.found_type(type_expr.resolved())));
                 constant.resolve(resolved.type_altered(type_expr.resolved().clone()));
             }
         }
@@ -100,8 +100,8 @@ pub(super) fn resolve_data_set_records<'a>(data_set: &'a DataSet, context: &'a R
                         }
                         let value_span = value_expression.span();
                         let value_resolved = resolve_expression(value_expression, context, field.type_expr().resolved(), &btreemap! {});
-                        if !field.type_expr().resolved().test(value_resolved.r#type()) {
-                            context.insert_diagnostics_error(value_span, format!("expect {}, found {}", field.type_expr().resolved(), value_resolved.r#type()));
+                        if !value_resolved.test(field.type_expr().resolved()) {
+                            context.insert_diagnostics_error(value_span, format!("expect {}, found {}", field.type_expr().resolved(), value_resolved.found_type(field.type_expr().resolved())));
                         }
                     } else if let Some(_relation_settings) = field.resolved().class.as_model_relation() {
                         if let Some(model_reference) = field.type_expr().resolved().unwrap_optional().unwrap_array().unwrap_optional().as_model_object() {
@@ -133,8 +133,8 @@ pub(super) fn resolve_data_set_records<'a>(data_set: &'a DataSet, context: &'a R
                         }
                         let value_span = value_expression.span();
                         let value_resolved = resolve_expression(value_expression, context, field.type_expr().resolved(), &btreemap! {});
-                        if !field.type_expr().resolved().test(value_resolved.r#type()) {
-                            context.insert_diagnostics_error(value_span, format!("expect {}, found {}", field.type_expr().resolved(), value_resolved.r#type()));
+                        if !value_resolved.test(field.type_expr().resolved()) {
+                            context.insert_diagnostics_error(value_span, format!("expect {}, found {}", field.type_expr().resolved(), value_resolved.found_type(field.type_expr().resolved())));
                         }
                     }
                 } else {
@@ -5,10 +5,12 @@ use maplit::btreemap;
 use crate::value::value::Value;
 use crate::ast::arith_expr::{ArithExpr, ArithExprOperator};
 use crate::ast::expression::{Expression, ExpressionKind};
+use crate::ast::literals::NumericLiteral;
 use crate::ast::r#enum::{Enum, EnumMember};
 use crate::ast::reference_space::ReferenceSpace;
 use crate::resolver::resolve_argument_list_declaration::resolve_argument_list_declaration;
 use crate::resolver::resolve_decorator::resolve_decorator;
+use crate::resolver::resolve_expression::resolve_string_literal_value;
 use crate::resolver::resolver_context::ResolverContext;
 use crate::traits::node_trait::NodeTrait;
 use crate::traits::resolved::Resolve;
@@ -55,7 +57,7 @@ pub(super) fn resolve_enum_member<'a>(
                     )
                 },
                 ExpressionKind::NumericLiteral(n) => {
-                    let value = n.value.as_int().unwrap();
+                    let value = resolve_enum_member_numeric_literal(n, context);
                     member.resolve(Value::Int(value));
                     map.lock().unwrap().insert(member.identifier().name(), value);
                 },
@@ -68,7 +70,12 @@ pub(super) fn resolve_enum_member<'a>(
             }
         } else {
             match member_expression.kind.as_string_literal() {
-                Some(s) => member.resolve(Value::String(s.value.clone())),
+                Some(s) => if let Some(value) = resolve_string_literal_value(s, context) {
+                    member.resolve(Value::String(value))
+                } else {
+                    member.resolve(Value::String(member.identifier().name().to_owned()));
+                    context.insert_diagnostics_error(member_expression.span(), "EnumMemberError: Enum expr expression should be constant")
+                },
                 None => {
                     member.resolve(Value::String(member.identifier().name().to_owned()));
                     context.insert_diagnostics_error(
@@ -99,7 +106,7 @@ fn resolve_enum_member_expression<'a>(expression: &Expression, context: &Resolve
             context.insert_diagnostics_error(expression.span(), "EnumMemberError: Only number literals and enum variant literals are allowed");
             0
         },
-        ExpressionKind::NumericLiteral(n) => n.value.as_int().unwrap(),
+        ExpressionKind::NumericLiteral(n) => resolve_enum_member_numeric_literal(n, context),
         ExpressionKind::Group(g) => resolve_enum_member_expression(g.expression(), context, map),
         ExpressionKind::EnumVariantLiteral(e) => if let Some(v) = map.lock().unwrap().get(e.identifier().name()) {
             *v
@@ -163,5 +170,20 @@ fn resolve_enum_member_expr<'a>(expr: &'a ArithExpr, context: &ResolverContext<'
             context.insert_diagnostics_error(u_postfix_op.span, "force unwrap is not allowed in enum member definition");
             0
         }
+        ArithExpr::TernaryOperation(ternary) => {
+            let condition = resolve_enum_member_expr(ternary.condition(), context, map);
+            let consequent = resolve_enum_member_expression(ternary.consequent(), context, map);
+            let alternative = resolve_enum_member_expression(ternary.alternative(), context, map);
+            if condition != 0 { consequent } else { alternative }
+        }
+    }
+}
+
+fn resolve_enum_member_numeric_literal(n: &NumericLiteral, context: &ResolverContext) -> i32 {
+    if let Some(value) = n.value.as_int() {
+        value
+    } else {
+        context.insert_diagnostics_error(n.span, format!("EnumMemberError: {} is out of range of Int", n.display));
+        0
     }
 }
\ No newline at end of file
@@ -1,10 +1,10 @@
 use std::collections::{BTreeMap, BTreeSet};
-use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
+use std::str::FromStr;
+use bigdecimal::{BigDecimal, ToPrimitive};
 use array_tool::vec::Join;
 use indexmap::{IndexMap, indexmap};
 use itertools::Itertools;
 use maplit::{btreemap, hashset};
-use crate::value::range::Range;
 use crate::value::{value, Value};
 use crate::value::option_variant::OptionVariant;
 use crate::ast::argument_list::ArgumentList;
@@ -13,17 +13,19 @@ use crate::ast::bracket_expression::BracketExpression;
 use crate::ast::callable_variant::CallableVariant;
 use crate::ast::expression::{Expression, ExpressionKind};
 use crate::ast::group::Group;
-use crate::ast::literals::{ArrayLiteral, BoolLiteral, DictionaryLiteral, EnumVariantLiteral, NullLiteral, NumericLiteral, RegexLiteral, StringLiteral, TupleLiteral};
+use crate::ast::literals::{ArrayLiteral, BoolLiteral, DateLiteral, DictionaryLiteral, EnumVariantLiteral, NullLiteral, NumericLiteral, RegexLiteral, StringLiteral, StringSegment, TupleLiteral};
 use crate::ast::reference_space::ReferenceSpace;
+use crate::ast::span::Span;
 use crate::r#type::keyword::Keyword;
 use crate::r#type::r#type::Type;
 use crate::r#type::synthesized_enum::SynthesizedEnum;
 use crate::resolver::resolve_argument_list::resolve_argument_list;
-use crate::resolver::resolve_identifier::resolve_identifier_with_diagnostic_message;
+use crate::resolver::resolve_identifier::{resolve_identifier_path_names_with_filter_to_expr_info, resolve_identifier_with_diagnostic_message, warn_if_deprecated, warn_if_reference_deprecated};
 use crate::resolver::resolve_pipeline::resolve_pipeline;
 use crate::resolver::resolve_unit::resolve_unit;
 use crate::resolver::resolver_context::ResolverContext;
 use crate::traits::has_availability::HasAvailability;
+use crate::traits::named_identifiable::NamedIdentifiable;
 use crate::traits::node_trait::NodeTrait;
 use crate::traits::resolved::{Resolve, ResolveAndClone};
 use crate::expr::{ExprInfo, ReferenceInfo, ReferenceType};
@@ -34,6 +36,7 @@ use crate::resolver::resolve_type_as_value_expression::resolve_type_as_value_exp
 use crate::search::search_identifier_path::{search_identifier_path_names_with_filter_to_top, search_identifier_path_names_with_filter_to_top_multiple};
 use crate::utils::top_filter::top_filter_for_reference_type;
 use crate::value::interface_enum_variant::InterfaceEnumVariant;
+use crate::evaluator::evaluate_operation::{checked_binary_operation, checked_unary_operation, evaluate_force_unwrap, evaluate_ternary_operation, evaluate_unary_operation, is_integer_zero, IntegerOverflow};
 
 pub(super) fn resolve_expression<'a>(expression: &'a Expression, context: &'a ResolverContext<'a>, expected: &Type, keywords_map: &BTreeMap<Keyword, Type>) -> ExprInfo {
     let t = resolve_expression_kind(&expression.kind, context, expected, keywords_map);
@@ -55,6 +58,7 @@ fn resolve_expression_kind<'a>(expression: &'a ExpressionKind, context: &'a Reso
         ExpressionKind::NumericLiteral(n) => resolve_numeric_literal(n, context, &expected.expect_for_literal()),
         ExpressionKind::StringLiteral(e) => resolve_string_literal(e, context, &expected.expect_for_literal()),
         ExpressionKind::RegexLiteral(e) => resolve_regex_literal(e, context, &expected.expect_for_literal()),
+        ExpressionKind::DateLiteral(d) => resolve_date_literal(d, context, &expected.expect_for_literal()),
         ExpressionKind::BoolLiteral(b) => resolve_bool_literal(b, context, &expected.expect_for_literal()),
         ExpressionKind::NullLiteral(n) => resolve_null_literal(n, context, &expected.expect_for_literal()),
         ExpressionKind::EnumVariantLiteral(e) => resolve_enum_variant_literal(e, context, &expected.expect_for_enum_variant_literal()),
@@ -87,90 +91,112 @@ fn resolve_numeric_literal<'a>(n: &NumericLiteral, context: &'a ResolverContext<
         expected = expected.unwrap_optional();
     }
     let undetermined = Type::Undetermined;
-    expected = if let Some(types) = expected.as_union() {
-        types.iter().find_map(|t| if t.is_int_32_or_64() || t.is_float_32_or_64() {
+    if let Some(types) = expected.as_union() {
+        expected = types.iter().find_map(|t| if t.is_int_32_or_64() || t.is_float_32_or_64() || t.is_decimal() {
             Some(t)
         } else {
             None
         }).unwrap_or(&undetermined)
-    } else {
-        &undetermined
-    };
-    match expected {
-        Type::Undetermined => if n.value.is_int64() {
-            ExprInfo {
-                r#type: Type::Int64,
-                value: Some(n.value.clone()),
-                reference_info: None,
-            }
-        } else if n.value.is_int() {
-            ExprInfo {
-                r#type: Type::Int,
-                value: Some(n.value.clone()),
-                reference_info: None,
-            }
-        } else if n.value.is_float() {
-            ExprInfo {
-                r#type: Type::Float,
-                value: Some(n.value.clone()),
-                reference_info: None,
-            }
-        } else {
-            unreachable!()
-        },
-        Type::Int => if n.value.is_any_int() {
-            ExprInfo {
-                r#type: Type::Int,
-                value: Some(Value::Int(n.value.to_int().unwrap())),
-                reference_info: None,
-            }
-        } else {
-            context.insert_diagnostics_error(n.span, "value is not int");
-            ExprInfo::undetermined()
-        },
-        Type::Int64 => if n.value.is_any_int() {
-            ExprInfo {
-                r#type: Type::Int64,
-                value: Some(Value::Int64(n.value.to_int64().unwrap())),
-                reference_info: None,
-            }
-        } else {
-            context.insert_diagnostics_error(n.span, "value is not int64");
-            ExprInfo::undetermined()
-        },
-        Type::Float32 => if n.value.is_any_float() {
-            ExprInfo {
-                r#type: Type::Float32,
-                value: Some(Value::Float32(n.value.to_float32().unwrap())),
-                reference_info: None,
-            }
-        } else {
-            context.insert_diagnostics_error(n.span, "ValueError: value is of wrong type");
-            ExprInfo::undetermined()
-        },
-        Type::Float => if n.value.is_any_float() {
-            ExprInfo {
-                r#type: Type::Float,
-                value: Some(Value::Float(n.value.to_float().unwrap())),
-                reference_info: None,
-            }
-        } else {
-            context.insert_diagnostics_error(n.span, "ValueError: value is of wrong type");
-            ExprInfo::undetermined()
-        },
-        _ => {
-            context.insert_diagnostics_error(n.span, "ValueError: value is of wrong type");
-            ExprInfo::undetermined()
+    }
+    let is_integer = n.value.is_any_int() || n.value.as_decimal().map_or(false, |d| d.is_integer());
+    let value = match expected {
+        Type::Int if is_integer => n.value.to_int64().and_then(|i| i32::try_from(i).ok()).map(Value::Int),
+        Type::Int64 if is_integer => n.value.to_int64().map(Value::Int64),
+        Type::Float32 => numeric_literal_to_float(n).map(|f| f as f32).filter(|f| f.is_finite()).map(Value::Float32),
+        Type::Float => numeric_literal_to_float(n).filter(|f| f.is_finite()).map(Value::Float),
+        Type::Decimal => Some(Value::Decimal(numeric_literal_to_decimal(n))),
+        _ => return ExprInfo {
+            r#type: if n.value.is_int64() {
+                Type::Int64
+            } else if n.value.is_int() {
+                Type::Int
+            } else if n.value.is_float() {
+                Type::Float
+            } else {
+                Type::Decimal
+            },
+            value: Some(n.value.clone()),
+            reference_info: None,
         }
+    };
+    if value.is_none() {
+        context.insert_diagnostics_error(n.span, format!("ValueError: {} is out of range of {}", n.display, expected));
+    }
+    ExprInfo {
+        r#type: expected.clone(),
+        value,
+        reference_info: None,
     }
 }
 
-fn resolve_string_literal<'a>(s: &StringLiteral, _context: &'a ResolverContext<'a>, _expected: &Type) -> ExprInfo {
+fn numeric_literal_to_float(n: &NumericLiteral) -> Option<f64> {
+    match n.value.as_decimal() {
+        Some(decimal) => decimal.to_f64(),
+        None => n.value.to_float(),
+    }
+}
+
+fn numeric_literal_to_decimal(n: &NumericLiteral) -> BigDecimal {
+    match &n.value {
+        Value::Int(i) => BigDecimal::from(*i as i64),
+        Value::Int64(i) => BigDecimal::from(*i),
+        Value::Decimal(d) => d.clone(),
+        _ => BigDecimal::from_str(&n.display.replace("_", "")).unwrap(),
+    }
+}
+
+fn resolve_string_literal<'a>(s: &StringLiteral, context: &'a ResolverContext<'a>, _expected: &Type) -> ExprInfo {
     ExprInfo {
         r#type: Type::String,
-        value: Some(Value::String(s.value.clone())),
+        value: resolve_string_literal_value(s, context).map(Value::String),
         reference_info: None,
+    }
+}
+
+// the value is only known when every interpolated constant has a value
+pub(super) fn resolve_string_literal_value<'a>(s: &StringLiteral, context: &'a ResolverContext<'a>) -> Option<String> {
+    let mut value = Some(String::new());
+    for segment in s.segments() {
+        match segment {
+            StringSegment::Text(text) => if let Some(value) = value.as_mut() {
+                value.push_str(text);
+            },
+            StringSegment::Interpolation(span, names) => {
+                let interpolated = resolve_string_interpolation(*span, names, context);
+                value = value.zip(interpolated).map(|(value, interpolated)| value + &interpolated);
+            }
+        }
+    }
+    value
+}
 
+// interpolations without a constant value leave the string to be evaluated at runtime
+fn resolve_string_interpolation<'a>(span: Span, names: &Vec<String>, context: &'a ResolverContext<'a>) -> Option<String> {
+    let Some(expr_info) = resolve_identifier_path_names_with_filter_to_expr_info(
+        &names.iter().map(AsRef::as_ref).collect(),
+        context.schema,
+        context.source(),
+        &context.current_namespace().map_or(vec![], |n| n.str_path()),
+        &top_filter_for_reference_type(ReferenceSpace::Default),
+        context.current_availability(),
+        context,
+    ) else {
+        context.insert_diagnostics_error(span, "undefined identifier");
+        return None
+    };
+    warn_if_reference_deprecated(span, &expr_info, context);
+    if expr_info.reference_info().map(|r| r.r#type()) != Some(ReferenceType::Constant) {
+        context.insert_diagnostics_error(span, "only constants can be interpolated");
+        return None
+    }
+    match expr_info.value()? {
+        Value::String(string) => Some(string.clone()),
+        Value::Decimal(decimal) => Some(decimal.to_string()),
+        value if value.is_any_int_or_float() || value.is_bool() => Some(value.to_string()),
+        _ => {
+            context.insert_diagnostics_error(span, format!("cannot interpolate {}", expr_info.r#type()));
+            None
+        }
     }
 }
 
@@ -183,6 +209,28 @@ fn resolve_regex_literal<'a>(r: &RegexLiteral, _context: &'a ResolverContext<'a>
     }
 }
 
+fn resolve_date_literal<'a>(d: &DateLiteral, _context: &'a ResolverContext<'a>, expected: &Type) -> ExprInfo {
+    match &d.value {
+        // dates are accepted where datetimes are expected
+        Value::Date(date) if expected.is_datetime() => ExprInfo {
+            r#type: Type::DateTime,
+            value: Some(Value::DateTime(date.and_hms_opt(0, 0, 0).unwrap().and_utc())),
+            reference_info: None,
+        },
+        Value::Date(_) => ExprInfo {
+            r#type: Type::Date,
+            value: Some(d.value.clone()),
+            reference_info: None,
+        },
+        Value::DateTime(_) => ExprInfo {
+            r#type: Type::DateTime,
+            value: Some(d.value.clone()),
+            reference_info: None,
+        },
+        _ => ExprInfo::type_only(Type::Undetermined),
+    }
+}
+
 fn resolve_bool_literal<'a>(r: &BoolLiteral, _context: &'a ResolverContext<'a>, _expected: &Type) -> ExprInfo {
     ExprInfo {
         r#type: Type::Bool,
@@ -212,6 +260,7 @@ pub(super) fn resolve_enum_variant_literal<'a>(e: &'a EnumVariantLiteral, contex
                 reference_info: None,
             }
         };
+        warn_if_deprecated(e.identifier().span, member.identifier().name(), member.comment(), context);
         if r#enum.interface {
             if let Some(argument_list_declaration) = member.argument_list_declaration() {
                 if let Some(argument_list) = e.argument_list() {
@@ -289,6 +338,7 @@ pub(super) fn resolve_enum_variant_literal<'a>(e: &'a EnumVariantLiteral, contex
         resolve_enum_variant_literal_from_synthesized_enum(e, synthesized_enum, context, expected)
     } else if let Some(reference) = expected.as_synthesized_enum_reference() {
         if let Some(synthesized_enum) = reference.fetch_synthesized_definition(context.schema) {
+            warn_if_model_field_deprecated(e, reference.owner.as_ref(), context);
             resolve_enum_variant_literal_from_synthesized_enum(e, synthesized_enum, context, expected)
         } else {
             context.insert_diagnostics_error(e.span, format!("expect {}, found .{}", reference, e.identifier().name()));
@@ -300,6 +350,7 @@ pub(super) fn resolve_enum_variant_literal<'a>(e: &'a EnumVariantLiteral, contex
         }
     } else if let Some(reference) = expected.as_synthesized_interface_enum_reference() {
         if let Some(synthesized_enum) = reference.fetch_synthesized_definition(context.schema) {
+            warn_if_model_field_deprecated(e, reference.owner.as_ref(), context);
             resolve_enum_variant_literal_from_synthesized_interface_enum(e, synthesized_enum, context, expected)
         } else {
             context.insert_diagnostics_error(e.span, format!("expect {}, found .{}", reference, e.identifier().name()));
@@ -319,7 +370,7 @@ pub(super) fn resolve_enum_variant_literal<'a>(e: &'a EnumVariantLiteral, contex
             &top_filter_for_reference_type(ReferenceSpace::Default),
             context.current_availability(),
         ).iter().map(|n| n.as_data_set().unwrap()) {
-            if let Some(group) = data_set.groups().find(|g| g.resolved().path() == that_model.as_model_object().unwrap().path()) {
+            if let Some(group) = data_set.groups().find(|g| g.is_resolved() && g.resolved().path() == that_model.as_model_object().unwrap().path()) {
                 if let Some(record) = group.records().find(|r| r.identifier().name() == e.identifier().name()) {
                     return ExprInfo {
                         r#type: expected.clone(),
@@ -466,6 +517,15 @@ pub(super) fn resolve_enum_variant_literal<'a>(e: &'a EnumVariantLiteral, contex
     }
 }
 
+fn warn_if_model_field_deprecated<'a>(e: &EnumVariantLiteral, owner: &Type, context: &'a ResolverContext<'a>) {
+    if let Some(model_reference) = owner.as_model_object() {
+        let model = context.schema.find_top_by_path(model_reference.path()).unwrap().as_model().unwrap();
+        if let Some(field) = model.fields().find(|f| f.identifier().name() == e.identifier().name()) {
+            warn_if_deprecated(e.identifier().span, field.identifier().name(), field.comment(), context);
+        }
+    }
+}
+
 fn resolve_enum_variant_literal_from_synthesized_enum<'a>(e: &EnumVariantLiteral, synthesized_enum: &SynthesizedEnum, context: &'a ResolverContext<'a>, source: &Type) -> ExprInfo {
     if synthesized_enum.keys.contains(&e.identifier().name) {
         ExprInfo {
@@ -491,8 +551,8 @@ fn check_and_build_args_for_interface_enum_variant_literal<'a>(argument_list: &'
         if let Some(name) = argument.name() {
             if let Some(t) = member_definition.args.get(name.name()) {
                 resolve_expression(argument.value(), context, t, &btreemap! {});
-                if !t.test(argument.value().resolved().r#type()) {
-                    context.insert_diagnostics_error(argument.value().span(), format!("expect {}, found {}", t, argument.value().resolved().r#type()));
+                if !argument.value().resolved().test(t) {
+                    context.insert_diagnostics_error(argument.value().span(), format!("expect {}, found {}", t, argument.value().resolved().found_type(t)));
                 } else {
                     if let Some(value) = argument.value().resolved().value() {
                         result.insert(name.name().to_owned(), value.clone());
@@ -650,11 +710,33 @@ pub(super) fn resolve_dictionary_literal<'a>(a: &'a DictionaryLiteral, context:
         }, keywords_map, expected)
     } else if let Some(object_type) = expected.as_synthesized_shape() {
         resolve_dictionary_literal_as_shape_type(a, context, Some(object_type), keywords_map, expected)
+    } else if let Some((member, shape)) = discriminated_union_member(a, expected, context) {
+        resolve_dictionary_literal_as_shape_type(a, context, Some(&shape), keywords_map, member)
     } else {
         resolve_dictionary_literal_as_shape_type(a, context, None, keywords_map, expected)
     }
 }
 
+// the member of a discriminated union is picked by the literal written for the discriminator
+fn discriminated_union_member<'a, 'b>(literal: &'a DictionaryLiteral, expected: &'b Type, context: &'a ResolverContext<'a>) -> Option<(&'b Type, SynthesizedShape)> {
+    let key = expected.union_discriminator(context.schema)?;
+    let named_expression = literal.expressions().find(|e| e.key().named_key_without_resolving() == Some(key.as_str()))?;
+    let discriminator = match &named_expression.value().kind {
+        ExpressionKind::StringLiteral(s) => Type::StringLiteral(s.value()?.to_owned()),
+        ExpressionKind::NumericLiteral(n) => Type::IntLiteral(n.value.to_int64()?),
+        ExpressionKind::BoolLiteral(b) => Type::BoolLiteral(b.value),
+        _ => return None,
+    };
+    expected.union_members().into_iter().find_map(|member| {
+        let shape = member.evaluate_synthesized_shape(context.schema)?;
+        if shape.get(&key) == Some(&discriminator) {
+            Some((member, shape))
+        } else {
+            None
+        }
+    })
+}
+
 pub(super) fn resolve_dictionary_literal_as_shape_type<'a>(literal: &'a DictionaryLiteral, context: &'a ResolverContext<'a>, type_shape: Option<&SynthesizedShape>, keywords_map: &BTreeMap<Keyword, Type>, source_type: &Type) -> ExprInfo {
     let mut resolved_map = indexmap! {};
     let mut value_should_be_none = false;
@@ -706,11 +788,16 @@ pub(super) fn resolve_dictionary_literal_as_shape_type<'a>(literal: &'a Dictiona
             if value_expr_info.value().is_none() {
                 value_should_be_none = true;
             }
-            if !value_type_expected.is_undetermined() && !value_type_expected.test(value_expr_info.r#type()) {
-                context.insert_diagnostics_error(named_expression.value().span(), format!("expect {}, found {}", value_type_expected, value_expr_info.r#type()));
+            if !value_type_expected.is_undetermined() && !value_expr_info.test(value_type_expected) {
+                context.insert_diagnostics_error(named_expression.value().span(), format!("expect {}, found {}", value_type_expected, value_expr_info.found_type(value_type_expected)));
             }
             if !this_entry_is_undefined && !value_should_be_none {
-                resolved_values.insert(key_expr_info.value().unwrap().as_str().unwrap().to_owned(), value_expr_info.r#type().coerce_value_to(value_expr_info.value().unwrap(), value_type_expected).unwrap_or(Value::Null));
+                let value = if value_type_expected.is_undetermined() {
+                    value_expr_info.value().cloned()
+                } else {
+                    value_expr_info.r#type().coerce_value_to(value_expr_info.value().unwrap(), value_type_expected)
+                };
+                resolved_values.insert(key_expr_info.value().unwrap().as_str().unwrap().to_owned(), value.unwrap_or(Value::Null));
             }
             if key_expr_info.r#type.is_string() && !key_expr_info.value().is_none() {
                 resolved_map.insert(key_expr_info.value().unwrap().as_str().unwrap().to_owned(), if value_type_expected.is_undetermined() {
@@ -785,6 +872,13 @@ pub(super) fn resolve_dictionary_literal_as_dictionary_type<'a>(a: &'a Dictionar
     }
 }
 
+fn report_integer_overflow<'a>(result: Result<Option<Value>, IntegerOverflow>, span: Span, r#type: &Type, context: &'a ResolverContext<'a>) -> Option<Value> {
+    result.unwrap_or_else(|_| {
+        context.insert_diagnostics_error(span, format!("ValueError: result is out of range of {}", r#type));
+        None
+    })
+}
+
 fn resolve_arith_expr<'a>(arith_expr: &'a ArithExpr, context: &'a ResolverContext<'a>, expected: &Type, keywords_map: &BTreeMap<Keyword, Type>,) -> ExprInfo {
     match arith_expr {
         ArithExpr::Expression(e) => resolve_expression(e.as_ref(), context, expected, keywords_map),
@@ -796,7 +890,7 @@ fn resolve_arith_expr<'a>(arith_expr: &'a ArithExpr, context: &'a ResolverContex
                         match v.r#type() {
                             Type::Int | Type::Int64 | Type::Float | Type::Float32 | Type::Decimal => ExprInfo {
                                 r#type: v.r#type.clone(),
-                                value: if let Some(v) = v.value { Some(v.neg().unwrap()) } else { None },
+                                value: report_integer_overflow(checked_unary_operation(unary.op, v.value()), unary.span, v.r#type(), context),
                                 reference_info: None,
 
                             },
@@ -813,14 +907,14 @@ fn resolve_arith_expr<'a>(arith_expr: &'a ArithExpr, context: &'a ResolverContex
                     }
                     ArithExprOperator::Not => ExprInfo {
                         r#type: Type::Bool,
-                        value: if let Some(v) = v.value { Some(v.normal_not()) } else { None },
+                        value: evaluate_unary_operation(unary.op, v.value()),
                         reference_info: None,
 
                     },
                     ArithExprOperator::BitNeg => match v.r#type() {
                         Type::Int | Type::Int64 | Type::Float | Type::Float32 | Type::Decimal => ExprInfo {
                             r#type: v.r#type.clone(),
-                            value: if let Some(v) = v.value { Some(v.not().unwrap()) } else { None },
+                            value: report_integer_overflow(checked_unary_operation(unary.op, v.value()), unary.span, v.r#type(), context),
                             reference_info: None,
                         },
                         _ => {
@@ -842,7 +936,7 @@ fn resolve_arith_expr<'a>(arith_expr: &'a ArithExpr, context: &'a ResolverContex
             let v = resolve_arith_expr(unary.lhs(), context, expected, keywords_map);
             ExprInfo {
                 r#type: v.r#type.unwrap_optional().clone(),
-                value: v.value,
+                value: evaluate_force_unwrap(v.value()),
                 reference_info: None,
             }
         }
@@ -914,39 +1008,45 @@ fn resolve_arith_expr<'a>(arith_expr: &'a ArithExpr, context: &'a ResolverContex
             } else {
                 Type::Undetermined
             };
-            let new_value = if new_type.is_undetermined() {
+            let divides_by_zero = matches!(binary.op, ArithExprOperator::Div | ArithExprOperator::Mod) && rhs.value.as_ref().map_or(false, is_integer_zero);
+            if divides_by_zero {
+                context.insert_diagnostics_error(binary.rhs().span(), "division by zero");
+            }
+            let new_value = if new_type.is_undetermined() || divides_by_zero {
                 None
-            } else if lhs.value.is_none() || rhs.value.is_none() {
+            } else {
+                report_integer_overflow(checked_binary_operation(binary.op, lhs.value(), rhs.value()), binary.span, &new_type, context)
+            };
+            ExprInfo {
+                r#type: new_type,
+                value: new_value,
+                reference_info: None,
+            }
+        }
+        ArithExpr::TernaryOperation(ternary) => {
+            let condition = resolve_arith_expr(ternary.condition(), context, &Type::Bool, keywords_map);
+            if !condition.r#type().is_undetermined() && !condition.r#type().is_bool() {
+                context.insert_diagnostics_error(ternary.condition().span(), "ternary condition must be Bool");
+            }
+            let consequent = resolve_expression(ternary.consequent(), context, expected, keywords_map);
+            let alternative = resolve_expression(ternary.alternative(), context, expected, keywords_map);
+            let new_type = if condition.r#type().is_undetermined() || consequent.r#type().is_undetermined() || alternative.r#type().is_undetermined() {
+                Type::Undetermined
+            } else if consequent.r#type().is_null() {
+                alternative.r#type().wrap_in_optional()
+            } else if alternative.r#type().is_null() {
+                consequent.r#type().wrap_in_optional()
+            } else if consequent.r#type().test(alternative.r#type()) {
+                consequent.r#type().clone()
+            } else if alternative.r#type().test(consequent.r#type()) {
+                alternative.r#type().clone()
+            } else {
+                Type::Union(vec![consequent.r#type().clone(), alternative.r#type().clone()])
+            };
+            let new_value = if new_type.is_undetermined() {
                 None
             } else {
-                Some(match binary.op {
-                    ArithExprOperator::Add => lhs.value.as_ref().unwrap().add(rhs.value.as_ref().unwrap()).unwrap(),
-                    ArithExprOperator::Sub => lhs.value.as_ref().unwrap().sub(rhs.value.as_ref().unwrap()).unwrap(),
-                    ArithExprOperator::Mul => lhs.value.as_ref().unwrap().mul(rhs.value.as_ref().unwrap()).unwrap(),
-                    ArithExprOperator::Div => lhs.value.as_ref().unwrap().div(rhs.value.as_ref().unwrap()).unwrap(),
-                    ArithExprOperator::Mod => lhs.value.as_ref().unwrap().rem(rhs.value.as_ref().unwrap()).unwrap(),
-                    ArithExprOperator::And => lhs.value.as_ref().unwrap().and(rhs.value.as_ref().unwrap()).clone(),
-                    ArithExprOperator::Or => lhs.value.as_ref().unwrap().or(rhs.value.as_ref().unwrap()).clone(),
-                    ArithExprOperator::BitAnd => lhs.value.as_ref().unwrap().bitand(rhs.value.as_ref().unwrap()).unwrap(),
-                    ArithExprOperator::BitXor => lhs.value.as_ref().unwrap().bitxor(rhs.value.as_ref().unwrap()).unwrap(),
-                    ArithExprOperator::BitOr => lhs.value.as_ref().unwrap().bitor(rhs.value.as_ref().unwrap()).unwrap(),
-                    ArithExprOperator::BitLS => lhs.value.as_ref().unwrap().shl(rhs.value.as_ref().unwrap()).unwrap(),
-                    ArithExprOperator::BitRS => lhs.value.as_ref().unwrap().shr(rhs.value.as_ref().unwrap()).unwrap(),
-                    ArithExprOperator::NullishCoalescing => if lhs.value.as_ref().unwrap().is_null() {
-                        rhs.value.as_ref().unwrap().clone()
-                    } else {
-                        rhs.value.as_ref().unwrap().clone()
-                    },
-                    ArithExprOperator::Gt => Value::Bool(lhs.value.as_ref().unwrap().gt(rhs.value.as_ref().unwrap())),
-                    ArithExprOperator::Gte => Value::Bool(lhs.value.as_ref().unwrap() >= rhs.value.as_ref().unwrap()),
-                    ArithExprOperator::Lt => Value::Bool(lhs.value.as_ref().unwrap().lt(rhs.value.as_ref().unwrap())),
-                    ArithExprOperator::Lte => Value::Bool(lhs.value.as_ref().unwrap() <= rhs.value.as_ref().unwrap()),
-                    ArithExprOperator::Eq => Value::Bool(lhs.value.as_ref().unwrap().eq(rhs.value.as_ref().unwrap())),
-                    ArithExprOperator::Neq => Value::Bool(!lhs.value.as_ref().unwrap().eq(rhs.value.as_ref().unwrap())),
-                    ArithExprOperator::RangeOpen => build_range_value(lhs.value.as_ref().unwrap(), rhs.value.as_ref().unwrap(), false),
-                    ArithExprOperator::RangeClose => build_range_value(lhs.value.as_ref().unwrap(), rhs.value.as_ref().unwrap(), true),
-                    _ => unreachable!()
-                })
+                evaluate_ternary_operation(condition.value(), consequent.value(), alternative.value())
             };
             ExprInfo {
                 r#type: new_type,
@@ -982,14 +1082,6 @@ fn build_range(lhs: &Type, rhs: &Type) -> Option<Type> {
     }
 }
 
-fn build_range_value(lhs: &Value, rhs: &Value, closed: bool) -> Value {
-    Value::Range(Range {
-        closed,
-        start: Box::new(lhs.clone()),
-        end: Box::new(rhs.clone()),
-    })
-}
-
 pub(super) fn resolve_expression_for_named_expression_key<'a>(expression: &'a Expression, context: &'a ResolverContext<'a>, expected: &Type, keywords_map: &BTreeMap<Keyword, Type>,) -> ExprInfo {
     expression.resolve_and_return(match &expression.kind {
         ExpressionKind::StringLiteral(s) => resolve_string_literal(s, context, expected),
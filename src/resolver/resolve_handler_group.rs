@@ -7,6 +7,7 @@ use crate::ast::span::Span;
 use crate::r#type::keyword::Keyword;
 use crate::r#type::r#type::Type;
 use crate::r#type::reference::Reference;
+use crate::r#type::synthesized_shape::SynthesizedShape;
 use crate::resolver::resolve_decorator::resolve_decorator;
 use crate::resolver::resolve_type_expr::resolve_type_expr;
 use crate::resolver::resolver_context::ResolverContext;
@@ -111,20 +112,7 @@ pub(super) fn validate_handler_related_types<'a, F>(r#type: &'a Type, span: Span
                 return ();
             }
             if let Some(shape) = t.unwrap().as_synthesized_shape() {
-                for (_, t) in shape.iter() {
-                    if let Some(e) = t.as_enum_variant() {
-                        let enum_declaration = context.schema.find_top_by_path(e.path()).unwrap().as_enum().unwrap();
-                        if enum_declaration.interface || enum_declaration.option {
-                            context.insert_diagnostics_error(span, "interface or option enum is disallowed");
-                            break
-                        }
-                    } else {
-                        if let Some(msg) = f(t) {
-                            context.insert_diagnostics_error(span, msg);
-                            break
-                        }
-                    }
-                }
+                validate_handler_related_shape(shape, span, context, &f);
             } else {
                 context.insert_diagnostics_error(span, "handler argument type should be interface or any");
             }
@@ -132,26 +120,44 @@ pub(super) fn validate_handler_related_types<'a, F>(r#type: &'a Type, span: Span
         Type::InterfaceObject(reference, gen) => {
             let interface = context.schema.find_top_by_path(reference.path()).unwrap().as_interface_declaration().unwrap();
             let shape = interface.shape_from_generics(gen);
-            for (_, t) in shape.iter() {
-                if let Some(e) = t.as_enum_variant() {
-                    let enum_declaration = context.schema.find_top_by_path(e.path()).unwrap().as_enum().unwrap();
-                    if enum_declaration.interface || enum_declaration.option {
-                        context.insert_diagnostics_error(span, "interface or option enum is disallowed");
-                        break
-                    }
-                } else {
-                    if let Some(msg) = f(t) {
-                        context.insert_diagnostics_error(span, msg);
-                        break
-                    }
+            validate_handler_related_shape(&shape, span, context, &f);
+        }
+        Type::ShapeOperation(shape_operation) => {
+            if let Some(shape) = shape_operation.fetch_synthesized_definition(context.schema) {
+                validate_handler_related_shape(&shape, span, context, &f);
+            }
+        }
+        Type::Union(_) => if r#type.union_discriminator(context.schema).is_some() {
+            for member in r#type.union_members() {
+                if let Some(shape) = member.evaluate_synthesized_shape(context.schema) {
+                    validate_handler_related_shape(&shape, span, context, &f);
                 }
             }
+        } else {
+            context.insert_diagnostics_error(span, "handler argument union should be discriminated by a literal key");
         }
         Type::DeclaredSynthesizedShape(_, __) => (),
         _ => context.insert_diagnostics_error(span, "handler argument type should be interface or any"),
     }
 }
 
+fn validate_handler_related_shape<'a, F>(shape: &SynthesizedShape, span: Span, context: &'a ResolverContext<'a>, f: &F) where F: Fn(&Type) -> Option<&'static str> {
+    for (_, t) in shape.iter() {
+        if let Some(e) = t.as_enum_variant() {
+            let enum_declaration = context.schema.find_top_by_path(e.path()).unwrap().as_enum().unwrap();
+            if enum_declaration.interface || enum_declaration.option {
+                context.insert_diagnostics_error(span, "interface or option enum is disallowed");
+                break
+            }
+        } else {
+            if let Some(msg) = f(t) {
+                context.insert_diagnostics_error(span, msg);
+                break
+            }
+        }
+    }
+}
+
 pub(super) fn is_valid_form_input_type<'a>(r#type: &'a Type) -> Option<&'static str> {
     match r#type {
         Type::Any => None,
@@ -171,11 +177,13 @@ pub(super) fn is_valid_form_input_type<'a>(r#type: &'a Type) -> Option<&'static
         Type::Dictionary(_) => Some("invalid form handler input type: Dictionary is not supported"),
         Type::Tuple(_) => Some("invalid form handler input type: Tuple is not supported"),
         Type::Range(_) => Some("invalid form handler input type: Range is not supported"),
+        Type::Union(_) if r#type.union_members().iter().all(|t| t.is_literal()) => None,
         Type::Union(_) => Some("invalid form handler input type: Union is not supported"),
         Type::Ignored => None,
         Type::EnumVariant(_) => None,
         Type::Model => Some("invalid form handler input type: Model is not supported"),
         Type::InterfaceObject(_, _items) => None,
+        Type::ShapeOperation(shape_operation) => is_valid_form_input_type(shape_operation.owner.as_ref()),
         Type::FieldType(_, _) => Some("invalid form handler input type: FieldType is not supported"),
         Type::FieldName(_) => Some("invalid form handler input type: FieldReference is not supported"),
         Type::GenericItem(_) => Some("invalid form handler input type: GenericsItem is not supported"),
@@ -215,11 +223,13 @@ pub(super) fn is_valid_json_input_type<'a>(r#type: &'a Type) -> Option<&'static
         Type::DeclaredSynthesizedShape(_, _) => None,
         Type::Tuple(_) => Some("invalid handler input type: Tuple is not supported"),
         Type::Range(_) => Some("invalid handler input type: Range is not supported"),
+        Type::Union(_) if r#type.union_members().iter().all(|t| t.is_literal()) => None,
         Type::Union(_) => Some("invalid handler input type: Union is not supported"),
         Type::Ignored => None,
         Type::EnumVariant(_) => None,
         Type::Model => Some("invalid form handler input type: Model is not supported"),
         Type::InterfaceObject(_, _) => None,
+        Type::ShapeOperation(shape_operation) => is_valid_json_input_type(shape_operation.owner.as_ref()),
         Type::FieldType(_, _) => Some("invalid handler input type: FieldType is not supported"),
         Type::FieldName(_) => Some("invalid handler input type: FieldReference is not supported"),
         Type::GenericItem(_) => Some("invalid form handler input type: GenericsItem is not supported"),
@@ -258,11 +268,13 @@ pub(super) fn is_valid_json_output_type<'a>(r#type: &'a Type) -> Option<&'static
         }
         Type::Tuple(_) => Some("invalid handler output type: Tuple is not supported"),
         Type::Range(_) => Some("invalid handler output type: Range is not supported"),
+        Type::Union(_) if r#type.union_members().iter().all(|t| t.is_literal()) => None,
         Type::Union(_) => Some("invalid handler output type: Union is not supported"),
         Type::Ignored => None,
         Type::EnumVariant(_) => None,
         Type::Model => Some("invalid form handler output type: Model is not supported"),
         Type::InterfaceObject(_, _) => None,
+        Type::ShapeOperation(shape_operation) => is_valid_json_output_type(shape_operation.owner.as_ref()),
         Type::FieldType(_, _) => Some("invalid handler output type: FieldType is not supported"),
         Type::FieldName(_) => Some("invalid handler output type: FieldReference is not supported"),
         Type::GenericItem(_) => Some("invalid form handler output type: GenericsItem is not supported"),
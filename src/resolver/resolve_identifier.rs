@@ -2,12 +2,14 @@ use std::sync::Arc;
 
 use crate::availability::Availability;
 use crate::expr::{ExprInfo, ReferenceInfo, ReferenceType};
+use crate::ast::doc_comment::DocComment;
 use crate::ast::identifier::Identifier;
 use crate::ast::identifier_path::IdentifierPath;
 use crate::ast::node::Node;
 use crate::ast::reference_space::ReferenceSpace;
 use crate::ast::schema::Schema;
 use crate::ast::source::Source;
+use crate::ast::span::Span;
 use crate::r#type::reference::Reference;
 use crate::r#type::Type;
 use crate::resolver::resolve_config::resolve_config_references;
@@ -53,7 +55,7 @@ pub(super) fn resolve_identifier_with_filter<'a>(
     filter: &Arc<dyn Fn(&Node) -> bool>,
     availability: Availability,
 ) -> Option<ExprInfo> {
-    resolve_identifier_path_names_with_filter_to_expr_info(
+    let result = resolve_identifier_path_names_with_filter_to_expr_info(
         &vec![identifier.name()],
         context.schema,
         context.source(),
@@ -61,7 +63,11 @@ pub(super) fn resolve_identifier_with_filter<'a>(
         filter,
         availability,
         context,
-    )
+    );
+    if let Some(expr_info) = &result {
+        warn_if_reference_deprecated(identifier.span, expr_info, context);
+    }
+    result
 }
 
 pub(super) fn resolve_identifier_path<'a>(
@@ -87,7 +93,7 @@ pub(super) fn resolve_identifier_path_with_filter<'a>(
     filter: &Arc<dyn Fn(&Node) -> bool>,
     availability: Availability,
 ) -> Option<ExprInfo> {
-    resolve_identifier_path_names_with_filter_to_expr_info(
+    let result = resolve_identifier_path_names_with_filter_to_expr_info(
         &identifier_path.names(),
         context.schema,
         context.source(),
@@ -95,7 +101,30 @@ pub(super) fn resolve_identifier_path_with_filter<'a>(
         filter,
         availability,
         context,
-    )
+    );
+    if let Some(expr_info) = &result {
+        warn_if_reference_deprecated(identifier_path.span, expr_info, context);
+    }
+    result
+}
+
+pub(super) fn warn_if_reference_deprecated<'a>(span: Span, expr_info: &ExprInfo, context: &'a ResolverContext<'a>) {
+    if let Some(reference_info) = expr_info.reference_info() {
+        if let Some(node) = context.schema.find_top_by_path(reference_info.reference().path()) {
+            warn_if_deprecated(span, node.name().unwrap_or_default(), node.comment(), context);
+        }
+    }
+}
+
+/// Warn at a usage of a declaration which is marked with `@deprecated` in its doc comment.
+pub(super) fn warn_if_deprecated<'a>(span: Span, name: &str, comment: Option<&DocComment>, context: &'a ResolverContext<'a>) {
+    if let Some(note) = comment.and_then(|c| c.deprecated()) {
+        if note.is_empty() {
+            context.insert_diagnostics_warning(span, format!("{name} is deprecated"));
+        } else {
+            context.insert_diagnostics_warning(span, format!("{name} is deprecated: {note}"));
+        }
+    }
 }
 
 pub(crate) fn resolve_identifier_path_names_with_filter_to_expr_info<'a>(
@@ -153,7 +182,7 @@ pub fn resolve_identifier_path_names_with_filter_to_top<'a>(
     reference
 }
 
-fn resolve_identifier_path_names_in_source_to_top<'a>(
+pub(super) fn resolve_identifier_path_names_in_source_to_top<'a>(
     identifier_path_names: &Vec<&str>,
     schema: &'a Schema,
     filter: &Arc<dyn Fn(&Node) -> bool>,
@@ -186,11 +215,15 @@ fn resolve_identifier_path_names_in_source_to_top<'a>(
         }
     }
     for import in source.imports() {
+        // selective imports only expose the listed names, aliased imports only expose
+        // names prefixed with the alias
+        let Some(imported_path_names) = import.imported_identifier_path_names(identifier_path_names) else {
+            continue
+        };
+        let imported_ns_str_path = if import.alias().is_some() { vec![] } else { ns_str_path.clone() };
         // find with imports
-        if let Some(from_source) = schema.sources().iter().find(|source| {
-            import.file_path.as_str() == source.file_path.as_str()
-        }).map(|s| *s) {
-            if let Some(found) = resolve_identifier_path_names_in_source_to_top(identifier_path_names, schema, filter, from_source, used_sources, &ns_str_path, availability) {
+        for from_source in schema.import_sources(import) {
+            if let Some(found) = resolve_identifier_path_names_in_source_to_top(&imported_path_names, schema, filter, from_source, used_sources, &imported_ns_str_path, availability) {
                 return Some(found)
             }
         }
@@ -200,7 +233,19 @@ fn resolve_identifier_path_names_in_source_to_top<'a>(
 
 pub(crate) fn top_to_expr_info<'a>(top: &'a Node, resolver_context: Option<&'a ResolverContext<'a>>) -> ExprInfo {
     match top {
-        Node::Import(_) => ExprInfo::undetermined(),
+        Node::Import(i) => if let Some(alias) = i.alias() {
+            ExprInfo {
+                r#type: Type::Undetermined,
+                value: None,
+                reference_info: Some(ReferenceInfo::new(
+                    ReferenceType::Namespace,
+                    Reference::new(i.path.clone(), vec![alias.to_owned()]),
+                    None
+                ))
+            }
+        } else {
+            ExprInfo::undetermined()
+        },
         Node::Config(c) => ExprInfo {
             r#type: Type::Undetermined,
             value: None,
@@ -277,8 +322,8 @@ pub(crate) fn top_to_expr_info<'a>(top: &'a Node, resolver_context: Option<&'a R
             }
         }
         Node::ConstantDeclaration(c) => {
-            if resolver_context.is_some() {
-                c.use_count.set(c.use_count.get() + 1);
+            if let Some(resolver_context) = resolver_context {
+                resolver_context.add_constant_use(c.path.clone());
             }
             if c.is_resolved() {
                 ExprInfo {
@@ -375,6 +420,15 @@ pub(crate) fn top_to_expr_info<'a>(top: &'a Node, resolver_context: Option<&'a R
                 None,
             ))
         },
+        Node::TypeAliasDeclaration(t) => ExprInfo {
+            r#type: Type::Undetermined,
+            value: None,
+            reference_info: Some(ReferenceInfo::new(
+                ReferenceType::TypeAlias,
+                Reference::new(t.path.clone(), t.string_path.clone()),
+                None,
+            ))
+        },
         Node::InterfaceDeclaration(i) => ExprInfo {
             r#type: Type::Undetermined,
             value: None,
@@ -506,11 +560,15 @@ fn resolve_identifier_path_names_in_source_to_top_multiple<'a>(
         }
     }
     for import in source.imports() {
+        // selective imports only expose the listed names, aliased imports only expose
+        // names prefixed with the alias
+        let Some(imported_path_names) = import.imported_identifier_path_names(identifier_path_names) else {
+            continue
+        };
+        let imported_ns_str_path = if import.alias().is_some() { vec![] } else { ns_str_path.clone() };
         // find with imports
-        if let Some(from_source) = schema.sources().iter().find(|source| {
-            import.file_path.as_str() == source.file_path.as_str()
-        }).map(|s| *s) {
-            result.extend(resolve_identifier_path_names_in_source_to_top_multiple(identifier_path_names, schema, filter, from_source, used_sources, &ns_str_path, availability));
+        for from_source in schema.import_sources(import) {
+            result.extend(resolve_identifier_path_names_in_source_to_top_multiple(&imported_path_names, schema, filter, from_source, used_sources, &imported_ns_str_path, availability));
         }
     }
     result
@@ -0,0 +1,29 @@
+use crate::ast::import::Import;
+use crate::availability::Availability;
+use crate::resolver::resolve_identifier::resolve_identifier_path_names_in_source_to_top;
+use crate::resolver::resolver_context::ResolverContext;
+use crate::utils::top_filter::top_filter_for_import;
+
+pub(super) fn resolve_import<'a>(import: &'a Import, context: &'a ResolverContext<'a>) {
+    let imported_sources = context.schema.import_sources(import);
+    if imported_sources.is_empty() {
+        return
+    }
+    let filter = top_filter_for_import();
+    for identifier in import.identifiers() {
+        if !imported_sources.iter().any(|imported_source| {
+            let mut used_sources = vec![];
+            resolve_identifier_path_names_in_source_to_top(
+                &vec![identifier.name()],
+                context.schema,
+                &filter,
+                imported_source,
+                &mut used_sources,
+                &vec![],
+                Availability::default(),
+            ).is_some()
+        }) {
+            context.insert_diagnostics_error(identifier.span, format!("ImportError: '{}' is not found in imported file", identifier.name()));
+        }
+    }
+}
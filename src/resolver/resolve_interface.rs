@@ -4,7 +4,6 @@ use maplit::{btreemap, btreeset};
 use crate::ast::interface::{InterfaceDeclaration, InterfaceDeclarationResolved};
 use crate::ast::reference_space::ReferenceSpace;
 use crate::ast::span::Span;
-use crate::ast::type_expr::TypeExpr;
 use crate::r#type::keyword::Keyword;
 use crate::r#type::reference::Reference;
 use crate::r#type::synthesized_shape::SynthesizedShape;
@@ -46,7 +45,7 @@ pub(super) fn resolve_interface_declaration_types<'a>(interface_declaration: &'a
             context,
             interface_declaration.define_availability,
         );
-        if !extend.resolved().is_interface_object() && !extend.resolved().is_synthesized_shape() && !extend.resolved().is_synthesized_shape_reference() {
+        if !extend.resolved().is_interface_object() && !extend.resolved().is_synthesized_shape() && !extend.resolved().is_synthesized_shape_reference() && !extend.resolved().is_shape_operation() {
             context.insert_diagnostics_error(extend.span(), "type is invalid for extending");
         }
     }
@@ -93,7 +92,7 @@ pub(super) fn resolve_interface_declaration_shapes<'a>(interface_declaration: &'
     let mut existing_keys = vec![];
     let mut extending_dependencies = btreeset![interface_declaration.str_path()];
     for extend in interface_declaration.extends() {
-        insert_extend_into_interface_map(extend.span(), extend, context, &mut map, &mut existing_keys, &mut extending_dependencies, vec![]);
+        insert_extend_into_interface_map(extend.span(), extend.resolved(), context, &mut map, &mut existing_keys, &mut extending_dependencies, vec![]);
     }
     for field in interface_declaration.fields() {
         if existing_keys.contains(&field.identifier().name) {
@@ -107,8 +106,8 @@ pub(super) fn resolve_interface_declaration_shapes<'a>(interface_declaration: &'
     interface_declaration.resolved_mut().shape = Some(shape);
 }
 
-fn insert_extend_into_interface_map<'a>(error_span: Span, extend: &'a TypeExpr, context: &'a ResolverContext<'a>, map: &mut IndexMap<String, Type>, existing_keys: &mut Vec<String>, extending_dependencies: &mut BTreeSet<Vec<&'a str>>, mut generics_maps: Vec<BTreeMap<String, Type>>) {
-    if let Some((reference, types)) = extend.resolved().as_interface_object() {
+fn insert_extend_into_interface_map<'a>(error_span: Span, extend: &'a Type, context: &'a ResolverContext<'a>, map: &mut IndexMap<String, Type>, existing_keys: &mut Vec<String>, extending_dependencies: &mut BTreeSet<Vec<&'a str>>, mut generics_maps: Vec<BTreeMap<String, Type>>) {
+    if let Some((reference, types)) = extend.as_interface_object() {
         if extending_dependencies.contains(&reference.str_path()) {
             context.insert_diagnostics_error(error_span, "circular extending found");
         } else {
@@ -117,7 +116,7 @@ fn insert_extend_into_interface_map<'a>(error_span: Span, extend: &'a TypeExpr,
             let generics_map = interface_for_extending.calculate_generics_map(types);
             generics_maps.push(generics_map);
             for extend_extend in interface_for_extending.extends() {
-                insert_extend_into_interface_map(error_span, extend_extend, context, map, existing_keys, extending_dependencies, generics_maps.clone());
+                insert_extend_into_interface_map(error_span, extend_extend.resolved(), context, map, existing_keys, extending_dependencies, generics_maps.clone());
             }
             let mut shape_for_this_interface = interface_for_extending.resolved().base_shape().clone();
             for alter in generics_maps.iter().rev() {
@@ -125,9 +124,15 @@ fn insert_extend_into_interface_map<'a>(error_span: Span, extend: &'a TypeExpr,
             }
             insert_synthesized_shape_into_interface_map(error_span, &shape_for_this_interface, context, map, existing_keys);
         }
-    } else if let Some(synthesized_shape) = extend.resolved().as_synthesized_shape() {
+    } else if let Some(synthesized_shape) = extend.as_synthesized_shape() {
         insert_synthesized_shape_into_interface_map(error_span, synthesized_shape, context, map, existing_keys);
-    } else if let Some(synthesized_shape_reference) = extend.resolved().as_synthesized_shape_reference() {
+    } else if let Some(shape_operation) = extend.as_shape_operation() {
+        // the shape of the owner is collected here since the shapes of other interfaces may not be resolved yet
+        let mut owner_map = indexmap! {};
+        insert_extend_into_interface_map(error_span, shape_operation.owner.as_ref(), context, &mut owner_map, &mut vec![], extending_dependencies, generics_maps);
+        let shape = shape_operation.apply(&SynthesizedShape::new(owner_map));
+        insert_synthesized_shape_into_interface_map(error_span, &shape, context, map, existing_keys);
+    } else if let Some(synthesized_shape_reference) = extend.as_synthesized_shape_reference() {
         if let Some(t) = synthesized_shape_reference.fetch_synthesized_definition(context.schema) {
             if let Some(synthesized_shape) = t.as_synthesized_shape() {
                 insert_synthesized_shape_into_interface_map(error_span, synthesized_shape, context, map, existing_keys);
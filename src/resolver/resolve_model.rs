@@ -1,15 +1,25 @@
+use std::collections::BTreeSet;
 use maplit::btreemap;
+use crate::ast::field::Field;
+use crate::ast::identifier_path::IdentifierPath;
 use crate::ast::model::{Model, ModelResolved};
+use crate::ast::node::Node;
 use crate::ast::reference_space::ReferenceSpace;
+use crate::ast::schema::Schema;
 use crate::r#type::keyword::Keyword;
 use crate::r#type::r#type::Type;
 use crate::r#type::reference::Reference;
 use crate::resolver::resolve_decorator::resolve_decorator;
 use crate::resolver::resolve_field::{FieldParentType, resolve_field_class, resolve_field_decorators, resolve_field_types};
 use crate::resolver::resolve_handler_group::{resolve_handler_declaration_decorators, resolve_handler_declaration_types};
+use crate::resolver::resolve_identifier::resolve_identifier_path_names_with_filter_to_top;
 use crate::resolver::resolve_include_handler_from_template::resolve_include_handler_from_template_decorators;
-use crate::resolver::resolver_context::ResolverContext;
+use crate::diagnostics::diagnostics::{DiagnosticsError, DiagnosticsLog, DiagnosticsWarning};
+use crate::resolver::resolver_context::{CopiedFieldDiagnostics, ResolverContext};
+use crate::traits::identifiable::Identifiable;
+use crate::traits::info_provider::InfoProvider;
 use crate::traits::resolved::Resolve;
+use crate::utils::top_filter::top_filter_for_model_extends;
 
 pub(super) fn resolve_model_fields<'a>(model: &'a Model, context: &'a ResolverContext<'a>) {
     let actual_availability = context.current_availability();
@@ -18,9 +28,10 @@ pub(super) fn resolve_model_fields<'a>(model: &'a Model, context: &'a ResolverCo
         context.insert_duplicated_identifier(model.identifier().span);
     }
     context.clear_examined_fields();
+    resolve_model_extends(model, context);
     // fields
     for field in model.fields() {
-        resolve_field_class(field, FieldParentType::Model, context);
+        resolve_in_field_origin(model, field, context, || resolve_field_class(field, FieldParentType::Model, context));
     }
     for partial_field in model.partial_fields() {
         context.insert_diagnostics_error(partial_field.span, "partial field");
@@ -35,7 +46,125 @@ pub(super) fn resolve_model_fields<'a>(model: &'a Model, context: &'a ResolverCo
 
 pub(super) fn resolve_model_types<'a>(model: &'a Model, context: &'a ResolverContext<'a>) {
     for field in model.fields() {
-        resolve_field_types(field, None, None, context);
+        resolve_in_field_origin(model, field, context, || resolve_field_types(field, None, None, context));
+    }
+}
+
+fn resolve_model_extends<'a>(model: &'a Model, context: &'a ResolverContext<'a>) {
+    for identifier_path in model.extends() {
+        match resolve_identifier_path_names_with_filter_to_top(
+            &identifier_path.names(),
+            context.schema,
+            context.source(),
+            &context.current_namespace_path(),
+            &top_filter_for_model_extends(),
+            context.current_availability(),
+        ) {
+            Some(Node::Model(base)) => if !base.r#abstract {
+                context.insert_diagnostics_error(identifier_path.span, "only abstract models can be extended");
+            } else if base.path == model.path || extends_model(base, &model.path, context.schema, &mut vec![]) {
+                context.insert_diagnostics_error(identifier_path.span, "circular reference detected");
+            },
+            Some(Node::InterfaceDeclaration(interface)) => if interface.generics_declaration().is_some() {
+                context.insert_diagnostics_error(identifier_path.span, "generic interfaces cannot be extended");
+            },
+            _ => context.insert_diagnostics_error(identifier_path.span, "model or interface not found"),
+        }
+    }
+}
+
+// whether `model` extends the model or interface at `path` directly or through its bases
+fn extends_model(model: &Model, path: &Vec<usize>, schema: &Schema, visited: &mut Vec<Vec<usize>>) -> bool {
+    let Some(source) = schema.source(model.source_id()) else {
+        return false
+    };
+    visited.push(model.path.clone());
+    model.extends().any(|identifier_path| match resolve_identifier_path_names_with_filter_to_top(
+        &identifier_path.names(),
+        schema,
+        source,
+        &model.namespace_str_path(),
+        &top_filter_for_model_extends(),
+        model.define_availability,
+    ) {
+        Some(Node::Model(base)) => &base.path == path || (!visited.contains(&base.path) && extends_model(base, path, schema, visited)),
+        Some(top) => top.path() == path,
+        None => false,
+    })
+}
+
+// fields copied from other models and interfaces are resolved in the scope they are declared
+// in, their problems are reported by their origin. Problems which a field copied from an
+// interface only has in a model are reported at the `extends` clause
+fn resolve_in_field_origin<'a, F>(model: &'a Model, field: &'a Field, context: &'a ResolverContext<'a>, job: F) where F: Fn() {
+    if model.is_mixin_field(field) {
+        let owner_path: Vec<usize> = field.origin().iter().take(field.origin().len() - 1).cloned().collect();
+        let extends_span = match context.schema.find_top_by_path(&owner_path) {
+            Some(Node::InterfaceDeclaration(_)) => model.extends().find(|identifier_path| {
+                extends_model_through(model, identifier_path, &owner_path, context)
+            }).map(|identifier_path| identifier_path.span),
+            _ => None,
+        };
+        let source_path = context.source().file_path.clone();
+        let namespace_path = field.origin().iter().take(field.origin().len() - 2).cloned().collect();
+        context.alter_state_and_restore(field.origin_source_id(), &namespace_path, |context| {
+            let diagnostics = context.take_diagnostics(|| job());
+            if let Some(extends_span) = extends_span {
+                context.copied_field_diagnostics.lock().unwrap().push(CopiedFieldDiagnostics {
+                    field_name: field.identifier().name().to_owned(),
+                    extends_span,
+                    source_path: source_path.clone(),
+                    diagnostics,
+                });
+            }
+        });
+    } else {
+        job();
+    }
+}
+
+fn extends_model_through<'a>(model: &'a Model, identifier_path: &IdentifierPath, path: &Vec<usize>, context: &'a ResolverContext<'a>) -> bool {
+    let Some(source) = context.schema.source(model.source_id()) else {
+        return false
+    };
+    match resolve_identifier_path_names_with_filter_to_top(
+        &identifier_path.names(),
+        context.schema,
+        source,
+        &model.namespace_str_path(),
+        &top_filter_for_model_extends(),
+        model.define_availability,
+    ) {
+        Some(Node::Model(base)) => extends_model(base, path, context.schema, &mut vec![]),
+        Some(top) => top.path() == path,
+        None => false,
+    }
+}
+
+/// Report the problems of fields copied from interfaces which the interfaces don't have, once
+/// per `extends` clause.
+pub(super) fn resolve_copied_field_diagnostics<'a>(context: &'a ResolverContext<'a>) {
+    let pending = std::mem::take(&mut *context.copied_field_diagnostics.lock().unwrap());
+    let mut reported: BTreeSet<(String, usize, String)> = BTreeSet::new();
+    for copied in pending {
+        for error in copied.diagnostics.errors() {
+            if context.diagnostics().errors().iter().any(|e| e.span() == error.span() && e.message() == error.message() && e.source_path() == error.source_path()) {
+                continue
+            }
+            let message = format!("{} (field {} copied from an interface)", error.message(), copied.field_name);
+            if reported.insert((copied.source_path.clone(), copied.extends_span.start, message.clone())) {
+                context.insert_error(DiagnosticsError::new(copied.extends_span, message, copied.source_path.clone()));
+            }
+        }
+        for warning in copied.diagnostics.warnings() {
+            if context.diagnostics().warnings().iter().any(|w| w.span() == warning.span() && w.message() == warning.message() && w.source_path() == warning.source_path()) {
+                continue
+            }
+            let message = format!("{} (field {} copied from an interface)", warning.message(), copied.field_name);
+            if reported.insert((copied.source_path.clone(), copied.extends_span.start, message.clone())) {
+                context.diagnostics().insert(DiagnosticsWarning::new(copied.extends_span, message, copied.source_path.clone()));
+            }
+        }
     }
 }
 
@@ -56,7 +185,7 @@ pub(super) fn resolve_model_decorators<'a>(model: &'a Model, context: &'a Resolv
     }
     // fields
     for field in model.fields() {
-        resolve_field_decorators(model, field, context);
+        resolve_in_field_origin(model, field, context, || resolve_field_decorators(model, field, context));
     }
     // handlers
     for handler in model.handlers() {
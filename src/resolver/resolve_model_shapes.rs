@@ -30,7 +30,7 @@ use crate::traits::resolved::Resolve;
 use crate::utils::top_filter::top_filter_for_reference_type;
 
 pub(super) fn resolve_model_shapes<'a>(model: &'a Model, context: &'a ResolverContext<'a>) {
-    if !context.current_availability().contains(Availability::database()) {
+    if model.is_abstract() || !context.current_availability().contains(Availability::database()) {
         return
     }
     let mut enums = IndexMap::new();
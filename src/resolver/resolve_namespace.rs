@@ -15,6 +15,7 @@ use crate::resolver::resolve_model::{resolve_model_decorators, resolve_model_fie
 use crate::resolver::resolve_model_shapes::{resolve_model_declared_shapes, resolve_model_shapes};
 use crate::resolver::resolve_pipeline_item_declaration::resolve_pipeline_item_declaration_references;
 use crate::resolver::resolve_struct_declaration::resolve_struct_declaration_types;
+use crate::resolver::resolve_type_alias_declaration::resolve_type_alias_declaration_types;
 use crate::resolver::resolve_use_middlewares_block::resolve_use_middlewares_block;
 use crate::resolver::resolver_context::ResolverContext;
 
@@ -62,6 +63,7 @@ pub(super) fn resolve_namespace_types<'a>(namespace: &'a Namespace, context: &'a
             Node::Model(model) => (),
             Node::InterfaceDeclaration(interface) => resolve_interface_declaration_types(interface, context),
             Node::SynthesizedShapeDeclaration(synthesized_shape_declaration) => resolve_declared_synthesized_shape(synthesized_shape_declaration, context),
+            Node::TypeAliasDeclaration(type_alias_declaration) => resolve_type_alias_declaration_types(type_alias_declaration, context),
             Node::Namespace(namespace) => resolve_namespace_types(namespace, context),
             Node::ConfigDeclaration(config_declaration) => resolve_config_declaration_types(config_declaration, context),
             Node::StructDeclaration(s) => resolve_struct_declaration_types(s, context),
@@ -7,7 +7,7 @@ use crate::ast::unit::Unit;
 use crate::r#type::keyword::Keyword;
 use crate::r#type::r#type::Type;
 use crate::resolver::resolve_argument_list::resolve_argument_list;
-use crate::resolver::resolve_identifier::resolve_identifier_path_names_with_filter_to_expr_info;
+use crate::resolver::resolve_identifier::{resolve_identifier_path_names_with_filter_to_expr_info, warn_if_deprecated};
 use crate::resolver::resolver_context::ResolverContext;
 use crate::traits::named_identifiable::NamedIdentifiable;
 use crate::traits::resolved::Resolve;
@@ -67,6 +67,7 @@ pub(super) fn resolve_pipeline_unit<'a>(span: Span, unit: &'a Unit, context: &'a
                     ReferenceType::Namespace => current_space = expr_info.reference_info().unwrap().reference.string_path().clone(),
                     ReferenceType::PipelineItemDeclaration => {
                         let pipeline_item_declaration = context.schema.find_top_by_path(expr_info.reference_info().unwrap().reference.path()).unwrap().as_pipeline_item_declaration().unwrap();
+                        warn_if_deprecated(identifier.span, pipeline_item_declaration.identifier().name(), pipeline_item_declaration.comment(), context);
                         let pipeline_type_context = TypeInfo {
                             passed_in: current_input_type.clone()
                         };
@@ -8,6 +8,7 @@ use crate::resolver::resolve_declared_synthesized_shape::resolve_declared_synthe
 use crate::resolver::resolve_decorator_declaration::resolve_decorator_declaration_references;
 use crate::resolver::resolve_enum::resolve_enum_types;
 use crate::resolver::resolve_handler_template_declaration::{resolve_handler_template_declaration_decorators, resolve_handler_template_declaration_types};
+use crate::resolver::resolve_import::resolve_import;
 use crate::resolver::resolve_interface::{resolve_interface_declaration_decorators, resolve_interface_declaration_shapes, resolve_interface_declaration_types};
 use crate::resolver::resolve_middleware::resolve_middleware_references;
 use crate::resolver::resolve_model::{resolve_model_decorators, resolve_model_fields, resolve_model_references};
@@ -15,6 +16,7 @@ use crate::resolver::resolve_model_shapes::{resolve_model_declared_shapes, resol
 use crate::resolver::resolve_namespace::{resolve_namespace_constant_used_check, resolve_namespace_consumers, resolve_namespace_interface_shapes, resolve_namespace_model_declared_shapes, resolve_namespace_model_fields, resolve_namespace_model_shapes, resolve_namespace_references, resolve_namespace_types};
 use crate::resolver::resolve_pipeline_item_declaration::resolve_pipeline_item_declaration_references;
 use crate::resolver::resolve_struct_declaration::resolve_struct_declaration_types;
+use crate::resolver::resolve_type_alias_declaration::resolve_type_alias_declaration_types;
 use crate::resolver::resolve_use_middlewares_block::resolve_use_middlewares_block;
 use crate::resolver::resolver_context::ResolverContext;
 
@@ -55,6 +57,7 @@ pub(super) fn resolve_source_types<'a>(context: &'a ResolverContext<'a>) {
             Node::Model(model) => (),
             Node::InterfaceDeclaration(interface) => resolve_interface_declaration_types(interface, context),
             Node::SynthesizedShapeDeclaration(synthesized_shape_declaration) => resolve_declared_synthesized_shape(synthesized_shape_declaration, context),
+            Node::TypeAliasDeclaration(type_alias_declaration) => resolve_type_alias_declaration_types(type_alias_declaration, context),
             Node::Namespace(namespace) => resolve_namespace_types(namespace, context),
             Node::ConfigDeclaration(config_declaration) => resolve_config_declaration_types(config_declaration, context),
             Node::StructDeclaration(s) => resolve_struct_declaration_types(s, context),
@@ -76,6 +79,7 @@ pub(super) fn resolve_source_interface_shapes<'a>(context: &'a ResolverContext<'
 pub(super) fn resolve_source_references<'a>(context: &'a ResolverContext<'a>) {
     for node in context.source().children.values() {
         match node {
+            Node::Import(import) => resolve_import(import, context),
             Node::ConstantDeclaration(constant) => resolve_constant_references(constant, context),
             Node::Config(config) => resolve_config_references(config, context),
             Node::MiddlewareDeclaration(middleware) => resolve_middleware_references(middleware, context),
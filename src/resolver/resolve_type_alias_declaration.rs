@@ -0,0 +1,38 @@
+use maplit::btreemap;
+use crate::ast::type_alias_declaration::TypeAliasDeclaration;
+use crate::resolver::resolve_generics::{resolve_generics_constraint, resolve_generics_declaration};
+use crate::resolver::resolve_type_expr::resolve_type_expr;
+use crate::resolver::resolver_context::ResolverContext;
+use crate::traits::resolved::Resolve;
+
+pub(super) fn resolve_type_alias_declaration_types<'a>(type_alias_declaration: &'a TypeAliasDeclaration, context: &'a ResolverContext<'a>) {
+    if context.has_examined_default_path(&type_alias_declaration.string_path, type_alias_declaration.define_availability) {
+        context.insert_duplicated_identifier(type_alias_declaration.identifier().span);
+    }
+    *type_alias_declaration.actual_availability.borrow_mut() = context.current_availability();
+    // an alias may have been resolved already when a previous declaration used it
+    if !type_alias_declaration.is_resolved() {
+        resolve_type_alias_declaration(type_alias_declaration, context);
+    }
+    context.add_examined_default_path(type_alias_declaration.string_path.clone(), type_alias_declaration.define_availability);
+}
+
+pub(super) fn resolve_type_alias_declaration<'a>(type_alias_declaration: &'a TypeAliasDeclaration, context: &'a ResolverContext<'a>) {
+    if let Some(generics_declaration) = type_alias_declaration.generics_declaration() {
+        resolve_generics_declaration(generics_declaration, &vec![], context);
+        if let Some(generics_constraint) = type_alias_declaration.generics_constraint() {
+            resolve_generics_constraint(generics_constraint, context, generics_declaration, type_alias_declaration.define_availability);
+        }
+    }
+    context.push_dependency(type_alias_declaration.path.clone());
+    let r#type = resolve_type_expr(
+        type_alias_declaration.type_expr(),
+        &type_alias_declaration.generics_declaration().into_iter().collect(),
+        &type_alias_declaration.generics_constraint().into_iter().collect(),
+        &btreemap! {},
+        context,
+        type_alias_declaration.define_availability,
+    );
+    context.pop_dependency();
+    type_alias_declaration.resolve(r#type);
+}
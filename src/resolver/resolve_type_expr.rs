@@ -8,6 +8,7 @@ use crate::ast::generics::{GenericsConstraint, GenericsDeclaration};
 use crate::ast::type_expr::{TypeExpr, TypeExprKind, TypeItem, TypeOperator};
 use crate::ast::reference_space::ReferenceSpace;
 use crate::ast::span::Span;
+use crate::diagnostics::diagnostics::DiagnosticsError;
 use crate::expr::ReferenceType;
 use crate::r#type::keyword::Keyword;
 use crate::r#type::r#type::Type;
@@ -17,8 +18,13 @@ use crate::r#type::synthesized_interface_enum_reference::{SynthesizedInterfaceEn
 use crate::r#type::synthesized_shape::SynthesizedShape;
 use crate::r#type::synthesized_shape_reference::SynthesizedShapeReferenceKind;
 use crate::r#type::synthesized_shape_reference::SynthesizedShapeReference;
+use crate::r#type::shape_operation::{ShapeOperation, ShapeOperationKind};
+use crate::resolver::resolve_expression::resolve_string_literal_value;
 use crate::resolver::resolve_identifier::resolve_identifier_path;
-use crate::resolver::resolver_context::ResolverContext;
+use crate::resolver::resolve_type_alias_declaration::resolve_type_alias_declaration;
+use crate::resolver::resolver_context::{ResolverContext, ShapeOperationKeys};
+use crate::traits::identifiable::Identifiable;
+use crate::traits::info_provider::InfoProvider;
 use crate::traits::node_trait::NodeTrait;
 use crate::traits::resolved::Resolve;
 
@@ -166,6 +172,19 @@ fn resolve_type_expr_kind<'a>(
         TypeExprKind::FieldName(r) => {
             Type::FieldName(r.identifier().name().to_string())
         }
+        TypeExprKind::StringLiteral(s) => if let Some(value) = resolve_string_literal_value(s, context) {
+            Type::StringLiteral(value)
+        } else {
+            context.insert_diagnostics_error(s.span, "literal type should be constant");
+            Type::Undetermined
+        },
+        TypeExprKind::NumericLiteral(n) => if n.value.is_any_int() {
+            Type::IntLiteral(n.value.to_int64().unwrap())
+        } else {
+            context.insert_diagnostics_error(n.span, "float literal types are not supported");
+            Type::Undetermined
+        },
+        TypeExprKind::BoolLiteral(b) => Type::BoolLiteral(b.value),
         TypeExprKind::TypedShape(typed_shape) => {
             let mut map = indexmap! {};
             let mut used_keys: BTreeSet<&str> = btreeset!{};
@@ -321,6 +340,7 @@ fn resolve_type_item<'a>(
                     } else {
                         vec![]
                     })),
+                    ReferenceType::TypeAlias => Some(resolve_type_alias_reference(type_item, reference_info.reference().path(), generics_declaration, generics_constraint, keywords_map, context, availability)),
                     ReferenceType::DeclaredSynthesizedShape => if let Some(generics) = type_item.generics() {
                         let gens: Vec<Type> = generics.type_exprs().map(|t| resolve_type_expr(t, generics_declaration, generics_constraint, keywords_map, context, availability)).collect();
                         if gens.len() == 1 {
@@ -376,7 +396,7 @@ fn type_item_builtin_match<'a>(
         },
         "Union" => {
             check_generics_amount_multiple(type_item, context);
-            Some(Type::Union(type_item.generic_items().iter().map(|t| resolve_type_expr(t, generics_declaration, generics_constraint, keywords_map, context, availability)).collect()))
+            Some(Type::Union(type_item.generic_items().into_iter().map(|t| resolve_type_expr(t, generics_declaration, generics_constraint, keywords_map, context, availability)).collect()))
         },
         "Enumerable" => {
             check_generics_amount(1, type_item, context);
@@ -434,6 +454,38 @@ fn type_item_builtin_match<'a>(
                 Some(Type::Undetermined)
             }
         },
+        "Partial" | "Required" | "Pick" | "Omit" => {
+            let kind = ShapeOperationKind::from_str(name).unwrap();
+            check_generics_amount(if kind.requires_keys() { 2 } else { 1 }, type_item, context);
+            let Some(owner_expr) = type_item.generic_items().get(0).cloned() else {
+                return Some(Type::Undetermined);
+            };
+            let owner = resolve_type_expr(owner_expr, generics_declaration, generics_constraint, keywords_map, context, availability);
+            if !(owner.is_interface_object() || owner.is_synthesized_shape() || owner.is_synthesized_shape_reference() || owner.is_declared_shape_reference() || owner.is_shape_operation() || owner.is_generic_item()) {
+                context.insert_diagnostics_error(owner_expr.span(), format!("{} takes an interface or shape", name));
+                return Some(Type::Undetermined);
+            }
+            let mut keys = vec![];
+            if kind.requires_keys() {
+                if let Some(keys_expr) = type_item.generic_items().get(1).cloned() {
+                    let keys_type = resolve_type_expr(keys_expr, generics_declaration, generics_constraint, keywords_map, context, availability);
+                    if !collect_field_names(&keys_type, &mut keys) {
+                        context.insert_diagnostics_error(keys_expr.span(), "type is not field reference");
+                        return Some(Type::Undetermined);
+                    }
+                    if !owner.contains_generics() {
+                        let mut key_spans = vec![];
+                        collect_field_name_spans(keys_expr, &mut key_spans);
+                        context.shape_operation_keys.lock().unwrap().push(ShapeOperationKeys {
+                            owner: owner.clone(),
+                            keys: key_spans,
+                            source_path: context.source().file_path.clone(),
+                        });
+                    }
+                }
+            }
+            Some(Type::ShapeOperation(ShapeOperation::new(kind, owner, keys)))
+        },
         "Self" => {
             check_generics_amount(0, type_item, context);
             Some(Type::Keyword(Keyword::SelfIdentifier))
@@ -539,7 +591,7 @@ fn type_item_builtin_match<'a>(
         },
         "Tuple" => {
             check_generics_amount_more_than_one(type_item, context);
-            Some(Type::Tuple(type_item.generic_items().iter().map(|t| resolve_type_expr(t, generics_declaration, generics_constraint, keywords_map, context, availability)).collect()))
+            Some(Type::Tuple(type_item.generic_items().into_iter().map(|t| resolve_type_expr(t, generics_declaration, generics_constraint, keywords_map, context, availability)).collect()))
         },
         "Range" => {
             check_generics_amount(1, type_item, context);
@@ -588,6 +640,94 @@ fn type_item_builtin_match<'a>(
     }
 }
 
+// `.a | .b` is resolved into a synthesized enum
+fn collect_field_names(t: &Type, names: &mut Vec<String>) -> bool {
+    if let Some(synthesized_enum) = t.as_synthesized_enum() {
+        names.extend(synthesized_enum.keys.iter().cloned());
+        true
+    } else if let Some(name) = t.as_field_name() {
+        names.push(name.to_owned());
+        true
+    } else {
+        false
+    }
+}
+
+fn collect_field_name_spans(type_expr: &TypeExpr, names: &mut Vec<(String, Span)>) {
+    collect_field_name_spans_in_kind(&type_expr.kind, names);
+}
+
+fn collect_field_name_spans_in_kind(kind: &TypeExprKind, names: &mut Vec<(String, Span)>) {
+    match kind {
+        TypeExprKind::Expr(inner) => collect_field_name_spans_in_kind(inner, names),
+        TypeExprKind::BinaryOp(binary_op) => {
+            collect_field_name_spans(binary_op.lhs(), names);
+            collect_field_name_spans(binary_op.rhs(), names);
+        },
+        TypeExprKind::TypeGroup(group) => collect_field_name_spans(group.type_expr(), names),
+        TypeExprKind::TypedEnum(typed_enum) => for member in typed_enum.members() {
+            names.push((member.identifier().name().to_owned(), member.span()));
+        },
+        TypeExprKind::FieldName(field_name) => names.push((field_name.identifier().name().to_owned(), field_name.span())),
+        _ => (),
+    }
+}
+
+// the shapes of interfaces are only available after the interface shapes are resolved
+pub(super) fn resolve_shape_operation_keys<'a>(context: &'a ResolverContext<'a>) {
+    let pending = std::mem::take(&mut *context.shape_operation_keys.lock().unwrap());
+    for ShapeOperationKeys { owner, keys, source_path } in pending {
+        let Some(shape) = owner.evaluate_synthesized_shape(context.schema) else {
+            continue
+        };
+        for (key, span) in keys {
+            if shape.get(&key).is_none() {
+                context.insert_error(DiagnosticsError::new(span, format!("field `{}` not found in {}", key, owner), source_path.clone()));
+            }
+        }
+    }
+}
+
+fn resolve_type_alias_reference<'a>(
+    type_item: &'a TypeItem,
+    path: &Vec<usize>,
+    generics_declaration: &Vec<&'a GenericsDeclaration>,
+    generics_constraint: &Vec<&'a GenericsConstraint>,
+    keywords_map: &BTreeMap<Keyword, Type>,
+    context: &'a ResolverContext<'a>,
+    availability: Availability,
+) -> Type {
+    let Some(type_alias_declaration) = context.schema.find_top_by_path(path).and_then(|top| top.as_type_alias_declaration()) else {
+        return Type::Undetermined;
+    };
+    if !type_alias_declaration.is_resolved() {
+        if context.has_dependency(type_alias_declaration.path()) {
+            context.insert_diagnostics_error(type_item.identifier_path().span, "circular reference detected");
+            return Type::Undetermined;
+        }
+        context.alter_state_and_restore(type_alias_declaration.source_id(), &type_alias_declaration.namespace_path(), |_| {
+            resolve_type_alias_declaration(type_alias_declaration, context);
+        });
+    }
+    let generics: Vec<Type> = type_item.generic_items().into_iter().map(|t| resolve_type_expr(t, generics_declaration, generics_constraint, keywords_map, context, availability)).collect();
+    let names = type_alias_declaration.generics_declaration().map_or(vec![], |g| g.names());
+    check_generics_amount(names.len(), type_item, context);
+    if let Some(constraint) = type_alias_declaration.generics_constraint() {
+        for item in constraint.items() {
+            let Some(argument) = names.iter().position(|name| *name == item.identifier().name()).and_then(|index| generics.get(index)) else {
+                continue
+            };
+            if argument.contains_generics() {
+                continue
+            }
+            if !item.type_expr().resolved().constraint_test(argument, context.schema).0 {
+                context.insert_diagnostics_error(type_item.identifier_path().span, format!("type {} doesn't satisfy {}", argument, item.type_expr().resolved()));
+            }
+        }
+    }
+    type_alias_declaration.type_from_generics(&generics)
+}
+
 fn check_generics_amount<'a>(expect: usize, type_item: &TypeItem, context: &'a ResolverContext<'a>) {
     if type_item.generic_items().len() == expect { return }
     context.insert_diagnostics_error(type_item.identifier_path().span, format!("wrong number of generic arguments, expect {}, found {}", expect, type_item.generic_items().len()));
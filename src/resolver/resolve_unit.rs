@@ -17,7 +17,7 @@ use crate::traits::resolved::{Resolve, ResolveAndClone};
 use crate::utils::top_filter::top_filter_for_reference_type;
 use crate::expr::{ExprInfo, ReferenceInfo, ReferenceType};
 use crate::r#type::synthesized_shape::SynthesizedShape;
-use crate::resolver::resolve_identifier::{resolve_identifier_path_names_with_filter_to_expr_info, resolve_identifier_path_names_with_filter_to_top};
+use crate::resolver::resolve_identifier::{resolve_identifier_path_names_with_filter_to_expr_info, resolve_identifier_path_names_with_filter_to_top, warn_if_deprecated};
 use crate::value::interface_enum_variant::InterfaceEnumVariant;
 use crate::value::Value;
 
@@ -35,18 +35,35 @@ pub(super) fn resolve_unit<'a>(
         }
     }
     if unit.expressions.len() == 1 {
+        let resolved = resolve_expression(unit.expression_at(0).unwrap(), context, expected, keywords_map);
+        report_if_type_alias(unit.expression_at(0).unwrap().span(), &resolved, context);
         return unit_type_coerce(
             unit.expression_at(0).unwrap().span(),
-            &resolve_expression(unit.expression_at(0).unwrap(), context, expected, keywords_map),
+            &resolved,
             expected,
             context
         );
     }
     let mut current: Option<ExprInfo> = None;
+    // `?.` makes the rest of the unit optional, a null value short circuits it
+    let mut optional_chained = false;
+    let mut short_circuited = false;
     for (index, expression) in unit.expressions().enumerate() {
         if current.is_some() && current.as_ref().unwrap().is_undetermined_anyway() {
             expression.resolve(ExprInfo::undetermined());
         } else {
+            if unit.is_optional_chained_at(index) {
+                if let Some(previous) = current.as_mut() {
+                    if previous.r#type().is_optional() {
+                        optional_chained = true;
+                        previous.r#type = previous.r#type().unwrap_optional().clone();
+                        if previous.value().map_or(false, |v| v.is_null()) {
+                            short_circuited = true;
+                            previous.value = None;
+                        }
+                    }
+                }
+            }
             current = Some(resolve_current_item_for_unit(
                 if index == 0 { None } else { Some(unit.expression_at(index - 1).unwrap().span()) },
                 current.as_ref(),
@@ -56,13 +73,26 @@ pub(super) fn resolve_unit<'a>(
             ));
         }
     }
-    if let Some(current) = current {
+    if let Some(mut current) = current {
+        report_if_type_alias(unit.last_expression().unwrap().span(), &current, context);
+        if optional_chained && !current.r#type().is_undetermined() {
+            current.r#type = current.r#type().wrap_in_optional();
+            if short_circuited {
+                current.value = Some(Value::Null);
+            }
+        }
         unit_type_coerce(unit.last_expression().unwrap().span(), &current, expected, context)
     } else {
         ExprInfo::undetermined()
     }
 }
 
+fn report_if_type_alias<'a>(span: Span, expr_info: &ExprInfo, context: &'a ResolverContext<'a>) {
+    if expr_info.reference_info().map_or(false, |reference_info| reference_info.r#type() == ReferenceType::TypeAlias) {
+        context.insert_diagnostics_error(span, "type alias cannot be used as a value");
+    }
+}
+
 fn resolve_current_item_for_unit<'a>(
     last_span: Option<Span>,
     current: Option<&ExprInfo>,
@@ -94,6 +124,10 @@ fn resolve_current_item_for_unit<'a>(
                 ReferenceType::DataSetRecord => todo!(),
                 ReferenceType::Namespace => resolve_namespace_reference_for_unit(reference_info.reference().string_path(), expression, context),
                 ReferenceType::DeclaredSynthesizedShape => todo!(),
+                ReferenceType::TypeAlias => {
+                    context.insert_diagnostics_error(last_span.unwrap(), "type alias cannot be used as a value");
+                    ExprInfo::undetermined()
+                },
             }
         } else {
             resolve_current_item_type_for_unit(last_span, current, expression, context, keywords_map)
@@ -234,6 +268,12 @@ fn resolve_struct_instance_for_unit<'a>(
                 }
             }
             let return_type = subscript_function.return_type().resolved().replace_generics(&map);
+            if struct_definition.string_path == vec!["std", "EnvVars"] {
+                context.add_environment_reader();
+                if let Some(environment) = context.schema.environment() {
+                    return expression.resolve_and_return(resolve_environment_variable(environment, subscript.expression(), return_type, context));
+                }
+            }
             ExprInfo::type_only(return_type)
         },
         _ => {
@@ -243,6 +283,24 @@ fn resolve_struct_instance_for_unit<'a>(
     })
 }
 
+fn resolve_environment_variable<'a>(
+    environment: &BTreeMap<String, String>,
+    key: &'a Expression,
+    return_type: Type,
+    context: &'a ResolverContext<'a>,
+) -> ExprInfo {
+    let Some(Value::String(name)) = key.resolved().value() else {
+        return ExprInfo::type_only(return_type);
+    };
+    match environment.get(name) {
+        Some(value) => ExprInfo::new(return_type, Some(Value::String(value.clone())), None),
+        None => {
+            context.insert_diagnostics_warning(key.span(), format!("undefined environment variable '{name}'"));
+            ExprInfo::type_only(return_type)
+        }
+    }
+}
+
 fn resolve_tuple_for_unit<'a>(
     types: &Vec<Type>,
     current: &ExprInfo,
@@ -276,14 +334,7 @@ fn resolve_enum_reference_for_unit<'a>(
     expression: &'a Expression,
     context: &'a ResolverContext<'a>,
 ) -> ExprInfo {
-    let enum_declaration = resolve_identifier_path_names_with_filter_to_top(
-        &reference.str_path(),
-        context.schema,
-        context.source(),
-        &context.current_namespace_path(),
-        &top_filter_for_reference_type(ReferenceSpace::Default),
-        context.current_availability()
-    ).unwrap().as_enum().unwrap();
+    let enum_declaration = context.schema.find_top_by_path(reference.path()).unwrap().as_enum().unwrap();
     expression.resolve_and_return(match &expression.kind {
         ExpressionKind::Identifier(identifier) => {
             if let Some(m) = enum_declaration.members().find(|m| m.identifier().name() == identifier.name()) {
@@ -374,14 +425,7 @@ fn resolve_config_reference_for_unit<'a>(
     expression: &'a Expression,
     context: &'a ResolverContext<'a>,
 ) -> ExprInfo {
-    let config = resolve_identifier_path_names_with_filter_to_top(
-        &reference.str_path(),
-        context.schema,
-        context.source(),
-        &context.current_namespace_path(),
-        &top_filter_for_reference_type(ReferenceSpace::Default),
-        context.current_availability()
-    ).unwrap().as_config().unwrap();
+    let config = context.schema.find_top_by_path(reference.path()).unwrap().as_config().unwrap();
     expression.resolve_and_return(match &expression.kind {
         ExpressionKind::Identifier(identifier) => {
             if let Some(item) = config.items().iter().find(|item| item.0.named_key_without_resolving() == Some(identifier.name())) {
@@ -403,17 +447,11 @@ fn resolve_model_reference_for_unit<'a>(
     expression: &'a Expression,
     context: &'a ResolverContext<'a>,
 ) -> ExprInfo {
-    let model = resolve_identifier_path_names_with_filter_to_top(
-        &reference.str_path(),
-        context.schema,
-        context.source(),
-        &context.current_namespace_path(),
-        &top_filter_for_reference_type(ReferenceSpace::Default),
-        context.current_availability()
-    ).unwrap().as_model().unwrap();
+    let model = context.schema.find_top_by_path(reference.path()).unwrap().as_model().unwrap();
     expression.resolve_and_return(match &expression.kind {
         ExpressionKind::Identifier(identifier) => {
             if let Some(item) = model.fields().find(|item| item.identifier().name() == identifier.name()) {
+                warn_if_deprecated(identifier.span, item.identifier().name(), item.comment(), context);
                 ExprInfo {
                     r#type: Type::Undetermined,
                     value: None,
@@ -441,14 +479,7 @@ fn resolve_interface_reference_for_unit<'a>(
     expression: &'a Expression,
     context: &'a ResolverContext<'a>,
 ) -> ExprInfo {
-    let interface = resolve_identifier_path_names_with_filter_to_top(
-        &reference.str_path(),
-        context.schema,
-        context.source(),
-        &context.current_namespace_path(),
-        &top_filter_for_reference_type(ReferenceSpace::Default),
-        context.current_availability()
-    ).unwrap().as_interface_declaration().unwrap();
+    let interface = context.schema.find_top_by_path(reference.path()).unwrap().as_interface_declaration().unwrap();
     expression.resolve_and_return(match &expression.kind {
         ExpressionKind::Identifier(identifier) => {
             if let Some(item) = interface.fields().find(|item| item.identifier().name() == identifier.name()) {
@@ -480,14 +511,7 @@ fn resolve_interface_object_for_unit<'a>(
     expression: &'a Expression,
     context: &'a ResolverContext<'a>,
 ) -> ExprInfo {
-    let interface = resolve_identifier_path_names_with_filter_to_top(
-        &reference.str_path(),
-        context.schema,
-        context.source(),
-        &context.current_namespace_path(),
-        &top_filter_for_reference_type(ReferenceSpace::Default),
-        context.current_availability()
-    ).unwrap().as_interface_declaration().unwrap();
+    let interface = context.schema.find_top_by_path(reference.path()).unwrap().as_interface_declaration().unwrap();
     expression.resolve_and_return(match &expression.kind {
         ExpressionKind::Identifier(identifier) => {
             if let Some((_, t)) = interface.resolved().shape().iter().find(|(k, t)| k.as_str() == identifier.name()) {
@@ -516,14 +540,7 @@ fn resolve_struct_reference_for_unit<'a>(
     expression: &'a Expression,
     context: &'a ResolverContext<'a>,
 ) -> ExprInfo {
-    let struct_declaration = resolve_identifier_path_names_with_filter_to_top(
-        &reference.str_path(),
-        context.schema,
-        context.source(),
-        &context.current_namespace_path(),
-        &top_filter_for_reference_type(ReferenceSpace::Default),
-        context.current_availability()
-    ).unwrap().as_struct_declaration().unwrap();
+    let struct_declaration = context.schema.find_top_by_path(reference.path()).unwrap().as_struct_declaration().unwrap();
     expression.resolve_and_return(match &expression.kind {
         ExpressionKind::Identifier(identifier) => {
             if let Some(function) = struct_declaration.static_function(identifier.name()) {
@@ -1,39 +1,111 @@
 use std::cell::RefCell;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Mutex;
 use maplit::btreeset;
 use crate::availability::Availability;
 use crate::ast::data_set::DataSetRecord;
 use crate::ast::field::Field;
+use crate::ast::import::Import;
 use crate::ast::namespace::Namespace;
 use crate::ast::r#enum::EnumMember;
 use crate::ast::schema::Schema;
 use crate::ast::source::Source;
 use crate::ast::span::Span;
-use crate::diagnostics::diagnostics::{Diagnostics, DiagnosticsError, DiagnosticsWarning};
+use crate::diagnostics::diagnostics::{Diagnostics, DiagnosticsError, DiagnosticsLog, DiagnosticsWarning};
+use crate::r#type::Type;
 use crate::search::search_availability::{find_namespace_availability, find_source_availability};
 use crate::traits::named_identifiable::NamedIdentifiable;
 
-#[derive(PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[derive(Debug, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub(crate) struct ExaminedDataSetRecord {
     pub(crate) data_set: Vec<String>,
     pub(crate) group: Vec<String>,
     pub(crate) record: String,
 }
 
+pub(crate) struct CopiedFieldDiagnostics {
+    pub(crate) field_name: String,
+    pub(crate) extends_span: Span,
+    pub(crate) source_path: String,
+    pub(crate) diagnostics: Diagnostics,
+}
+
+pub(crate) struct ShapeOperationKeys {
+    pub(crate) owner: Type,
+    pub(crate) keys: Vec<(String, Span)>,
+    pub(crate) source_path: String,
+}
+
+/// What resolving leaves behind, so that only the changed sources are resolved again after a
+/// reparse. Examined paths and records map to the id of the source they are declared in.
+#[derive(Debug, Default)]
+pub(crate) struct ResolverState {
+    pub(crate) resolved_sources: BTreeSet<usize>,
+    pub(crate) examined_default_paths_mysql: BTreeMap<Vec<String>, usize>,
+    pub(crate) examined_default_paths_postgres: BTreeMap<Vec<String>, usize>,
+    pub(crate) examined_default_paths_sqlite: BTreeMap<Vec<String>, usize>,
+    pub(crate) examined_default_paths_mongo: BTreeMap<Vec<String>, usize>,
+    pub(crate) examined_middleware_paths: BTreeMap<Vec<String>, usize>,
+    pub(crate) examined_data_set_records: BTreeMap<ExaminedDataSetRecord, usize>,
+    // paths of the constants each source refers to
+    pub(crate) constant_uses: BTreeMap<usize, Vec<Vec<usize>>>,
+    // sources which read `ENV`
+    pub(crate) environment_readers: BTreeSet<usize>,
+    pub(crate) diagnostics: Diagnostics,
+}
+
+impl ResolverState {
+
+    /// Forget everything that comes from other sources than the kept ones.
+    pub(crate) fn retain_sources(&mut self, kept: &BTreeSet<usize>, kept_paths: &BTreeSet<&str>) {
+        self.resolved_sources.retain(|id| kept.contains(id));
+        self.examined_default_paths_mysql.retain(|_, id| kept.contains(id));
+        self.examined_default_paths_postgres.retain(|_, id| kept.contains(id));
+        self.examined_default_paths_sqlite.retain(|_, id| kept.contains(id));
+        self.examined_default_paths_mongo.retain(|_, id| kept.contains(id));
+        self.examined_middleware_paths.retain(|_, id| kept.contains(id));
+        self.examined_data_set_records.retain(|_, id| kept.contains(id));
+        self.constant_uses.retain(|id, _| kept.contains(id));
+        self.environment_readers.retain(|id| kept.contains(id));
+        let mut diagnostics = Diagnostics::new();
+        for error in self.diagnostics.errors() {
+            if kept_paths.contains(error.source_path()) {
+                diagnostics.insert(error.clone());
+            }
+        }
+        for warning in self.diagnostics.warnings() {
+            if kept_paths.contains(warning.source_path()) {
+                diagnostics.insert(warning.clone());
+            }
+        }
+        self.diagnostics = diagnostics;
+    }
+}
+
 pub(crate) struct ResolverContext<'a> {
-    pub(crate) examined_default_paths_mysql: Mutex<BTreeSet<Vec<String>>>,
-    pub(crate) examined_default_paths_postgres: Mutex<BTreeSet<Vec<String>>>,
-    pub(crate) examined_default_paths_sqlite: Mutex<BTreeSet<Vec<String>>>,
-    pub(crate) examined_default_paths_mongo: Mutex<BTreeSet<Vec<String>>>,
+    pub(crate) resolved_sources: Mutex<BTreeSet<usize>>,
+    pub(crate) examined_default_paths_mysql: Mutex<BTreeMap<Vec<String>, usize>>,
+    pub(crate) examined_default_paths_postgres: Mutex<BTreeMap<Vec<String>, usize>>,
+    pub(crate) examined_default_paths_sqlite: Mutex<BTreeMap<Vec<String>, usize>>,
+    pub(crate) examined_default_paths_mongo: Mutex<BTreeMap<Vec<String>, usize>>,
     pub(crate) examined_fields: Mutex<BTreeSet<String>>,
-    pub(crate) examined_middleware_paths: Mutex<BTreeSet<Vec<String>>>,
-    pub(crate) examined_data_set_records: Mutex<BTreeSet<ExaminedDataSetRecord>>,
+    pub(crate) examined_middleware_paths: Mutex<BTreeMap<Vec<String>, usize>>,
+    pub(crate) examined_data_set_records: Mutex<BTreeMap<ExaminedDataSetRecord, usize>>,
+    pub(crate) constant_uses: Mutex<BTreeMap<usize, Vec<Vec<usize>>>>,
+    pub(crate) environment_readers: Mutex<BTreeSet<usize>>,
     pub(crate) examined_namespaces_in_a_file: Mutex<BTreeSet<Vec<String>>>,
     pub(crate) examined_datasets_in_a_file: Mutex<BTreeSet<Vec<String>>>,
+    // keys of `Pick` and `Omit` are checked when the shapes of their owners are available
+    pub(crate) shape_operation_keys: Mutex<Vec<ShapeOperationKeys>>,
+    // problems of fields copied from interfaces are reported when the ones of the interfaces are known
+    pub(crate) copied_field_diagnostics: Mutex<Vec<CopiedFieldDiagnostics>>,
     pub(crate) diagnostics: RefCell<&'a mut Diagnostics>,
     pub(crate) schema: &'a Schema,
     pub(crate) source: Mutex<Option<&'a Source>>,
+    // declarations only reachable through an alias don't clash with the global ones. These are
+    // the names of files imported with an alias only, except the names imported selectively
+    aliased_only_import_file_paths: BTreeSet<String>,
+    selectively_imported_names: BTreeMap<String, BTreeSet<String>>,
     pub(crate) namespaces: Mutex<Vec<&'a Namespace>>,
     pub(crate) availabilities: Mutex<Vec<Availability>>,
     // this is used for circular reference detection
@@ -42,27 +114,52 @@ pub(crate) struct ResolverContext<'a> {
 
 impl<'a> ResolverContext<'a> {
 
-    pub(crate) fn new(diagnostics: &'a mut Diagnostics, schema: &'a Schema) -> Self {
+    pub(crate) fn new(diagnostics: &'a mut Diagnostics, schema: &'a Schema, state: ResolverState) -> Self {
         Self {
-            examined_default_paths_mysql: Mutex::new(btreeset!{}),
-            examined_default_paths_postgres: Mutex::new(btreeset!{}),
-            examined_default_paths_sqlite: Mutex::new(btreeset!{}),
-            examined_default_paths_mongo: Mutex::new(btreeset!{}),
+            resolved_sources: Mutex::new(state.resolved_sources),
+            examined_default_paths_mysql: Mutex::new(state.examined_default_paths_mysql),
+            examined_default_paths_postgres: Mutex::new(state.examined_default_paths_postgres),
+            examined_default_paths_sqlite: Mutex::new(state.examined_default_paths_sqlite),
+            examined_default_paths_mongo: Mutex::new(state.examined_default_paths_mongo),
             examined_fields: Mutex::new(btreeset!{}),
-            examined_middleware_paths: Mutex::new(btreeset!{}),
-            examined_data_set_records: Mutex::new(btreeset!{}),
+            examined_middleware_paths: Mutex::new(state.examined_middleware_paths),
+            examined_data_set_records: Mutex::new(state.examined_data_set_records),
+            constant_uses: Mutex::new(state.constant_uses),
+            environment_readers: Mutex::new(state.environment_readers),
             examined_namespaces_in_a_file: Mutex::new(btreeset! {}),
             examined_datasets_in_a_file: Mutex::new(btreeset! {}),
+            shape_operation_keys: Mutex::new(vec![]),
+            copied_field_diagnostics: Mutex::new(vec![]),
             diagnostics: RefCell::new(diagnostics),
             schema,
             source: Mutex::new(None),
+            aliased_only_import_file_paths: aliased_only_import_file_paths(schema),
+            selectively_imported_names: selectively_imported_names(schema),
             namespaces: Mutex::new(vec![]),
             availabilities: Mutex::new(vec![]),
             resolving_dependencies: Mutex::new(vec![]),
         }
     }
 
+    /// The examined paths and records for resolving changed sources again later. Diagnostics
+    /// are left to the caller.
+    pub(crate) fn take_state(&self) -> ResolverState {
+        ResolverState {
+            resolved_sources: std::mem::take(&mut *self.resolved_sources.lock().unwrap()),
+            examined_default_paths_mysql: std::mem::take(&mut *self.examined_default_paths_mysql.lock().unwrap()),
+            examined_default_paths_postgres: std::mem::take(&mut *self.examined_default_paths_postgres.lock().unwrap()),
+            examined_default_paths_sqlite: std::mem::take(&mut *self.examined_default_paths_sqlite.lock().unwrap()),
+            examined_default_paths_mongo: std::mem::take(&mut *self.examined_default_paths_mongo.lock().unwrap()),
+            examined_middleware_paths: std::mem::take(&mut *self.examined_middleware_paths.lock().unwrap()),
+            examined_data_set_records: std::mem::take(&mut *self.examined_data_set_records.lock().unwrap()),
+            constant_uses: std::mem::take(&mut *self.constant_uses.lock().unwrap()),
+            environment_readers: std::mem::take(&mut *self.environment_readers.lock().unwrap()),
+            diagnostics: Diagnostics::new(),
+        }
+    }
+
     pub(crate) fn start_source(&self, source: &'a Source) {
+        self.resolved_sources.lock().unwrap().insert(source.id);
         *self.source.lock().unwrap() = Some(source);
         *self.namespaces.lock().unwrap() = vec![];
         // set availability
@@ -125,39 +222,54 @@ impl<'a> ResolverContext<'a> {
         self.current_namespace().map(|n| n.str_path()).unwrap_or(vec![])
     }
 
+    fn scoped_default_path(&self, path: Vec<String>) -> Vec<String> {
+        let source = self.source();
+        let selectively_imported = path.first().map_or(false, |name| self.selectively_imported_names.get(&source.file_path).map_or(false, |names| names.contains(name)));
+        if self.aliased_only_import_file_paths.contains(&source.file_path) && !selectively_imported {
+            let mut scoped = vec![source.file_path.clone()];
+            scoped.extend(path);
+            scoped
+        } else {
+            path
+        }
+    }
+
     pub(crate) fn add_examined_default_path(&self, path: Vec<String>, availability: Availability) {
+        let path = self.scoped_default_path(path);
+        let source_id = self.source().id;
         if availability.contains(Availability::mysql()) {
-            self.examined_default_paths_mysql.lock().unwrap().insert(path.clone());
+            self.examined_default_paths_mysql.lock().unwrap().entry(path.clone()).or_insert(source_id);
         }
         if availability.contains(Availability::postgres()) {
-            self.examined_default_paths_postgres.lock().unwrap().insert(path.clone());
+            self.examined_default_paths_postgres.lock().unwrap().entry(path.clone()).or_insert(source_id);
         }
         if availability.contains(Availability::sqlite()) {
-            self.examined_default_paths_sqlite.lock().unwrap().insert(path.clone());
+            self.examined_default_paths_sqlite.lock().unwrap().entry(path.clone()).or_insert(source_id);
         }
         if availability.contains(Availability::mongo()) {
-            self.examined_default_paths_mongo.lock().unwrap().insert(path.clone());
+            self.examined_default_paths_mongo.lock().unwrap().entry(path.clone()).or_insert(source_id);
         }
     }
 
     pub(crate) fn has_examined_default_path(&self, path: &Vec<String>, availability: Availability) -> bool {
+        let path = &self.scoped_default_path(path.clone());
         if availability.contains(Availability::mysql()) {
-            if self.examined_default_paths_mysql.lock().unwrap().contains(path) {
+            if self.examined_default_paths_mysql.lock().unwrap().contains_key(path) {
                 return true;
             }
         }
         if availability.contains(Availability::postgres()) {
-            if self.examined_default_paths_postgres.lock().unwrap().contains(path) {
+            if self.examined_default_paths_postgres.lock().unwrap().contains_key(path) {
                 return true;
             }
         }
         if availability.contains(Availability::sqlite()) {
-            if self.examined_default_paths_sqlite.lock().unwrap().contains(path) {
+            if self.examined_default_paths_sqlite.lock().unwrap().contains_key(path) {
                 return true;
             }
         }
         if availability.contains(Availability::mongo()) {
-            if self.examined_default_paths_mongo.lock().unwrap().contains(path) {
+            if self.examined_default_paths_mongo.lock().unwrap().contains_key(path) {
                 return true;
             }
         }
@@ -165,11 +277,11 @@ impl<'a> ResolverContext<'a> {
     }
 
     pub(crate) fn add_examined_middleware_path(&self, path: Vec<String>) {
-        self.examined_middleware_paths.lock().unwrap().insert(path);
+        self.examined_middleware_paths.lock().unwrap().entry(path).or_insert(self.source().id);
     }
 
     pub(crate) fn has_examined_middleware_path(&self, path: &Vec<String>) -> bool {
-        self.examined_middleware_paths.lock().unwrap().contains(path)
+        self.examined_middleware_paths.lock().unwrap().contains_key(path)
     }
 
     pub(crate) fn add_examined_field(&self, field: String) {
@@ -184,12 +296,20 @@ impl<'a> ResolverContext<'a> {
         self.examined_fields.lock().unwrap().clear();
     }
 
+    pub(crate) fn add_constant_use(&self, path: Vec<usize>) {
+        self.constant_uses.lock().unwrap().entry(self.source().id).or_default().push(path);
+    }
+
+    pub(crate) fn add_environment_reader(&self) {
+        self.environment_readers.lock().unwrap().insert(self.source().id);
+    }
+
     pub(crate) fn add_examined_data_set_record(&self, record: ExaminedDataSetRecord) {
-        self.examined_data_set_records.lock().unwrap().insert(record);
+        self.examined_data_set_records.lock().unwrap().entry(record).or_insert(self.source().id);
     }
 
     pub(crate) fn has_examined_data_set_record(&self, record: &ExaminedDataSetRecord) -> bool {
-        self.examined_data_set_records.lock().unwrap().contains(record)
+        self.examined_data_set_records.lock().unwrap().contains_key(record)
     }
     
     pub(crate) fn diagnostics(&self) -> &'a mut Diagnostics {
@@ -328,10 +448,35 @@ impl<'a> ResolverContext<'a> {
         ))
     }
 
+    /// Run `job` without keeping the diagnostics it reports.
+    pub(crate) fn discard_diagnostics<F>(&self, job: F) where F: FnOnce() {
+        self.take_diagnostics(job);
+    }
+
+    /// Run `job` and return the diagnostics it reports instead of keeping them.
+    pub(crate) fn take_diagnostics<F>(&self, job: F) -> Diagnostics where F: FnOnce() {
+        let errors = self.diagnostics().errors().len();
+        let warnings = self.diagnostics().warnings().len();
+        let shape_operation_keys = self.shape_operation_keys.lock().unwrap().len();
+        job();
+        let mut taken = Diagnostics::new();
+        for error in &self.diagnostics().errors()[errors..] {
+            taken.insert(error.clone());
+        }
+        for warning in &self.diagnostics().warnings()[warnings..] {
+            taken.insert(warning.clone());
+        }
+        self.diagnostics().truncate(errors, warnings);
+        self.shape_operation_keys.lock().unwrap().truncate(shape_operation_keys);
+        taken
+    }
+
     pub(crate) fn alter_state_and_restore<F>(&self, source_id: usize, namespace_path: &Vec<usize>, job: F) where F: Fn(&Self) {
         let source_to_restore = self.source();
         let availabilities_to_restore = self.availabilities.lock().unwrap().clone();
         let namespaces_to_restore = self.namespaces.lock().unwrap().clone();
+        let examined_datasets_to_restore = self.examined_datasets_in_a_file.lock().unwrap().clone();
+        let examined_namespaces_to_restore = self.examined_namespaces_in_a_file.lock().unwrap().clone();
         let new_source = self.schema.source(source_id).unwrap();
         self.start_source(new_source);
         for (index, namespace_id) in namespace_path.iter().enumerate() {
@@ -343,5 +488,26 @@ impl<'a> ResolverContext<'a> {
         *self.source.lock().unwrap() = Some(source_to_restore);
         *self.availabilities.lock().unwrap() = availabilities_to_restore;
         *self.namespaces.lock().unwrap() = namespaces_to_restore;
+        *self.examined_datasets_in_a_file.lock().unwrap() = examined_datasets_to_restore;
+        *self.examined_namespaces_in_a_file.lock().unwrap() = examined_namespaces_to_restore;
+    }
+}
+
+fn aliased_only_import_file_paths(schema: &Schema) -> BTreeSet<String> {
+    let imports: Vec<&Import> = schema.sources().iter().flat_map(|s| s.imports()).collect();
+    let unaliased: BTreeSet<&String> = imports.iter().filter(|i| i.alias().is_none() && !i.is_selective()).flat_map(|i| i.file_paths.iter()).collect();
+    let main = schema.references.main_source.and_then(|id| schema.source(id)).map(|source| &source.file_path);
+    imports.iter().filter(|i| i.alias().is_some()).flat_map(|i| i.file_paths.iter()).filter(|file_path| {
+        !unaliased.contains(file_path) && main != Some(*file_path)
+    }).cloned().collect()
+}
+
+fn selectively_imported_names(schema: &Schema) -> BTreeMap<String, BTreeSet<String>> {
+    let mut names: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for import in schema.sources().iter().flat_map(|s| s.imports()).filter(|i| i.is_selective()) {
+        for file_path in &import.file_paths {
+            names.entry(file_path.clone()).or_default().extend(import.identifiers().map(|identifier| identifier.name().to_owned()));
+        }
     }
+    names
 }
@@ -0,0 +1,61 @@
+use crate::ast::arith_expr::ArithExpr;
+use crate::ast::expression::{Expression, ExpressionKind};
+use crate::ast::node::Node;
+use crate::ast::source::Source;
+use crate::traits::node_trait::NodeTrait;
+use crate::traits::resolved::Resolve;
+
+/// Bring a source reused from a previous parse back to its just parsed state, so that it can
+/// be resolved again.
+pub(super) fn unresolve_source(source: &Source) {
+    for node in source.children.values() {
+        unresolve_node(node);
+    }
+}
+
+fn unresolve_node(node: &Node) {
+    match node {
+        Node::Argument(argument) => argument.unresolve(),
+        Node::ArithExpr(arith_expr) => unresolve_arith_expr(arith_expr),
+        Node::ConstantDeclaration(constant) => {
+            constant.use_count.set(0);
+            constant.unresolve();
+        },
+        Node::DataSetGroup(group) => group.unresolve(),
+        Node::DataSetRecord(record) => record.unresolve(),
+        Node::Decorator(decorator) => decorator.unresolve(),
+        Node::EnumMember(member) => member.unresolve(),
+        Node::Expression(expression) => unresolve_expression(expression),
+        Node::Field(field) => field.unresolve(),
+        Node::IncludeHandlerFromTemplate(include) => include.unresolve(),
+        Node::InterfaceDeclaration(interface) => interface.unresolve(),
+        Node::Model(model) => model.unresolve(),
+        Node::Pipeline(pipeline) => pipeline.unresolve(),
+        Node::SynthesizedShapeDeclaration(declaration) => declaration.unresolve(),
+        Node::SynthesizedShapeFieldDeclaration(declaration) => declaration.unresolve(),
+        Node::TypeAliasDeclaration(declaration) => declaration.unresolve(),
+        Node::TypeExpr(type_expr) => type_expr.unresolve(),
+        _ => (),
+    }
+    if let Some(children) = node.children() {
+        for child in children.values() {
+            unresolve_node(child);
+        }
+    }
+}
+
+// expressions hold their kind inline, its children are visited through the expression node
+fn unresolve_expression(expression: &Expression) {
+    expression.unresolve();
+    match &expression.kind {
+        ExpressionKind::ArithExpr(arith_expr) => unresolve_arith_expr(arith_expr),
+        ExpressionKind::Pipeline(pipeline) => pipeline.unresolve(),
+        _ => (),
+    }
+}
+
+fn unresolve_arith_expr(arith_expr: &ArithExpr) {
+    if let ArithExpr::Expression(expression) = arith_expr {
+        unresolve_expression(expression);
+    }
+}
@@ -33,11 +33,7 @@ pub(crate) fn find_source_connector<'a>(schema: &'a Schema, source: &'a Source)
         Some(connector)
     } else {
         source.imports().iter().find_map(|import| {
-            if let Some(source) = schema.source_at_path(&import.file_path) {
-                source.get_connector()
-            } else {
-                None
-            }
+            schema.import_sources(import).iter().find_map(|source| source.get_connector())
         })
     }
 }
@@ -54,7 +50,7 @@ pub(crate) fn find_availability_in_connector(connector: Option<&Config>) -> Avai
             if let Some(enum_variant_literal) = provider.1.kind.as_enum_variant_literal() {
                 availability_from_enum_variant_literal(enum_variant_literal)
             } else if let Some(unit) = provider.1.kind.as_unit() {
-                if let Some(enum_variant_literal) = unit.expressions().next().unwrap().kind.as_enum_variant_literal() {
+                if let Some(enum_variant_literal) = unit.expressions().next().and_then(|expression| expression.kind.as_enum_variant_literal()) {
                     availability_from_enum_variant_literal(enum_variant_literal)
                 } else {
                     Availability::no_database()
@@ -63,7 +59,7 @@ pub(crate) fn find_availability_in_connector(connector: Option<&Config>) -> Avai
                 match arith {
                     ArithExpr::Expression(e) => {
                         if let Some(unit) = e.kind.as_unit() {
-                            if let Some(enum_variant_literal) = unit.expressions().next().unwrap().kind.as_enum_variant_literal() {
+                            if let Some(enum_variant_literal) = unit.expressions().next().and_then(|expression| expression.kind.as_enum_variant_literal()) {
                                 availability_from_enum_variant_literal(enum_variant_literal)
                             } else {
                                 Availability::no_database()
@@ -106,15 +102,9 @@ pub(crate) fn find_namespace_connector<'a>(namespace: &'a Namespace, schema: &'a
         Some(connector)
     } else {
         let connector = source.imports().iter().find_map(|import| {
-            if let Some(source) = schema.source_at_path(&import.file_path) {
-                if let Some(namespace) = source.find_child_namespace_by_string_path(&namespace.str_path()) {
-                    namespace.get_connector()
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
+            schema.import_sources(import).iter().find_map(|source| {
+                source.find_child_namespace_by_string_path(&namespace.str_path()).map(|namespace| namespace.get_connector()).flatten()
+            })
         });
         if let Some(connector) = connector {
             return Some(connector);
@@ -126,15 +116,9 @@ pub(crate) fn find_namespace_connector<'a>(namespace: &'a Namespace, schema: &'a
                     return Some(connector);
                 } else {
                     let connector = source.imports().iter().find_map(|import| {
-                        if let Some(source) = schema.source_at_path(&import.file_path) {
-                            if let Some(namespace) = source.find_child_namespace_by_string_path(&namespace.str_path()) {
-                                namespace.get_connector()
-                            } else {
-                                None
-                            }
-                        } else {
-                            None
-                        }
+                        schema.import_sources(import).iter().find_map(|source| {
+                            source.find_child_namespace_by_string_path(&namespace.str_path()).map(|namespace| namespace.get_connector()).flatten()
+                        })
                     });
                     if let Some(connector) = connector {
                         return Some(connector);
@@ -126,7 +126,7 @@ pub fn search_unit_for_definition<HAL, HS, HI, OUTPUT>(
                                         }
                                     }
                                 }
-                                _ => unreachable!(),
+                                _ => return default,
                             }
                         } else {
                             return default;
@@ -153,7 +153,7 @@ pub fn search_unit_for_definition<HAL, HS, HI, OUTPUT>(
                                     ExpressionKind::Identifier(_i) => {
                                         return default;
                                     }
-                                    _ => unreachable!()
+                                    _ => return default,
                                 }
                             },
                             Node::Config(config) => {
@@ -175,7 +175,7 @@ pub fn search_unit_for_definition<HAL, HS, HI, OUTPUT>(
                                     ExpressionKind::Subscript(_s) => {
                                         return default;
                                     }
-                                    _ => unreachable!()
+                                    _ => return default,
                                 }
                             }
                             Node::Enum(r#enum) => {
@@ -197,7 +197,7 @@ pub fn search_unit_for_definition<HAL, HS, HI, OUTPUT>(
                                     ExpressionKind::Subscript(_s) => {
                                         return default;
                                     }
-                                    _ => unreachable!()
+                                    _ => return default,
                                 }
                             }
                             Node::Model(model) => {
@@ -219,7 +219,7 @@ pub fn search_unit_for_definition<HAL, HS, HI, OUTPUT>(
                                     ExpressionKind::Subscript(_s) => {
                                         return default;
                                     }
-                                    _ => unreachable!()
+                                    _ => return default,
                                 }
                             }
                             Node::InterfaceDeclaration(interface) => {
@@ -241,7 +241,7 @@ pub fn search_unit_for_definition<HAL, HS, HI, OUTPUT>(
                                     ExpressionKind::Subscript(_s) => {
                                         return default;
                                     }
-                                    _ => unreachable!()
+                                    _ => return default,
                                 }
                             }
                             Node::Namespace(namespace) => {
@@ -263,10 +263,39 @@ pub fn search_unit_for_definition<HAL, HS, HI, OUTPUT>(
                                     ExpressionKind::Subscript(_s) => {
                                         return default;
                                     }
-                                    _ => unreachable!()
+                                    _ => return default,
                                 }
                             }
-                            _ => unreachable!()
+                            Node::Import(import) => {
+                                match &expression.kind {
+                                    ExpressionKind::Identifier(identifier) => {
+                                        if let Some(path) = schema.import_sources(import).iter().find_map(|imported_source| search_identifier_path_names_with_filter_to_path(
+                                            &vec![identifier.name()],
+                                            schema,
+                                            imported_source,
+                                            &vec![],
+                                            &top_filter_for_reference_type(ReferenceSpace::Default),
+                                            availability,
+                                        )) {
+                                            if identifier.span.contains_line_col(line_col) {
+                                                return handle_identifier(identifier.span, &path, None);
+                                            } else {
+                                                current = Some(UnitSearchResult::Reference(path));
+                                            }
+                                        } else {
+                                            return default;
+                                        }
+                                    },
+                                    ExpressionKind::ArgumentList(_a) => {
+                                        return default;
+                                    }
+                                    ExpressionKind::Subscript(_s) => {
+                                        return default;
+                                    }
+                                    _ => return default,
+                                }
+                            }
+                            _ => return default,
                         }
                     }
                 }
@@ -19,6 +19,10 @@ pub trait Resolve<T> {
     fn is_resolved(&self) -> bool {
         self.resolved_ref_cell().borrow().is_some()
     }
+
+    fn unresolve(&self) {
+        *(unsafe { &mut *self.resolved_ref_cell().as_ptr() }) = None;
+    }
 }
 
 pub trait ResolveAndClone<T>: Resolve<T> where T: Clone {
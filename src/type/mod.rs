@@ -2,6 +2,7 @@ pub mod r#type;
 pub mod keyword;
 pub mod synthesized_shape;
 pub mod synthesized_shape_reference;
+pub mod shape_operation;
 pub mod synthesized_enum;
 pub mod synthesized_enum_reference;
 pub mod synthesized_interface_enum;
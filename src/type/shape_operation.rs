@@ -0,0 +1,84 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use itertools::Itertools;
+use serde::Serialize;
+use strum_macros::{Display, EnumString, AsRefStr, EnumIter};
+use crate::ast::schema::Schema;
+use crate::r#type::keyword::Keyword;
+use crate::r#type::synthesized_shape::SynthesizedShape;
+use crate::r#type::Type;
+
+#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq, Serialize, Display, EnumString, AsRefStr, EnumIter)]
+pub enum ShapeOperationKind {
+    Partial,
+    Required,
+    Pick,
+    Omit,
+}
+
+impl ShapeOperationKind {
+
+    pub fn requires_keys(&self) -> bool {
+        match self {
+            ShapeOperationKind::Pick | ShapeOperationKind::Omit => true,
+            _ => false,
+        }
+    }
+}
+
+/// A builtin type operator applied to an interface or a shape, like `Partial<I>` or `Pick<I, .a | .b>`.
+/// The result shape is calculated on demand since the shape of the owner may not be resolved
+/// when the operation is resolved.
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize)]
+pub struct ShapeOperation {
+    pub kind: ShapeOperationKind,
+    pub owner: Box<Type>,
+    pub keys: Vec<String>,
+}
+
+impl ShapeOperation {
+
+    pub fn new(kind: ShapeOperationKind, owner: Type, keys: Vec<String>) -> Self {
+        Self { kind, owner: Box::new(owner), keys }
+    }
+
+    pub fn apply(&self, shape: &SynthesizedShape) -> SynthesizedShape {
+        match self.kind {
+            ShapeOperationKind::Partial => shape.partial(),
+            ShapeOperationKind::Required => shape.required(),
+            ShapeOperationKind::Pick => shape.pick(&self.keys),
+            ShapeOperationKind::Omit => shape.omit(&self.keys),
+        }
+    }
+
+    pub fn fetch_synthesized_definition(&self, schema: &Schema) -> Option<SynthesizedShape> {
+        self.owner.evaluate_synthesized_shape(schema).map(|shape| self.apply(&shape))
+    }
+
+    pub fn replace_keywords(&self, map: &BTreeMap<Keyword, Type>) -> Self {
+        Self {
+            kind: self.kind,
+            owner: Box::new(self.owner.replace_keywords(map)),
+            keys: self.keys.clone(),
+        }
+    }
+
+    pub fn replace_generics(&self, map: &BTreeMap<String, Type>) -> Self {
+        Self {
+            kind: self.kind,
+            owner: Box::new(self.owner.replace_generics(map)),
+            keys: self.keys.clone(),
+        }
+    }
+}
+
+impl Display for ShapeOperation {
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.kind.requires_keys() {
+            f.write_str(&format!("{}<{}, {}>", self.kind, self.owner, self.keys.iter().map(|k| format!(".{}", k)).join(" | ")))
+        } else {
+            f.write_str(&format!("{}<{}>", self.kind, self.owner))
+        }
+    }
+}
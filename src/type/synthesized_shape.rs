@@ -107,6 +107,12 @@ impl SynthesizedShape {
             } else {
                 false
             }
+        } else if let Some(shape_operation) = other.as_shape_operation() {
+            if let Some(shape) = shape_operation.fetch_synthesized_definition(schema) {
+                self.can_coerce_to_shape(&shape)
+            } else {
+                false
+            }
         } else {
             false
         }
@@ -149,6 +155,38 @@ impl SynthesizedShape {
         true
     }
 
+    pub fn partial(&self) -> Self {
+        self.map_fields(|_, t| Some(t.wrap_in_optional()))
+    }
+
+    pub fn required(&self) -> Self {
+        self.map_fields(|_, t| Some(t.unwrap_optional().clone()))
+    }
+
+    pub fn pick(&self, keys: &Vec<String>) -> Self {
+        self.map_fields(|k, t| if keys.contains(k) { Some(t.clone()) } else { None })
+    }
+
+    pub fn omit(&self, keys: &Vec<String>) -> Self {
+        self.map_fields(|k, t| if keys.contains(k) { None } else { Some(t.clone()) })
+    }
+
+    fn map_fields<F>(&self, f: F) -> Self where F: Fn(&String, &Type) -> Option<Type> {
+        let mut keys = vec![];
+        let mut fields = btreemap! {};
+        for key in &self.keys {
+            if let Some(t) = self.fields.get(key).and_then(|t| f(key, t)) {
+                keys.push(key.clone());
+                fields.insert(key.clone(), t);
+            }
+        }
+        Self {
+            generics: self.generics.clone(),
+            keys,
+            fields,
+        }
+    }
+
     pub fn required_keys(&self) -> BTreeSet<&str> {
         self.iter().filter_map(|(k, v)| if !v.is_optional() { Some(k.as_str()) } else { None }).collect()
     }
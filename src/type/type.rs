@@ -13,6 +13,7 @@ use crate::r#type::synthesized_enum::SynthesizedEnum;
 use crate::r#type::synthesized_interface_enum::SynthesizedInterfaceEnum;
 use crate::r#type::synthesized_interface_enum_reference::SynthesizedInterfaceEnumReference;
 use crate::r#type::synthesized_shape_reference::SynthesizedShapeReference;
+use crate::r#type::shape_operation::ShapeOperation;
 use crate::traits::resolved::Resolve;
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
@@ -128,6 +129,20 @@ pub enum Type {
     ///
     Regex,
 
+    // literal types
+
+    /// String Literal
+    ///
+    StringLiteral(String),
+
+    /// Int Literal
+    ///
+    IntLiteral(i64),
+
+    /// Bool Literal
+    ///
+    BoolLiteral(bool),
+
     /// Array
     ///
     Array(Box<Type>),
@@ -158,6 +173,10 @@ pub enum Type {
     ///
     DeclaredSynthesizedShape(Reference, Box<Type>),
 
+    /// Shape Operation
+    ///
+    ShapeOperation(ShapeOperation),
+
     /// Enum Variant
     ///
     EnumVariant(Reference),
@@ -256,6 +275,14 @@ impl Type {
         }
     }
 
+    /// The members of a union, `A | B | C` is resolved into nested unions.
+    pub fn union_members(&self) -> Vec<&Type> {
+        match self {
+            Self::Union(types) => types.iter().flat_map(|t| t.union_members()).collect(),
+            _ => vec![self],
+        }
+    }
+
     pub fn is_enumerable(&self) -> bool {
         self.as_enumerable().is_some()
     }
@@ -442,6 +469,61 @@ impl Type {
         }
     }
 
+    pub fn is_string_literal(&self) -> bool {
+        self.as_string_literal().is_some()
+    }
+
+    pub fn as_string_literal(&self) -> Option<&str> {
+        match self {
+            Type::StringLiteral(v) => Some(v.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn is_int_literal(&self) -> bool {
+        self.as_int_literal().is_some()
+    }
+
+    pub fn as_int_literal(&self) -> Option<i64> {
+        match self {
+            Type::IntLiteral(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn is_bool_literal(&self) -> bool {
+        self.as_bool_literal().is_some()
+    }
+
+    pub fn as_bool_literal(&self) -> Option<bool> {
+        match self {
+            Type::BoolLiteral(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn is_literal(&self) -> bool {
+        self.is_string_literal() || self.is_int_literal() || self.is_bool_literal()
+    }
+
+    /// The literal type of `value`, a value of this type.
+    pub fn literal_type_of_value(&self, value: &Value) -> Option<Type> {
+        match self {
+            Type::String => value.as_str().map(|v| Type::StringLiteral(v.to_owned())),
+            Type::Int | Type::Int64 => value.to_int64().map(Type::IntLiteral),
+            Type::Bool => value.as_bool().map(Type::BoolLiteral),
+            _ => None,
+        }
+    }
+
+    pub fn contains_literals(&self) -> bool {
+        match self {
+            Type::Optional(inner) => inner.contains_literals(),
+            Type::Union(types) => types.iter().any(|t| t.contains_literals()),
+            _ => self.is_literal(),
+        }
+    }
+
     pub fn is_array(&self) -> bool {
         self.as_array().is_some()
     }
@@ -515,6 +597,17 @@ impl Type {
         }
     }
 
+    pub fn is_shape_operation(&self) -> bool {
+        self.as_shape_operation().is_some()
+    }
+
+    pub fn as_shape_operation(&self) -> Option<&ShapeOperation> {
+        match self {
+            Type::ShapeOperation(s) => Some(s),
+            _ => None,
+        }
+    }
+
     pub fn is_declared_shape_reference(&self) -> bool {
         self.as_declared_shape_reference().is_some()
     }
@@ -777,7 +870,7 @@ impl Type {
     }
 
     pub fn is_dictionary_representable(&self) -> bool {
-        self.is_dictionary() || self.is_synthesized_shape_reference() || self.is_synthesized_shape() || self.is_interface_object()
+        self.is_dictionary() || self.is_synthesized_shape_reference() || self.is_synthesized_shape() || self.is_interface_object() || self.is_shape_operation()
     }
 
     pub fn expect_for_literal(&self) -> Type {
@@ -880,6 +973,7 @@ impl Type {
             Type::SynthesizedShape(shape) => !shape.generics().is_empty(),
             Type::InterfaceObject(_, types) => types.iter().any(|t| t.contains_generics()),
             Type::DeclaredSynthesizedShape(_, inner) => inner.contains_generics(),
+            Type::ShapeOperation(shape_operation) => shape_operation.owner.contains_generics(),
             Type::StructObject(_, types) => types.iter().any(|t| t.contains_generics()),
             Type::DataSetGroup(inner) => inner.contains_generics(),
             Type::DataSetRecord(a, b) => a.contains_generics() || b.contains_generics(),
@@ -903,6 +997,7 @@ impl Type {
             Type::SynthesizedShape(shape) => !shape.generics().is_empty(),
             Type::InterfaceObject(_, types) => types.iter().any(|t| t.contains_keywords()),
             Type::DeclaredSynthesizedShape(_, inner) => inner.contains_keywords(),
+            Type::ShapeOperation(shape_operation) => shape_operation.owner.contains_keywords(),
             Type::StructObject(_, types) => types.iter().any(|t| t.contains_keywords()),
             Type::DataSetGroup(inner) => inner.contains_keywords(),
             Type::DataSetRecord(a, b) => a.contains_keywords() || b.contains_keywords(),
@@ -936,6 +1031,7 @@ impl Type {
             Type::SynthesizedInterfaceEnumReference(interface_enum_reference) => Type::SynthesizedInterfaceEnumReference(interface_enum_reference.replace_generics(map)),
             Type::SynthesizedShape(shape) => Type::SynthesizedShape(shape.replace_generics(map)),
             Type::DeclaredSynthesizedShape(reference, internal) => Type::DeclaredSynthesizedShape(reference.clone(), Box::new(internal.replace_generics(map))),
+            Type::ShapeOperation(shape_operation) => Type::ShapeOperation(shape_operation.replace_generics(map)),
             Type::InterfaceObject(r, types) => Type::InterfaceObject(r.clone(), types.iter().map(|t| t.replace_generics(map)).collect()),
             Type::StructObject(r, types) => Type::StructObject(r.clone(), types.iter().map(|t| t.replace_generics(map)).collect()),
             Type::DataSetGroup(inner) => Type::DataSetGroup(Box::new(inner.replace_generics(map))),
@@ -973,6 +1069,9 @@ impl Type {
                 shape_reference.build_generics_map(map, expect);
             },
             Type::DeclaredSynthesizedShape(_, inner) => inner.build_generics_map(map, expect),
+            Type::ShapeOperation(shape_operation) => if let Some(expect) = expect.as_shape_operation() {
+                shape_operation.owner.build_generics_map(map, expect.owner.as_ref());
+            },
             Type::Pipeline(a, b) => if let Some(pipeline) = expect.as_pipeline() {
                 a.as_ref().build_generics_map(map, pipeline.0);
                 b.as_ref().build_generics_map(map, pipeline.1);
@@ -1006,6 +1105,7 @@ impl Type {
             Type::SynthesizedInterfaceEnumReference(interface_enum_reference) => Type::SynthesizedInterfaceEnumReference(interface_enum_reference.replace_keywords(map)),
             Type::SynthesizedShape(shape) => Type::SynthesizedShape(shape.replace_keywords(map)),
             Type::DeclaredSynthesizedShape(reference, internal) => Type::DeclaredSynthesizedShape(reference.clone(), Box::new(internal.replace_keywords(map))),
+            Type::ShapeOperation(shape_operation) => Type::ShapeOperation(shape_operation.replace_keywords(map)),
             Type::InterfaceObject(r, types) => Type::InterfaceObject(r.clone(), types.iter().map(|t| t.replace_keywords(map)).collect()),
             Type::StructObject(r, types) => Type::StructObject(r.clone(), types.iter().map(|t| t.replace_keywords(map)).collect()),
             Type::DataSetGroup(inner) => Type::DataSetGroup(Box::new(inner.replace_keywords(map))),
@@ -1022,6 +1122,28 @@ impl Type {
         result.flatten()
     }
 
+    /// Return `true` if `other` with the known `value` satisfies `self`. Like `test`, and a
+    /// literal type also accepts a value of its base type when the value is the literal.
+    pub fn test_with_value(&self, other: &Type, value: Option<&Value>) -> bool {
+        if self.test(other) {
+            return true;
+        }
+        match self {
+            Type::Union(types) => types.iter().any(|t| t.test_with_value(other, value)),
+            Type::Optional(inner) => inner.test_with_value(other, value),
+            Type::Array(inner) => match (other.as_array(), value.and_then(Value::as_array)) {
+                (Some(other), Some(values)) => values.iter().all(|v| inner.test_with_value(other, Some(v))),
+                _ => false,
+            },
+            Type::Dictionary(inner) => match (other.as_dictionary(), value.and_then(Value::as_dictionary)) {
+                (Some(other), Some(values)) => values.values().all(|v| inner.test_with_value(other, Some(v))),
+                _ => false,
+            },
+            _ if self.is_literal() => value.and_then(|v| other.literal_type_of_value(v)).map_or(false, |literal| self.test(&literal)),
+            _ => false,
+        }
+    }
+
     /// Return `true` if `other` satisfies `self`
     ///
     pub fn test(&self, other: &Type) -> bool {
@@ -1040,18 +1162,21 @@ impl Type {
             Type::Type => other.is_type(),
             Type::TypeValueAsType(inner) => other.is_type_value_as_type() && other.as_type_value_as_type().unwrap() == inner.as_ref(),
             Type::Null => other.is_null(),
-            Type::Bool => other.is_bool(),
-            Type::Int => other.is_int(),
-            Type::Int64 => other.is_int64(),
+            Type::Bool => other.is_bool() || other.is_bool_literal(),
+            Type::Int => other.is_int() || other.is_int_literal(),
+            Type::Int64 => other.is_int64() || other.is_int_literal(),
             Type::Float32 => other.is_float32(),
             Type::Float => other.is_float(),
             Type::Decimal => other.is_decimal(),
-            Type::String => other.is_string(),
+            Type::String => other.is_string() || other.is_string_literal(),
             Type::ObjectId => other.is_object_id(),
             Type::Date => other.is_date(),
             Type::DateTime => other.is_datetime(),
             Type::File => other.is_file(),
             Type::Regex => other.is_regex(),
+            Type::StringLiteral(v) => other.as_string_literal() == Some(v.as_str()),
+            Type::IntLiteral(v) => other.as_int_literal() == Some(*v),
+            Type::BoolLiteral(v) => other.as_bool_literal() == Some(*v),
             Type::Array(inner) => other.is_array() && inner.as_ref().test(other.as_array().unwrap()),
             Type::Dictionary(inner) => other.is_dictionary() && inner.as_ref().test(other.as_dictionary().unwrap()),
             Type::Tuple(types) => other.is_tuple() && other.as_tuple().unwrap().len() == types.len() && types.iter().enumerate().all(|(index, t)| t.test(other.as_tuple().unwrap().get(index).unwrap())),
@@ -1059,6 +1184,7 @@ impl Type {
             Type::SynthesizedShape(shape) => other.is_synthesized_shape() && shape.test(other.as_synthesized_shape().unwrap()),
             Type::SynthesizedShapeReference(r) => other.is_synthesized_shape_reference() && r == other.as_synthesized_shape_reference().unwrap(),
             Type::DeclaredSynthesizedShape(r, t) => other.is_declared_shape_reference() && t.as_ref() == other.as_declared_shape_reference().unwrap().1 && r == other.as_declared_shape_reference().unwrap().0,
+            Type::ShapeOperation(s) => other.is_shape_operation() && s == other.as_shape_operation().unwrap(),
             Type::EnumVariant(r) => other.is_enum_variant() && r == other.as_enum_variant().unwrap(),
             Type::SynthesizedEnum(s) => other.is_synthesized_enum() && s.members.keys().collect::<BTreeSet<&String>>() == other.as_synthesized_enum().unwrap().members.keys().collect::<BTreeSet<&String>>(),
             Type::SynthesizedEnumReference(r) => other.is_synthesized_enum_reference() && r == other.as_synthesized_enum_reference().unwrap(),
@@ -1082,7 +1208,7 @@ impl Type {
         if self.is_model() && other.is_model_object() {
             (true, true)
         } else if self.is_shape() {
-            let result = other.is_synthesized_shape_reference() || other.is_synthesized_shape() || other.is_interface_object() || other.is_declared_shape_reference();
+            let result = other.is_synthesized_shape_reference() || other.is_synthesized_shape() || other.is_interface_object() || other.is_declared_shape_reference() || other.is_shape_operation();
             (result, result)
         } else if self.is_shape_field() && other.is_field_name() {
             let shape = self.as_shape_field().unwrap();
@@ -1115,6 +1241,13 @@ impl Type {
                     let shape = interface.shape_from_generics(types);
                     (shape.get(field_name).is_some(), true)
                 },
+                Type::ShapeOperation(shape_operation) => {
+                    if let Some(shape) = shape_operation.fetch_synthesized_definition(schema) {
+                        (shape.get(field_name).is_some(), true)
+                    } else {
+                        (false, true)
+                    }
+                },
                 _ => (false, false)
             }
         } else if self.is_synthesized_enum_reference() && other.is_field_name() {
@@ -1162,6 +1295,7 @@ impl Type {
                 Box::new(f_ref(b, &f)),
             ),
             Type::DeclaredSynthesizedShape(r, t) => Type::DeclaredSynthesizedShape(r.clone(), Box::new(f_ref(t, &f))),
+            Type::ShapeOperation(s) => Type::ShapeOperation(ShapeOperation::new(s.kind, f_ref(&s.owner, &f), s.keys.clone())),
             _ => self.clone(),
         }
     }
@@ -1175,6 +1309,7 @@ impl Type {
             Type::Range(inner) => vec![inner.as_ref().clone()],
             Type::InterfaceObject(_, types) => types.clone(),
             Type::DeclaredSynthesizedShape(_, inner) => vec![inner.as_ref().clone()],
+            Type::ShapeOperation(shape_operation) => vec![shape_operation.owner.as_ref().clone()],
             Type::StructObject(_, types) => types.clone(),
             Type::Pipeline(input, output) => vec![input.as_ref().clone(), output.as_ref().clone()],
             _ => vec![]
@@ -1206,6 +1341,12 @@ impl Type {
             let interface_declaration = schema.find_top_by_path(self.as_interface_object().unwrap().0.path()).unwrap().as_interface_declaration().unwrap();
             let shape = interface_declaration.shape_from_generics(self.as_interface_object().unwrap().1);
             shape.can_coerce_to(other, schema)
+        } else if self.is_shape_operation() && other.is_dictionary_representable() {
+            if let Some(shape) = self.as_shape_operation().unwrap().fetch_synthesized_definition(schema) {
+                shape.can_coerce_to(other, schema)
+            } else {
+                false
+            }
         } else if !self.is_optional() && other.is_optional() {
             self.can_coerce_to(other.as_optional().unwrap(), schema)
         } else if self.is_optional() && other.is_optional() {
@@ -1243,6 +1384,42 @@ impl Type {
         }
     }
 
+    /// The key whose literal types tell the members of a union of shapes apart.
+    pub fn union_discriminator(&self, schema: &Schema) -> Option<String> {
+        if !self.is_union() {
+            return None;
+        }
+        let shapes: Vec<SynthesizedShape> = self.union_members().iter().map(|t| t.evaluate_synthesized_shape(schema)).collect::<Option<Vec<_>>>()?;
+        shapes.first()?.keys().find(|key| {
+            let literals: Vec<&Type> = shapes.iter().filter_map(|shape| shape.get(key.as_str())).filter(|t| t.is_literal()).collect();
+            literals.len() == shapes.len() && literals.iter().all_unique()
+        }).cloned()
+    }
+
+    /// The shape of an interface object, a shape or a shape operation. The shapes of interfaces are
+    /// only available after the interface shapes are resolved.
+    pub fn evaluate_synthesized_shape(&self, schema: &Schema) -> Option<SynthesizedShape> {
+        match self {
+            Type::SynthesizedShape(shape) => Some(shape.clone()),
+            Type::SynthesizedShapeReference(shape_reference) => shape_reference.fetch_synthesized_definition(schema).and_then(|t| t.evaluate_synthesized_shape(schema)),
+            Type::DeclaredSynthesizedShape(reference, inner) => {
+                let model_reference = inner.as_model_object()?;
+                let model = schema.find_top_by_path(model_reference.path())?.as_model()?;
+                model.resolved().get_declared(reference.string_path()).cloned()
+            },
+            Type::InterfaceObject(reference, types) => {
+                let interface = schema.find_top_by_path(reference.path())?.as_interface_declaration()?;
+                if interface.is_resolved() && interface.resolved().shape.is_some() {
+                    Some(interface.shape_from_generics(types))
+                } else {
+                    None
+                }
+            },
+            Type::ShapeOperation(shape_operation) => shape_operation.fetch_synthesized_definition(schema),
+            _ => None,
+        }
+    }
+
     pub fn flatten_struct_into_primitive(&self) -> Type {
         if let Some((reference, types)) = self.as_struct_object() {
             if reference.str_path() == vec!["std", "Null"] {
@@ -1324,6 +1501,9 @@ impl Display for Type {
             Type::DateTime => f.write_str("DateTime"),
             Type::File => f.write_str("File"),
             Type::Regex => f.write_str("Regex"),
+            Type::StringLiteral(v) => f.write_str(&format!("\"{}\"", v)),
+            Type::IntLiteral(v) => f.write_str(&v.to_string()),
+            Type::BoolLiteral(v) => f.write_str(&v.to_string()),
             Type::Array(inner) => if inner.is_union() {
                 f.write_str(&format!("({})[]", inner))
             } else {
@@ -1352,6 +1532,7 @@ impl Display for Type {
             Type::SynthesizedShape(shape) => Display::fmt(shape, f),
             Type::SynthesizedShapeReference(r) => Display::fmt(r, f),
             Type::DeclaredSynthesizedShape(r, t) => f.write_str(&format!("{}<{}>", r.str_path().join("."), t)),
+            Type::ShapeOperation(s) => Display::fmt(s, f),
             Type::EnumVariant(r) => f.write_str(&r.string_path().join(".")),
             Type::SynthesizedEnum(e) => Display::fmt(e, f),
             Type::SynthesizedEnumReference(r) => f.write_str(&format!("{}", r)),
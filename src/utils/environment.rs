@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+use crate::utils::path::SchemaFileSystem;
+
+/// The environment variables `ENV` is resolved against.
+#[derive(Debug, Clone)]
+pub enum Environment {
+    Variables(BTreeMap<String, String>),
+    /// A `.env` file. A relative path is relative to the directory of the main schema file.
+    DotEnvFile(String),
+}
+
+impl Environment {
+
+    /// The variables of the environment, or the path of the `.env` file which cannot be read.
+    pub(crate) fn load(&self, file_system: &dyn SchemaFileSystem, main: &str) -> Result<BTreeMap<String, String>, String> {
+        match self {
+            Environment::Variables(variables) => Ok(variables.clone()),
+            Environment::DotEnvFile(file_path) => {
+                let file_path = dot_env_file_path(file_path, file_system, main);
+                file_system.read(&file_path).map(|content| parse_dot_env(&content)).ok_or(file_path)
+            }
+        }
+    }
+
+    /// The path of the `.env` file, `None` if the variables are given directly.
+    pub(crate) fn file_path(&self, file_system: &dyn SchemaFileSystem, main: &str) -> Option<String> {
+        match self {
+            Environment::Variables(_) => None,
+            Environment::DotEnvFile(file_path) => Some(dot_env_file_path(file_path, file_system, main)),
+        }
+    }
+}
+
+fn dot_env_file_path(file_path: &str, file_system: &dyn SchemaFileSystem, main: &str) -> String {
+    if file_system.is_absolute(file_path) {
+        file_path.to_owned()
+    } else {
+        file_system.join(&file_system.parent(main), file_path)
+    }
+}
+
+/// Parse the content of a `.env` file. Lines are `KEY=VALUE` pairs with an optional `export`
+/// prefix, values may be quoted. Blank lines, comments and malformed lines are skipped.
+pub fn parse_dot_env(content: &str) -> BTreeMap<String, String> {
+    let mut variables = BTreeMap::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue
+        }
+        let line = line.strip_prefix("export ").map(str::trim_start).unwrap_or(line);
+        let Some((key, value)) = line.split_once('=') else {
+            continue
+        };
+        let key = key.trim();
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.') {
+            continue
+        }
+        variables.insert(key.to_owned(), parse_dot_env_value(value.trim()));
+    }
+    variables
+}
+
+fn parse_dot_env_value(value: &str) -> String {
+    if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        let mut result = String::new();
+        let mut chars = value[1..value.len() - 1].chars();
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                match chars.next() {
+                    Some('n') => result.push('\n'),
+                    Some('t') => result.push('\t'),
+                    Some(c) => result.push(c),
+                    None => result.push('\\'),
+                }
+            } else {
+                result.push(c);
+            }
+        }
+        result
+    } else if value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'') {
+        value[1..value.len() - 1].to_owned()
+    } else {
+        // an unquoted value ends before an inline comment
+        match value.find(" #") {
+            Some(index) => value[..index].trim_end().to_owned(),
+            None => value.to_owned(),
+        }
+    }
+}
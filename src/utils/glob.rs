@@ -0,0 +1,36 @@
+/// Whether an import path contains glob wildcards.
+pub(crate) fn is_glob_pattern(path: &str) -> bool {
+    path.contains('*') || path.contains('?')
+}
+
+/// Match a single path segment against a pattern segment. `*` matches any run of characters
+/// and `?` matches exactly one character. `**` is handled by the caller.
+pub(crate) fn segment_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = backtrack {
+            p = star_p + 1;
+            n = star_n + 1;
+            backtrack = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+    while p < pattern.len() && pattern[p] == '*' {
+        p += 1;
+    }
+    p == pattern.len()
+}
+
+pub(crate) fn path_segments(path: &str) -> Vec<&str> {
+    path.split(|c| c == '/' || c == '\\').collect()
+}
@@ -0,0 +1,32 @@
+use pest::unicode::{XID_CONTINUE, XID_START};
+
+/// Words which the grammar reserves. A name spelled like one of these is written in backticks.
+const KEYWORDS: &[&str] = &[
+    "let", "enum", "model", "struct", "static", "function", "unique", "exclusive", "variant",
+    "field", "relation", "property", "member", "pipeline", "item", "server", "connector", "client",
+    "entity", "admin", "debug", "namespace", "import", "from", "dataset", "autoseed", "notrack",
+    "group", "record", "declare", "builtin", "synthesized", "shape", "optional", "required", "with",
+    "nonapi", "config", "middleware", "middlewares", "decorator", "handler", "template", "include",
+    "as", "json", "form", "interface", "option", "extends", "abstract", "where", "type",
+    "true", "false", "null",
+];
+
+pub fn is_keyword(name: &str) -> bool {
+    KEYWORDS.contains(&name)
+}
+
+/// Whether `name` matches the `plain_identifier` rule.
+pub fn is_plain_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().map_or(false, |c| c == '_' || c.is_ascii_alphanumeric() || XID_START(c)) &&
+        chars.all(|c| c == '_' || XID_CONTINUE(c))
+}
+
+/// Wrap `name` in backticks if it cannot be written as a plain identifier or it's a keyword.
+pub fn escape_identifier(name: &str) -> String {
+    if is_plain_identifier(name) && !is_keyword(name) {
+        name.to_owned()
+    } else {
+        format!("`{}`", name)
+    }
+}
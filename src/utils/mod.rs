@@ -1,3 +1,6 @@
 pub mod path;
+pub mod environment;
 pub mod top_filter;
-pub(crate) mod output;
\ No newline at end of file
+pub mod identifier;
+pub(crate) mod output;
+pub(crate) mod glob;
\ No newline at end of file
@@ -1,46 +1,202 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use path_clean::PathClean;
 
-pub struct FileUtility {
-    pub read_file: fn(file_path: &str) -> Option<String>,
-    pub file_exists: fn(file_path: &str) -> bool,
-    pub file_is_directory: fn(file_path: &str) -> bool,
-    pub path_join: fn(base: &str, path: &str) -> String,
-    pub parent_directory: fn(file_path: &str) -> String,
-    pub path_is_absolute: fn(file_path: &str) -> bool,
+/// Where schema files are read from. The parser, the diagnostics printer and completion
+/// access files only through this trait.
+pub trait SchemaFileSystem: Debug + Send + Sync {
+
+    fn read(&self, file_path: &str) -> Option<String>;
+
+    fn exists(&self, file_path: &str) -> bool;
+
+    fn is_dir(&self, file_path: &str) -> bool;
+
+    /// Full paths of the entries directly inside the directory, sorted.
+    fn list_dir(&self, directory: &str) -> Vec<String>;
+
+    fn join(&self, base: &str, path: &str) -> String {
+        path_join(base, path)
+    }
+
+    fn parent(&self, file_path: &str) -> String {
+        parent_directory(file_path)
+    }
+
+    fn is_absolute(&self, file_path: &str) -> bool {
+        Path::new(file_path).is_absolute()
+    }
+
+    fn import_path(&self, source_path: &str, string: &str) -> String {
+        self.join(&self.parent(source_path), string)
+    }
+
+    /// The path with links resolved. Paths without links are returned unchanged.
+    fn canonicalize(&self, file_path: &str) -> String {
+        file_path.to_owned()
+    }
 }
 
-impl FileUtility {
+/// The local file system.
+#[derive(Debug, Default)]
+pub struct LocalFileSystem;
+
+impl SchemaFileSystem for LocalFileSystem {
+
+    fn read(&self, file_path: &str) -> Option<String> {
+        fs::read_to_string(Path::new(file_path)).ok()
+    }
+
+    fn exists(&self, file_path: &str) -> bool {
+        Path::new(file_path).exists()
+    }
 
-    pub fn import_path(&self, source_path: &str, string: &str) -> String {
-        (self.path_join)(&(self.parent_directory)(source_path), string)
+    fn is_dir(&self, file_path: &str) -> bool {
+        Path::new(file_path).is_dir()
+    }
+
+    fn list_dir(&self, directory: &str) -> Vec<String> {
+        let mut result: Vec<String> = match fs::read_dir(Path::new(directory)) {
+            Ok(entries) => entries.filter_map(|entry| entry.ok()).filter_map(|entry| entry.path().to_str().map(|s| s.to_string())).collect(),
+            Err(_) => vec![],
+        };
+        result.sort();
+        result
+    }
+
+    fn canonicalize(&self, file_path: &str) -> String {
+        fs::canonicalize(Path::new(file_path)).ok().and_then(|path| path.to_str().map(|s| s.to_string())).unwrap_or_else(|| file_path.to_owned())
     }
 }
 
-impl Default for FileUtility {
+/// Files served from memory, keyed by path. Directories exist implicitly when files are
+/// inside them.
+#[derive(Debug, Default)]
+pub struct InMemoryFileSystem {
+    files: BTreeMap<String, String>,
+}
 
-    fn default() -> Self {
-        Self {
-            read_file,
-            file_exists,
-            file_is_directory,
-            path_join,
-            parent_directory,
-            path_is_absolute,
-        }
+impl InMemoryFileSystem {
+
+    pub fn new(files: BTreeMap<String, String>) -> Self {
+        Self { files }
+    }
+
+    pub fn insert(&mut self, file_path: impl Into<String>, content: impl Into<String>) {
+        self.files.insert(file_path.into(), content.into());
     }
 }
 
-fn read_file(file_path: &str) -> Option<String> {
-    match fs::read_to_string(Path::new(file_path)) {
-        Ok(s) => Some(s),
-        Err(_) => None,
+impl SchemaFileSystem for InMemoryFileSystem {
+
+    fn read(&self, file_path: &str) -> Option<String> {
+        self.files.get(file_path).cloned()
+    }
+
+    fn exists(&self, file_path: &str) -> bool {
+        self.files.contains_key(file_path) || self.is_dir(file_path)
+    }
+
+    fn is_dir(&self, file_path: &str) -> bool {
+        contains_directory(self.files.keys(), file_path)
+    }
+
+    fn list_dir(&self, directory: &str) -> Vec<String> {
+        let mut result = vec![];
+        insert_directory_entries(self.files.keys(), directory, &mut result);
+        result.sort();
+        result
     }
 }
 
-fn file_exists(file_path: &str) -> bool {
-    Path::new(file_path).exists()
+/// Unsaved file contents laid over another file system, the way an editor sees the files.
+#[derive(Debug)]
+pub struct UnsavedFileSystem {
+    base: Arc<dyn SchemaFileSystem>,
+    unsaved_files: BTreeMap<String, String>,
+}
+
+impl UnsavedFileSystem {
+
+    pub fn new(base: Arc<dyn SchemaFileSystem>, unsaved_files: BTreeMap<String, String>) -> Self {
+        Self { base, unsaved_files }
+    }
+
+    /// The file system without the unsaved files.
+    pub fn base(&self) -> &Arc<dyn SchemaFileSystem> {
+        &self.base
+    }
+
+    pub fn unsaved_files(&self) -> &BTreeMap<String, String> {
+        &self.unsaved_files
+    }
+}
+
+impl SchemaFileSystem for UnsavedFileSystem {
+
+    fn read(&self, file_path: &str) -> Option<String> {
+        match self.unsaved_files.get(file_path) {
+            Some(content) => Some(content.clone()),
+            None => self.base.read(file_path),
+        }
+    }
+
+    fn exists(&self, file_path: &str) -> bool {
+        self.unsaved_files.contains_key(file_path) || contains_directory(self.unsaved_files.keys(), file_path) || self.base.exists(file_path)
+    }
+
+    fn is_dir(&self, file_path: &str) -> bool {
+        contains_directory(self.unsaved_files.keys(), file_path) || self.base.is_dir(file_path)
+    }
+
+    fn list_dir(&self, directory: &str) -> Vec<String> {
+        let mut result = self.base.list_dir(directory);
+        insert_directory_entries(self.unsaved_files.keys(), directory, &mut result);
+        result.sort();
+        result.dedup();
+        result
+    }
+
+    fn join(&self, base: &str, path: &str) -> String {
+        self.base.join(base, path)
+    }
+
+    fn parent(&self, file_path: &str) -> String {
+        self.base.parent(file_path)
+    }
+
+    fn is_absolute(&self, file_path: &str) -> bool {
+        self.base.is_absolute(file_path)
+    }
+
+    fn import_path(&self, source_path: &str, string: &str) -> String {
+        self.base.import_path(source_path, string)
+    }
+
+    fn canonicalize(&self, file_path: &str) -> String {
+        self.base.canonicalize(file_path)
+    }
+}
+
+fn contains_directory<'a>(file_paths: impl Iterator<Item=&'a String>, directory: &str) -> bool {
+    file_paths.into_iter().any(|path| Path::new(path).parent().map_or(false, |parent| parent.starts_with(directory)))
+}
+
+fn insert_directory_entries<'a>(file_paths: impl Iterator<Item=&'a String>, directory: &str, result: &mut Vec<String>) {
+    for path in file_paths {
+        let Ok(relative) = Path::new(path).strip_prefix(directory) else {
+            continue
+        };
+        if let Some(first) = relative.components().next() {
+            let entry = path_join(directory, first.as_os_str().to_str().unwrap());
+            if !result.contains(&entry) {
+                result.push(entry);
+            }
+        }
+    }
 }
 
 fn parent_directory(path: &str) -> String {
@@ -49,14 +205,6 @@ fn parent_directory(path: &str) -> String {
     path.to_str().unwrap().to_string()
 }
 
-fn path_is_absolute(path: &str) -> bool {
-    Path::new(path).is_absolute()
-}
-
 fn path_join(base: &str, path: &str) -> String {
     Path::new(base).join(Path::new(path)).clean().to_str().unwrap().to_string()
 }
-
-fn file_is_directory(file_path: &str) -> bool {
-    Path::new(file_path).is_dir()
-}
\ No newline at end of file
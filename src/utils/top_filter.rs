@@ -26,7 +26,7 @@ pub fn top_filter_for_reference_type(reference_type: ReferenceSpace) -> Arc<dyn
             top.as_middleware_declaration().is_some()
         }),
         ReferenceSpace::Default => Arc::new(|top: &Node| {
-            top.is_enum() || top.is_model() || top.is_interface_declaration() || top.is_struct_declaration() || top.is_config() || top.is_constant_declaration() || top.is_namespace() || top.is_data_set() || top.is_synthesized_shape_declaration()
+            top.is_enum() || top.is_model() || top.is_interface_declaration() || top.is_struct_declaration() || top.is_config() || top.is_constant_declaration() || top.is_namespace() || top.is_import() || top.is_data_set() || top.is_synthesized_shape_declaration() || top.is_type_alias_declaration()
         }),
     }
 }
@@ -66,13 +66,19 @@ pub fn top_filter_for_model() -> Arc<dyn Fn(&Node) -> bool> {
     })
 }
 
+pub fn top_filter_for_model_extends() -> Arc<dyn Fn(&Node) -> bool> {
+    Arc::new(|top: &Node| {
+        top.is_model() || top.is_interface_declaration() || top.is_namespace()
+    })
+}
+
 pub fn top_filter_for_type_expr_filter(type_expr_filter: TypeExprFilter) -> Arc<dyn Fn(&Node) -> bool> {
     match type_expr_filter {
         TypeExprFilter::None => Arc::new(|top: &Node| {
-            top.is_model() || top.is_interface_declaration() || top.is_enum() || (top.is_struct_declaration() && !struct_is_builtin(top.as_struct_declaration().unwrap()))
+            top.is_model() || top.is_interface_declaration() || top.is_enum() || top.is_type_alias_declaration() || (top.is_struct_declaration() && !struct_is_builtin(top.as_struct_declaration().unwrap()))
         }),
         TypeExprFilter::Model => Arc::new(|top: &Node| {
-            (top.is_enum() && enum_is_normal(top.as_enum().unwrap())) || top.is_model()
+            (top.is_enum() && enum_is_normal(top.as_enum().unwrap())) || top.is_model() || top.is_type_alias_declaration()
         }),
         TypeExprFilter::ActionInput => Arc::new(|top: &Node| {
             top.is_interface_declaration()
@@ -94,4 +100,10 @@ fn struct_is_builtin(struct_declaration: &StructDeclaration) -> bool {
 
 fn enum_is_normal(enum_declaration: &Enum) -> bool {
     !enum_declaration.interface && !enum_declaration.option
-}
\ No newline at end of file
+}
+
+pub fn top_filter_for_import() -> Arc<dyn Fn(&Node) -> bool> {
+    Arc::new(|top: &Node| {
+        top.name().is_some()
+    })
+}
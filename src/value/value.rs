@@ -457,6 +457,10 @@ fn operand_error_message(operand: &Value, name: &str) -> Error {
     Error::new(format!("cannot {name} {}", operand))
 }
 
+fn operand_overflow_error_message(operand: &Value, name: &str) -> Error {
+    Error::new(format!("cannot {name} {}: overflow", operand))
+}
+
 fn check_operands<F>(lhs: &Value, rhs: &Value, name: &str, matcher: F) -> teo_result::Result<()> where F: Fn(&Value) -> bool {
     let matcher_wrapper = |value: &Value| {
         (&matcher)(value)
@@ -471,6 +475,15 @@ fn operands_error_message(lhs: &Value, rhs: &Value, name: &str) -> Error {
     Error::new(format!("cannot {name} {:?} with {:?}", lhs, rhs))
 }
 
+fn overflow_error_message(lhs: &Value, rhs: &Value, name: &str) -> Error {
+    Error::new(format!("cannot {name} {:?} with {:?}: overflow", lhs, rhs))
+}
+
+// a negative amount or one of at least the bit width overflows
+fn shift_amount(rhs: &Value) -> Option<u32> {
+    rhs.to_int64().and_then(|rhs| u32::try_from(rhs).ok())
+}
+
 impl Add for &Value {
 
     type Output = teo_result::Result<Value>;
@@ -479,11 +492,11 @@ impl Add for &Value {
         Ok(match self {
             Value::Int(v) => {
                 check_operands(&self, &rhs, "add", |v| v.is_any_int())?;
-                Value::Int(v + rhs.to_int().unwrap())
+                Value::Int(rhs.to_int().and_then(|rhs| v.checked_add(rhs)).ok_or_else(|| overflow_error_message(self, rhs, "add"))?)
             },
             Value::Int64(v) => {
                 check_operands(&self, &rhs, "add", |v| v.is_any_int())?;
-                Value::Int64(v + rhs.to_int64().unwrap())
+                Value::Int64(rhs.to_int64().and_then(|rhs| v.checked_add(rhs)).ok_or_else(|| overflow_error_message(self, rhs, "add"))?)
             },
             Value::Float32(v) => {
                 check_operands(&self, &rhs, "add", |v| v.is_any_int_or_float())?;
@@ -514,11 +527,11 @@ impl Sub for &Value {
         Ok(match self {
             Value::Int(v) => {
                 check_operands(&self, &rhs, "sub", |v| v.is_any_int())?;
-                Value::Int(v - rhs.to_int().unwrap())
+                Value::Int(rhs.to_int().and_then(|rhs| v.checked_sub(rhs)).ok_or_else(|| overflow_error_message(self, rhs, "sub"))?)
             },
             Value::Int64(v) => {
                 check_operands(&self, &rhs, "sub", |v| v.is_any_int())?;
-                Value::Int64(v - rhs.to_int64().unwrap())
+                Value::Int64(rhs.to_int64().and_then(|rhs| v.checked_sub(rhs)).ok_or_else(|| overflow_error_message(self, rhs, "sub"))?)
             },
             Value::Float32(v) => {
                 check_operands(&self, &rhs, "sub", |v| v.is_any_int_or_float())?;
@@ -545,11 +558,11 @@ impl Mul for &Value {
         Ok(match self {
             Value::Int(v) => {
                 check_operands(&self, &rhs, "mul", |v| v.is_any_int())?;
-                Value::Int(v * rhs.to_int().unwrap())
+                Value::Int(rhs.to_int().and_then(|rhs| v.checked_mul(rhs)).ok_or_else(|| overflow_error_message(self, rhs, "mul"))?)
             },
             Value::Int64(v) => {
                 check_operands(&self, &rhs, "mul", |v| v.is_any_int())?;
-                Value::Int64(v * rhs.to_int64().unwrap())
+                Value::Int64(rhs.to_int64().and_then(|rhs| v.checked_mul(rhs)).ok_or_else(|| overflow_error_message(self, rhs, "mul"))?)
             },
             Value::Float32(v) => {
                 check_operands(&self, &rhs, "mul", |v| v.is_any_int_or_float())?;
@@ -576,11 +589,11 @@ impl Div for &Value {
         Ok(match self {
             Value::Int(v) => {
                 check_operands(&self, &rhs, "div", |v| v.is_any_int())?;
-                Value::Int(v / rhs.to_int().unwrap())
+                Value::Int(rhs.to_int().and_then(|rhs| v.checked_div(rhs)).ok_or_else(|| overflow_error_message(self, rhs, "div"))?)
             },
             Value::Int64(v) => {
                 check_operands(&self, &rhs, "div", |v| v.is_any_int())?;
-                Value::Int64(v / rhs.to_int64().unwrap())
+                Value::Int64(rhs.to_int64().and_then(|rhs| v.checked_div(rhs)).ok_or_else(|| overflow_error_message(self, rhs, "div"))?)
             },
             Value::Float32(v) => {
                 check_operands(&self, &rhs, "div", |v| v.is_any_int_or_float())?;
@@ -607,11 +620,11 @@ impl Rem for &Value {
         Ok(match self {
             Value::Int(v) => {
                 check_operands(&self, &rhs, "rem", |v| v.is_any_int())?;
-                Value::Int(v % rhs.to_int().unwrap())
+                Value::Int(rhs.to_int().and_then(|rhs| v.checked_rem(rhs)).ok_or_else(|| overflow_error_message(self, rhs, "rem"))?)
             },
             Value::Int64(v) => {
                 check_operands(&self, &rhs, "rem", |v| v.is_any_int())?;
-                Value::Int64(v % rhs.to_int64().unwrap())
+                Value::Int64(rhs.to_int64().and_then(|rhs| v.checked_rem(rhs)).ok_or_else(|| overflow_error_message(self, rhs, "rem"))?)
             },
             Value::Float32(v) => {
                 check_operands(&self, &rhs, "rem", |v| v.is_any_int_or_float())?;
@@ -636,8 +649,8 @@ impl Neg for &Value {
 
     fn neg(self) -> Self::Output {
         Ok(match self {
-            Value::Int(val) => Value::Int(-*val),
-            Value::Int64(val) => Value::Int64(-*val),
+            Value::Int(val) => Value::Int(val.checked_neg().ok_or_else(|| operand_overflow_error_message(self, "neg"))?),
+            Value::Int64(val) => Value::Int64(val.checked_neg().ok_or_else(|| operand_overflow_error_message(self, "neg"))?),
             Value::Float32(val) => Value::Float32(-*val),
             Value::Float(val) => Value::Float(-*val),
             Value::Decimal(val) => Value::Decimal(val.neg()),
@@ -654,11 +667,11 @@ impl Shl for &Value {
         Ok(match self {
             Value::Int(v) => {
                 check_operands(&self, rhs, "shift left", |v| v.is_any_int())?;
-                Value::Int(v << rhs.as_int().unwrap())
+                Value::Int(shift_amount(rhs).and_then(|rhs| v.checked_shl(rhs)).ok_or_else(|| overflow_error_message(self, rhs, "shift left"))?)
             },
             Value::Int64(v) => {
                 check_operands(&self, rhs, "shift left", |v| v.is_any_int())?;
-                Value::Int64(v << rhs.as_int64().unwrap())
+                Value::Int64(shift_amount(rhs).and_then(|rhs| v.checked_shl(rhs)).ok_or_else(|| overflow_error_message(self, rhs, "shift left"))?)
             },
             _ => Err(operand_error_message(self, "shift left"))?,
         })
@@ -673,11 +686,11 @@ impl Shr for &Value {
         Ok(match self {
             Value::Int(v) => {
                 check_operands(&self, rhs, "shift right", |v| v.is_any_int())?;
-                Value::Int(v >> rhs.as_int().unwrap())
+                Value::Int(shift_amount(rhs).and_then(|rhs| v.checked_shr(rhs)).ok_or_else(|| overflow_error_message(self, rhs, "shift right"))?)
             },
             Value::Int64(v) => {
                 check_operands(&self, rhs, "shift right", |v| v.is_any_int())?;
-                Value::Int64(v >> rhs.as_int64().unwrap())
+                Value::Int64(shift_amount(rhs).and_then(|rhs| v.checked_shr(rhs)).ok_or_else(|| overflow_error_message(self, rhs, "shift right"))?)
             },
             _ => Err(operand_error_message(self, "shift right"))?,
         })
@@ -692,11 +705,11 @@ impl BitAnd for &Value {
         Ok(match self {
             Value::Int(v) => {
                 check_operands(&self, rhs, "bitand", |v| v.is_any_int())?;
-                Value::Int(v & rhs.as_int().unwrap())
+                Value::Int(v & rhs.to_int().ok_or_else(|| overflow_error_message(self, rhs, "bitand"))?)
             },
             Value::Int64(v) => {
                 check_operands(&self, rhs, "bitand", |v| v.is_any_int())?;
-                Value::Int64(v & rhs.as_int64().unwrap())
+                Value::Int64(v & rhs.to_int64().unwrap())
             },
             Value::OptionVariant(e) => {
                 check_enum_operands("bitand", self, rhs)?;
@@ -715,11 +728,11 @@ impl BitXor for &Value {
         Ok(match self {
             Value::Int(v) => {
                 check_operands(&self, rhs, "bitxor", |v| v.is_any_int())?;
-                Value::Int(v ^ rhs.as_int().unwrap())
+                Value::Int(v ^ rhs.to_int().ok_or_else(|| overflow_error_message(self, rhs, "bitxor"))?)
             },
             Value::Int64(v) => {
                 check_operands(&self, rhs, "bitxor", |v| v.is_any_int())?;
-                Value::Int64(v ^ rhs.as_int64().unwrap())
+                Value::Int64(v ^ rhs.to_int64().unwrap())
             },
             Value::OptionVariant(e) => {
                 check_enum_operands("bitxor", self, rhs)?;
@@ -738,11 +751,11 @@ impl BitOr for &Value {
         Ok(match self {
             Value::Int(v) => {
                 check_operands(&self, rhs, "bitor", |v| v.is_any_int())?;
-                Value::Int(v | rhs.as_int().unwrap())
+                Value::Int(v | rhs.to_int().ok_or_else(|| overflow_error_message(self, rhs, "bitor"))?)
             },
             Value::Int64(v) => {
                 check_operands(&self, rhs, "bitor", |v| v.is_any_int())?;
-                Value::Int64(v | rhs.as_int64().unwrap())
+                Value::Int64(v | rhs.to_int64().unwrap())
             },
             Value::OptionVariant(e) => {
                 check_enum_operands("bitor", self, rhs)?;
@@ -760,8 +773,8 @@ impl Not for &Value {
 
     fn not(self) -> Self::Output {
         Ok(match self {
-            Value::Int(val) => Value::Int(-*val),
-            Value::Int64(val) => Value::Int64(-*val),
+            Value::Int(val) => Value::Int(val.checked_neg().ok_or_else(|| operand_overflow_error_message(self, "bitneg"))?),
+            Value::Int64(val) => Value::Int64(val.checked_neg().ok_or_else(|| operand_overflow_error_message(self, "bitneg"))?),
             Value::Float32(val) => Value::Float32(-*val),
             Value::Float(val) => Value::Float(-*val),
             Value::Decimal(val) => Value::Decimal(val.neg()),
@@ -0,0 +1,32 @@
+mod test {
+    use maplit::btreemap;
+    use teo_parser::{auto_complete_items, parse, ParseOptions};
+    use teo_parser::utils::path::InMemoryFileSystem;
+
+    #[test]
+    fn completion_lists_schema_files_and_directories() {
+        let file_system = InMemoryFileSystem::new(btreemap! {
+            "/project/schema.teo".to_owned() => "import \"./\"\n".to_owned(),
+            "/project/models.teo".to_owned() => "model User {\n  @id\n  id: Int\n}\n".to_owned(),
+            "/project/enums/role.teo".to_owned() => "enum Role {\n  admin\n}\n".to_owned(),
+            "/project/README.md".to_owned() => "# project\n".to_owned(),
+        });
+        let (schema, _) = parse("/project/schema.teo", ParseOptions::new().file_system(Box::new(file_system)));
+        let completions = auto_complete_items(&schema, "/project/schema.teo", (1, 11));
+        let labels: Vec<&str> = completions.iter().map(|c| c.label.as_str()).collect();
+        assert_eq!(labels, vec!["enums", "models"]);
+    }
+
+    #[test]
+    fn completion_lists_files_in_typed_directory() {
+        let file_system = InMemoryFileSystem::new(btreemap! {
+            "/project/schema.teo".to_owned() => "import \"./enums/\"\n".to_owned(),
+            "/project/enums/role.teo".to_owned() => "enum Role {\n  admin\n}\n".to_owned(),
+            "/project/enums/status.teo".to_owned() => "enum Status {\n  active\n}\n".to_owned(),
+        });
+        let (schema, _) = parse("/project/schema.teo", ParseOptions::new().file_system(Box::new(file_system)));
+        let completions = auto_complete_items(&schema, "/project/schema.teo", (1, 17));
+        let labels: Vec<&str> = completions.iter().map(|c| c.label.as_str()).collect();
+        assert_eq!(labels, vec!["role", "status"]);
+    }
+}
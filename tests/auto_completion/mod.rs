@@ -3,4 +3,5 @@ pub mod decorator;
 pub mod unit;
 pub mod identifier;
 pub mod enum_variant_literal;
-pub mod argument_list;
\ No newline at end of file
+pub mod argument_list;
+pub mod import_path;
\ No newline at end of file
@@ -1,12 +1,12 @@
 mod test {
     use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
-    use teo_parser::parse;
+    use teo_parser::{parse, ParseOptions};
 
     #[test]
     fn errors_if_data_set_group_is_not_found() {
         let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/data_set_groups/schemas/data.teo");
         let path = path_buf.to_str().unwrap();
-        let (_, diagnostics) = parse(path, None, None);
+        let (_, diagnostics) = parse(path, ParseOptions::new());
         diagnostics.errors().iter().for_each(|e| {
             assert_eq!(e.message(), "model not found");
         });
@@ -17,7 +17,7 @@ mod test {
     fn errors_if_parent_file_is_loaded_and_data_set_group_is_not_found() {
         let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/data_set_groups/schemas/schema.teo");
         let path = path_buf.to_str().unwrap();
-        let (_, diagnostics) = parse(path, None, None);
+        let (_, diagnostics) = parse(path, ParseOptions::new());
         diagnostics.errors().iter().for_each(|e| {
             assert_eq!(e.message(), "model not found");
         });
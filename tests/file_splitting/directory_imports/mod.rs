@@ -0,0 +1,92 @@
+mod test {
+    use maplit::btreemap;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::{auto_complete_items, parse, ParseOptions};
+
+    #[test]
+    fn directory_import_loads_every_schema_file() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/directory_imports/schemas/01.teo");
+        let path = path_buf.to_str().unwrap();
+        let (_, diagnostics) = parse(path, ParseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+    }
+
+    #[test]
+    fn glob_import_loads_every_matching_file() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/directory_imports/schemas/02.teo");
+        let path = path_buf.to_str().unwrap();
+        let (schema, diagnostics) = parse(path, ParseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+        let import = schema.main_source().imports().first().unwrap().file_paths.clone();
+        assert_eq!(import.len(), 2);
+        assert!(import[0].ends_with("nested/billing/v1/invoice.teo"));
+        assert!(import[1].ends_with("nested/shop/order.teo"));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn recursive_glob_import_does_not_follow_links_back_to_a_parent() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/directory_imports/schemas/06.teo");
+        let path = path_buf.to_str().unwrap();
+        let (schema, diagnostics) = parse(path, ParseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+        let imports = schema.main_source().imports();
+        let import = imports.first().unwrap();
+        assert_eq!(import.file_paths.len(), 2);
+        assert!(import.file_paths[0].ends_with("linked/inner/label.teo"));
+        assert!(import.file_paths[1].ends_with("linked/tag.teo"));
+        assert_eq!(import.file_path(), import.file_paths[0]);
+    }
+
+    #[test]
+    fn directory_import_includes_unsaved_files() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/directory_imports/schemas/03.teo");
+        let path = path_buf.to_str().unwrap();
+        let (_, diagnostics) = parse(path, ParseOptions::new());
+        assert_eq!(diagnostics.errors().len(), 1);
+        assert_eq!(diagnostics.errors().first().unwrap().message(), "unknown type");
+        let comment_path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/directory_imports/schemas/models/comment.teo");
+        let unsaved_files = btreemap! {
+            comment_path_buf.to_str().unwrap().to_owned() => "interface Comment {\n  content: String\n}\n".to_owned(),
+        };
+        let (_, diagnostics) = parse(path, ParseOptions::new().unsaved_files(unsaved_files));
+        assert_eq!(diagnostics.has_errors(), false);
+    }
+
+    #[test]
+    fn glob_import_without_matches_is_an_error() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/directory_imports/schemas/04.teo");
+        let path = path_buf.to_str().unwrap();
+        let (_, diagnostics) = parse(path, ParseOptions::new());
+        assert_eq!(diagnostics.errors().len(), 1);
+        assert_eq!(diagnostics.errors().first().unwrap().message(), "ImportError: no file matches the pattern");
+    }
+
+    #[test]
+    fn directory_import_with_index_file_loads_index_file_only() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/directory_imports/schemas/05.teo");
+        let path = path_buf.to_str().unwrap();
+        let (schema, diagnostics) = parse(path, ParseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+        let import = schema.main_source().imports().first().unwrap().file_paths.clone();
+        assert_eq!(import.len(), 1);
+        assert!(import[0].ends_with("indexed/index.teo"));
+        let extra_path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/directory_imports/schemas/indexed/extra.teo");
+        assert!(schema.source_at_path(extra_path_buf.to_str().unwrap()).is_none());
+    }
+
+    #[test]
+    fn unsaved_files_are_served_through_the_schema_file_system() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/directory_imports/schemas/03.teo");
+        let path = path_buf.to_str().unwrap();
+        let draft_path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/directory_imports/schemas/draft.teo");
+        let draft_path = draft_path_buf.to_str().unwrap();
+        let unsaved_files = btreemap! {
+            draft_path.to_owned() => "interface Draft {}\n".to_owned(),
+        };
+        let (schema, _) = parse(path, ParseOptions::new().unsaved_files(unsaved_files));
+        assert_eq!(schema.file_system().read(draft_path).unwrap(), "interface Draft {}\n");
+        let completions = auto_complete_items(&schema, path, (1, 11));
+        assert!(completions.iter().any(|item| item.label == "draft"));
+    }
+}
@@ -0,0 +1,56 @@
+mod test {
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::{auto_complete_items, jump_to_definition, parse, ParseOptions};
+
+    #[test]
+    fn aliased_names_are_resolved_without_clashing() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/import_aliases/schemas/01.teo");
+        let path = path_buf.to_str().unwrap();
+        let (_, diagnostics) = parse(path, ParseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+    }
+
+    #[test]
+    fn aliased_names_are_not_reachable_without_alias() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/import_aliases/schemas/02.teo");
+        let path = path_buf.to_str().unwrap();
+        let (_, diagnostics) = parse(path, ParseOptions::new());
+        assert_eq!(diagnostics.errors().len(), 1);
+        assert_eq!(diagnostics.errors().first().unwrap().message(), "unknown type");
+    }
+
+    #[test]
+    fn names_also_imported_without_alias_clash_with_global_ones() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/import_aliases/schemas/03.teo");
+        let path = path_buf.to_str().unwrap();
+        let (_, diagnostics) = parse(path, ParseOptions::new());
+        let errors: Vec<(&str, &str)> = diagnostics.errors().iter().map(|e| (e.message(), e.source_path())).collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, "TypeError: identifier is duplicated");
+        assert!(errors[0].1.ends_with("shop.teo"));
+    }
+
+    #[test]
+    fn jump_to_definition_through_alias() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/import_aliases/schemas/01.teo");
+        let path = path_buf.to_str().unwrap();
+        let (schema, _) = parse(path, ParseOptions::new());
+        let definitions = jump_to_definition(&schema, path, (5, 28));
+        assert_eq!(definitions.len(), 1);
+        assert!(format!("{:?}", definitions.first().unwrap()).contains("billing.teo"));
+        let definitions = jump_to_definition(&schema, path, (9, 36));
+        assert_eq!(definitions.len(), 1);
+        assert!(format!("{:?}", definitions.first().unwrap()).contains("billing.teo"));
+    }
+
+    #[test]
+    fn completion_after_alias() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/import_aliases/schemas/01.teo");
+        let path = path_buf.to_str().unwrap();
+        let (schema, _) = parse(path, ParseOptions::new());
+        let completions = auto_complete_items(&schema, path, (5, 27));
+        assert_eq!(completions.iter().map(|c| c.label.as_str()).collect::<Vec<&str>>(), vec!["Status", "Invoice"]);
+        let completions = auto_complete_items(&schema, path, (5, 19));
+        assert_eq!(completions.iter().find(|c| c.label.as_str() == "billing").is_some(), true);
+    }
+}
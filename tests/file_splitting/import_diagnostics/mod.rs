@@ -0,0 +1,42 @@
+mod test {
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::{parse, ParseOptions};
+
+    #[test]
+    fn missing_import_is_an_error_on_the_import() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/import_diagnostics/schemas/01.teo");
+        let path = path_buf.to_str().unwrap();
+        let (_, diagnostics) = parse(path, ParseOptions::new());
+        assert_eq!(diagnostics.errors().len(), 1);
+        let error = diagnostics.errors().first().unwrap();
+        assert_eq!(error.message(), "ImportError: file doesn't exist");
+        assert_eq!(error.span().start_position, (1, 1));
+        assert_eq!(error.span().end_position, (1, 19));
+    }
+
+    #[test]
+    fn circular_import_is_a_warning_with_the_cycle_path() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/import_diagnostics/schemas/a.teo");
+        let path = path_buf.to_str().unwrap();
+        let (_, diagnostics) = parse(path, ParseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(diagnostics.warnings().len(), 1);
+        let warning = diagnostics.warnings().first().unwrap();
+        assert_eq!(warning.message(), "circular import: a.teo -> b.teo -> c.teo -> a.teo");
+        assert!(warning.source_path().ends_with("c.teo"));
+    }
+
+    #[test]
+    fn import_graph_lists_file_dependencies() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/import_diagnostics/schemas/a.teo");
+        let path = path_buf.to_str().unwrap();
+        let (schema, _) = parse(path, ParseOptions::new());
+        let graph = schema.import_graph();
+        let schemas = std::env::current_dir().unwrap().join("tests/file_splitting/import_diagnostics/schemas");
+        let file = |name: &str| schemas.join(name).to_str().unwrap().to_owned();
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.get(&file("a.teo")).unwrap(), &vec![file("b.teo")]);
+        assert_eq!(graph.get(&file("b.teo")).unwrap(), &vec![file("c.teo")]);
+        assert_eq!(graph.get(&file("c.teo")).unwrap(), &vec![file("a.teo")]);
+    }
+}
@@ -0,0 +1,141 @@
+mod test {
+    use crate::schema_path;
+    use maplit::btreemap;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::{parse, reparse, ParseOptions, ReparseOptions};
+    use teo_parser::traits::named_identifiable::NamedIdentifiable;
+
+    #[test]
+    fn reparse_reuses_sources_of_unchanged_files() {
+        let (schema, diagnostics) = parse(schema_path("file_splitting/incremental_reparse", "01.teo"), ParseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+        let std_source_id = schema.std_source().id;
+        let enums_source_id = schema.source_at_path(&schema_path("file_splitting/incremental_reparse", "enums.teo")).unwrap().id;
+        let models_source_id = schema.source_at_path(&schema_path("file_splitting/incremental_reparse", "models.teo")).unwrap().id;
+        let unsaved_files = btreemap! {
+            schema_path("file_splitting/incremental_reparse", "models.teo") => "import \"./enums\"\n\nconnector {\n  provider: .sqlite,\n  url: \"sqlite::memory:\"\n}\n\nmodel User {\n  @id @autoIncrement\n  id: Int\n  email: String\n  role: Role\n}\n".to_owned(),
+        };
+        let (schema, diagnostics) = reparse(schema, vec![], ReparseOptions::new().unsaved_files(unsaved_files));
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(schema.std_source().id, std_source_id);
+        assert_eq!(schema.source_at_path(&schema_path("file_splitting/incremental_reparse", "enums.teo")).unwrap().id, enums_source_id);
+        assert_ne!(schema.source_at_path(&schema_path("file_splitting/incremental_reparse", "models.teo")).unwrap().id, models_source_id);
+        assert_eq!(schema.sources().len(), 4);
+        assert_eq!(schema.models().len(), 3);
+        let user = schema.models().into_iter().find(|model| model.identifier().name() == "User").unwrap();
+        assert!(user.fields().any(|field| field.name() == "email"));
+    }
+
+    #[test]
+    fn reparse_resolves_changed_files_again() {
+        let (schema, _) = parse(schema_path("file_splitting/incremental_reparse", "01.teo"), ParseOptions::new());
+        let unsaved_files = btreemap! {
+            schema_path("file_splitting/incremental_reparse", "01.teo") => "import \"./models\"\nimport \"./enums\"\n\ninterface Inputs {\n  user: Account\n}\n".to_owned(),
+        };
+        let (schema, diagnostics) = reparse(schema, vec![], ReparseOptions::new().unsaved_files(unsaved_files));
+        assert_eq!(diagnostics.errors().len(), 1);
+        assert_eq!(diagnostics.errors().first().unwrap().message(), "unknown type");
+        let (_, diagnostics) = reparse(schema, vec![schema_path("file_splitting/incremental_reparse", "01.teo")], ReparseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+    }
+
+    #[test]
+    fn reparse_keeps_parser_diagnostics_of_reused_sources() {
+        let (schema, diagnostics) = parse(schema_path("file_splitting/incremental_reparse", "02.teo"), ParseOptions::new());
+        assert_eq!(diagnostics.warnings().len(), 1);
+        let unsaved_files = btreemap! {
+            schema_path("file_splitting/incremental_reparse", "02.teo") => "import \"./comments\"\n\ninterface Filter {\n  status: Status?\n}\n".to_owned(),
+        };
+        let (_, diagnostics) = reparse(schema, vec![], ReparseOptions::new().unsaved_files(unsaved_files));
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(diagnostics.warnings().len(), 1);
+        assert_eq!(diagnostics.warnings().first().unwrap().message(), "unattached doc comment");
+        assert_eq!(diagnostics.warnings().first().unwrap().source_path(), schema_path("file_splitting/incremental_reparse", "comments.teo"));
+    }
+
+    #[test]
+    fn reparse_resolves_imports_of_added_files() {
+        let (schema, diagnostics) = parse(schema_path("file_splitting/incremental_reparse", "03.teo"), ParseOptions::new());
+        assert_eq!(diagnostics.errors().first().unwrap().message(), "ImportError: file doesn't exist");
+        let unsaved_files = btreemap! {
+            schema_path("file_splitting/incremental_reparse", "generated.teo") => "interface Generated {\n  value: String\n}\n".to_owned(),
+        };
+        let (schema, diagnostics) = reparse(schema, vec![], ReparseOptions::new().unsaved_files(unsaved_files));
+        assert_eq!(diagnostics.has_errors(), false);
+        assert!(schema.source_at_path(&schema_path("file_splitting/incremental_reparse", "generated.teo")).is_some());
+    }
+
+    #[test]
+    fn reparse_resolves_sources_importing_changed_files_again() {
+        let (schema, _) = parse(schema_path("file_splitting/incremental_reparse", "01.teo"), ParseOptions::new());
+        let unsaved_files = btreemap! {
+            schema_path("file_splitting/incremental_reparse", "enums.teo") => "enum Kind {\n  admin\n  user\n}\n".to_owned(),
+        };
+        let (_, diagnostics) = reparse(schema, vec![], ReparseOptions::new().unsaved_files(unsaved_files));
+        assert_eq!(diagnostics.errors().len(), 2);
+        assert!(diagnostics.errors().iter().any(|error| error.source_path() == schema_path("file_splitting/incremental_reparse", "01.teo")));
+        assert!(diagnostics.errors().iter().any(|error| error.source_path() == schema_path("file_splitting/incremental_reparse", "models.teo")));
+    }
+
+    #[test]
+    fn reparse_checks_changed_files_against_declarations_of_unchanged_files() {
+        let (schema, _) = parse(schema_path("file_splitting/incremental_reparse", "01.teo"), ParseOptions::new());
+        let unsaved_files = btreemap! {
+            schema_path("file_splitting/incremental_reparse", "01.teo") => "import \"./models\"\nimport \"./enums\"\n\nenum Role {\n  guest\n}\n".to_owned(),
+        };
+        let (_, diagnostics) = reparse(schema, vec![], ReparseOptions::new().unsaved_files(unsaved_files));
+        assert_eq!(diagnostics.errors().len(), 1);
+        assert_eq!(diagnostics.errors().first().unwrap().message(), "TypeError: identifier is duplicated");
+        assert_eq!(diagnostics.errors().first().unwrap().source_path(), schema_path("file_splitting/incremental_reparse", "01.teo"));
+    }
+
+    #[test]
+    fn reparse_keeps_resolver_diagnostics_of_unchanged_files() {
+        let (schema, diagnostics) = parse(schema_path("file_splitting/incremental_reparse", "04.teo"), ParseOptions::new());
+        assert_eq!(diagnostics.errors().len(), 1);
+        let unsaved_files = btreemap! {
+            schema_path("file_splitting/incremental_reparse", "04.teo") => "import \"./broken\"\n\ninterface Wrapper {\n  value: Broken\n}\n".to_owned(),
+        };
+        let (_, diagnostics) = reparse(schema, vec![], ReparseOptions::new().unsaved_files(unsaved_files));
+        assert_eq!(diagnostics.errors().len(), 1);
+        assert_eq!(diagnostics.errors().first().unwrap().message(), "unknown type");
+        assert_eq!(diagnostics.errors().first().unwrap().source_path(), schema_path("file_splitting/incremental_reparse", "broken.teo"));
+    }
+
+    #[test]
+    fn reparse_checks_changed_files_against_builtin_declarations() {
+        let (schema, diagnostics) = parse(schema_path("file_splitting/incremental_reparse", "05.teo"), ParseOptions::new());
+        assert!(diagnostics.errors().iter().any(|error| error.message() == "TypeError: identifier is duplicated"));
+        let unsaved_files = btreemap! {
+            schema_path("file_splitting/incremental_reparse", "05.teo") => "namespace std {\n  declare struct Int {}\n}\n".to_owned(),
+        };
+        let (_, diagnostics) = reparse(schema, vec![], ReparseOptions::new().unsaved_files(unsaved_files));
+        assert!(diagnostics.errors().iter().any(|error| error.message() == "TypeError: identifier is duplicated"));
+    }
+
+    #[test]
+    fn reparse_reports_constants_unused_after_their_last_use_is_removed() {
+        let constants_path = schema_path("file_splitting/incremental_reparse", "constants.teo");
+        let (schema, diagnostics) = parse(schema_path("file_splitting/incremental_reparse", "06.teo"), ParseOptions::new());
+        assert!(!diagnostics.warnings().iter().any(|warning| warning.source_path() == constants_path));
+        let unsaved_files = btreemap! {
+            schema_path("file_splitting/incremental_reparse", "06.teo") => "import \"./constants\"\n\nlet doubled = 20\n".to_owned(),
+        };
+        let (schema, diagnostics) = reparse(schema, vec![], ReparseOptions::new().unsaved_files(unsaved_files));
+        assert!(diagnostics.warnings().iter().any(|warning| warning.source_path() == constants_path && warning.message() == "unused constant"));
+        let (_, diagnostics) = reparse(schema, vec![schema_path("file_splitting/incremental_reparse", "06.teo")], ReparseOptions::new());
+        assert!(!diagnostics.warnings().iter().any(|warning| warning.source_path() == constants_path));
+    }
+
+    #[test]
+    fn reparse_drops_unused_constant_warnings_once_constants_are_used() {
+        let constants_path = schema_path("file_splitting/incremental_reparse", "constants.teo");
+        let unsaved_files = btreemap! {
+            schema_path("file_splitting/incremental_reparse", "06.teo") => "import \"./constants\"\n\nlet doubled = 20\n".to_owned(),
+        };
+        let (schema, diagnostics) = parse(schema_path("file_splitting/incremental_reparse", "06.teo"), ParseOptions::new().unsaved_files(unsaved_files));
+        assert!(diagnostics.warnings().iter().any(|warning| warning.source_path() == constants_path && warning.message() == "unused constant"));
+        let (_, diagnostics) = reparse(schema, vec![schema_path("file_splitting/incremental_reparse", "06.teo")], ReparseOptions::new());
+        assert!(!diagnostics.warnings().iter().any(|warning| warning.source_path() == constants_path));
+    }
+}
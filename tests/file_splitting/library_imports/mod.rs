@@ -0,0 +1,52 @@
+mod test {
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use maplit::btreemap;
+    use teo_parser::{parse, reparse, ParseOptions, ReparseOptions};
+
+    fn library_roots() -> Vec<String> {
+        vec![
+            std::env::current_dir().unwrap().join("tests/file_splitting/library_imports/schemas/vendor").to_str().unwrap().to_owned(),
+            std::env::current_dir().unwrap().join("tests/file_splitting/library_imports/schemas/libs").to_str().unwrap().to_owned(),
+        ]
+    }
+
+    #[test]
+    fn non_relative_import_is_found_in_library_roots() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/library_imports/schemas/01.teo");
+        let path = path_buf.to_str().unwrap();
+        let (_, diagnostics) = parse(path, ParseOptions::new().library_roots(library_roots()));
+        assert_eq!(diagnostics.has_errors(), false);
+    }
+
+    #[test]
+    fn non_relative_import_without_library_roots_is_an_error() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/library_imports/schemas/01.teo");
+        let path = path_buf.to_str().unwrap();
+        let (_, diagnostics) = parse(path, ParseOptions::new());
+        assert_eq!(diagnostics.errors().first().unwrap().message(), "ImportError: file doesn't exist");
+    }
+
+    #[test]
+    fn missing_library_import_lists_searched_roots() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/library_imports/schemas/02.teo");
+        let path = path_buf.to_str().unwrap();
+        let (_, diagnostics) = parse(path, ParseOptions::new().library_roots(library_roots()));
+        assert_eq!(diagnostics.errors().len(), 1);
+        assert_eq!(diagnostics.errors().first().unwrap().message(), format!("ImportError: file doesn't exist, searched library roots: {}", library_roots().join(", ")));
+    }
+
+    #[test]
+    fn reparse_keeps_library_roots() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/library_imports/schemas/01.teo");
+        let path = path_buf.to_str().unwrap();
+        let (schema, _) = parse(path, ParseOptions::new().library_roots(library_roots()));
+        assert_eq!(schema.library_roots(), &library_roots());
+        let unsaved_files = btreemap! {
+            path.to_owned() => "import \"@acme/auth\"\n\ninterface SignUpInput {\n  credentials: Credentials\n}\n".to_owned(),
+        };
+        let (schema, diagnostics) = reparse(schema, vec![], ReparseOptions::new().unsaved_files(unsaved_files));
+        assert_eq!(diagnostics.has_errors(), false);
+        let (_, diagnostics) = reparse(schema, vec![], ReparseOptions::new().library_roots(vec![]));
+        assert_eq!(diagnostics.errors().first().unwrap().message(), "ImportError: file doesn't exist");
+    }
+}
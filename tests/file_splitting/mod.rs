@@ -1 +1,7 @@
-pub mod data_set_groups;
\ No newline at end of file
+pub mod data_set_groups;
+pub mod directory_imports;
+pub mod import_aliases;
+pub mod import_diagnostics;
+pub mod incremental_reparse;
+pub mod library_imports;
+pub mod selective_imports;
\ No newline at end of file
@@ -0,0 +1,30 @@
+mod test {
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::{parse, ParseOptions};
+
+    #[test]
+    fn imported_names_are_resolved() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/selective_imports/schemas/01.teo");
+        let path = path_buf.to_str().unwrap();
+        let (_, diagnostics) = parse(path, ParseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+    }
+
+    #[test]
+    fn names_not_imported_are_undefined() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/selective_imports/schemas/02.teo");
+        let path = path_buf.to_str().unwrap();
+        let (_, diagnostics) = parse(path, ParseOptions::new());
+        assert_eq!(diagnostics.errors().len(), 1);
+        assert_eq!(diagnostics.errors().first().unwrap().message(), "unknown type");
+    }
+
+    #[test]
+    fn importing_undefined_name_is_an_error() {
+        let path_buf = std::env::current_dir().unwrap().join("tests/file_splitting/selective_imports/schemas/03.teo");
+        let path = path_buf.to_str().unwrap();
+        let (_, diagnostics) = parse(path, ParseOptions::new());
+        assert_eq!(diagnostics.errors().len(), 1);
+        assert_eq!(diagnostics.errors().first().unwrap().message(), "ImportError: 'Author' is not found in imported file");
+    }
+}
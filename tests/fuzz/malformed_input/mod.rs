@@ -0,0 +1,96 @@
+mod test {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use maplit::btreemap;
+    use teo_parser::ast::schema::Schema;
+    use teo_parser::{auto_complete_items, format_document, jump_to_definition, parse_str, reparse, ParseOptions, ReparseOptions};
+
+    const PATH: &str = "/virtual/schema.teo";
+
+    fn corpus() -> Vec<(String, String)> {
+        let directory = std::env::current_dir().unwrap().join("tests/fuzz/malformed_input/schemas");
+        let mut paths: Vec<_> = std::fs::read_dir(directory).unwrap().map(|entry| entry.unwrap().path()).collect();
+        paths.sort();
+        paths.into_iter().map(|path| (path.file_name().unwrap().to_str().unwrap().to_owned(), std::fs::read_to_string(&path).unwrap())).collect()
+    }
+
+    // std is parsed once, every document reparses the virtual file only. A panic while parsing,
+    // formatting or querying the document is recorded as a failure.
+    fn check_document(schema: Schema, label: String, content: &str, line_cols: &[(usize, usize)], failures: &mut Vec<String>) -> Schema {
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let (schema, _) = reparse(schema, vec![], ReparseOptions::new().unsaved_files(btreemap! { PATH.to_owned() => content.to_owned() }));
+            let _ = format_document(&schema, PATH);
+            for line_col in line_cols {
+                let _ = jump_to_definition(&schema, PATH, *line_col);
+                let _ = auto_complete_items(&schema, PATH, *line_col);
+            }
+            schema
+        }));
+        match result {
+            Ok(schema) => schema,
+            Err(_) => {
+                failures.push(label);
+                parse_str("", PATH, ParseOptions::new()).0
+            }
+        }
+    }
+
+    #[test]
+    fn corpus_is_parsed_without_panic() {
+        let mut schema = parse_str("", PATH, ParseOptions::new()).0;
+        let mut failures = vec![];
+        for (name, content) in corpus() {
+            let line_cols: Vec<(usize, usize)> = content.split('\n').enumerate().flat_map(|(index, line)| {
+                (1..=line.chars().count() + 2).map(move |column| (index + 1, column))
+            }).collect();
+            schema = check_document(schema, name, &content, &line_cols, &mut failures);
+        }
+        assert!(failures.is_empty(), "panicked on {:?}", failures);
+    }
+
+    #[test]
+    fn truncated_corpus_is_parsed_without_panic() {
+        let mut schema = parse_str("", PATH, ParseOptions::new()).0;
+        let mut failures = vec![];
+        for (name, content) in corpus() {
+            let line_ends = content.match_indices('\n').map(|(index, _)| index);
+            let middles = content.split('\n').scan(0, |start, line| {
+                let middle = *start + line.char_indices().nth(line.chars().count() / 2).map_or(0, |(index, _)| index);
+                *start += line.len() + 1;
+                Some(middle)
+            });
+            for end in line_ends.chain(middles) {
+                let truncated = &content[..end];
+                let line = truncated.split('\n').count();
+                let column = truncated.split('\n').last().unwrap().chars().count() + 1;
+                schema = check_document(schema, format!("{name} truncated at {end}"), truncated, &[(line, column)], &mut failures);
+            }
+        }
+        assert!(failures.is_empty(), "panicked on {:?}", failures);
+    }
+
+    #[test]
+    fn mutated_corpus_is_parsed_without_panic() {
+        let tokens = [".", "(", ")", "\"", "{", "}", ":", "@", "$", "?", "[", "]", "<", ">", ",", "#", "=", "|", "!", "/", "\n", "1", "_", "\\", "\\u{"];
+        let mut seed: u64 = 0x2545f4914f6cdd1d;
+        let mut random = |bound: usize| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % bound as u64) as usize
+        };
+        let mut schema = parse_str("", PATH, ParseOptions::new()).0;
+        let mut failures = vec![];
+        for (name, content) in corpus() {
+            let boundaries: Vec<usize> = content.char_indices().map(|(index, _)| index).chain([content.len()]).collect();
+            for _ in 0..20 {
+                let at = boundaries[random(boundaries.len())];
+                let token = tokens[random(tokens.len())];
+                let mutated = format!("{}{}{}", &content[..at], token, &content[at..]);
+                let line = mutated[..at].split('\n').count();
+                let column = mutated[..at].split('\n').last().unwrap().chars().count() + 1;
+                schema = check_document(schema, format!("{name} with {token:?} inserted at {at}"), &mutated, &[(line, column)], &mut failures);
+            }
+        }
+        assert!(failures.is_empty(), "panicked on {:?}", failures);
+    }
+}
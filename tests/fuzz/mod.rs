@@ -0,0 +1 @@
+pub mod malformed_input;
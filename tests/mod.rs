@@ -3,13 +3,20 @@ pub mod jump_to_definition;
 pub mod auto_completion;
 pub mod format;
 pub mod file_splitting;
+pub mod fuzz;
+
+/// The absolute path of the schema file `name` in the `schemas` directory of the test module at
+/// `directory`, which is relative to `tests`.
+pub fn schema_path(directory: &str, name: &str) -> String {
+    std::env::current_dir().unwrap().join("tests").join(directory).join("schemas").join(name).to_str().unwrap().to_owned()
+}
 
 mod test {
 
     // #[test]
     // fn test_jump_to_definition() {
     //     let path = "/Users/victor/Developer/teo-namespace-example/part.teo";
-    //     let (schema, _) = parse(path, None, None);
+    //     let (schema, _) = parse(path, ParseOptions::new());
     //     let definitions = jump_to_definition(&schema, path, (8, 17));
     // }
     //
@@ -19,7 +26,7 @@ mod test {
     //     println!("test formatting start");
     //     // unsafe { backtrace_on_stack_overflow::enable() };
     //     let path = "/Users/victor/Developer/hello-teo/schema.teo";
-    //     let (schema, _) = parse(path, None, None);
+    //     let (schema, _) = parse(path, ParseOptions::new());
     //     let _result = format_document(&schema, "/Users/victor/Developer/hello-teo/schema.teo");
     // }
 }
\ No newline at end of file
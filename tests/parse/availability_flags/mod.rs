@@ -0,0 +1,45 @@
+mod test {
+    use crate::schema_path;
+    use teo_parser::availability::Availability;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::{format_document, parse, ParseOptions};
+    use teo_parser::traits::has_availability::HasAvailability;
+
+    #[test]
+    fn boolean_expressions_and_else_branches_define_availability() {
+        let (schema, diagnostics) = parse(schema_path("parse/availability_flags", "01.teo"), ParseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+        let models: Vec<_> = schema.models().into_iter().filter(|model| ["Account", "Log"].contains(&model.identifier().name())).collect();
+        let if_branch = models.get(0).unwrap();
+        let else_branch = models.get(1).unwrap();
+        let log = models.get(2).unwrap();
+        assert_eq!(if_branch.define_availability(), Availability::mysql().bi_or(Availability::postgres()));
+        assert_eq!(else_branch.define_availability(), Availability::mysql().bi_or(Availability::postgres()).bi_not());
+        assert_eq!(log.identifier().name(), "Log");
+        assert_eq!(log.define_availability(), Availability::mongo().bi_not().bi_and(Availability::sqlite().bi_not()));
+        assert!(if_branch.is_available());
+        assert!(!else_branch.is_available());
+        assert!(log.is_available());
+    }
+
+    #[test]
+    fn unreachable_unknown_and_unbalanced_flags_are_errors() {
+        let (_, diagnostics) = parse(schema_path("parse/availability_flags", "02.teo"), ParseOptions::new());
+        let messages: Vec<&str> = diagnostics.errors().iter().map(|error| error.message()).collect();
+        assert_eq!(messages, vec![
+            "unreachable availability flag",
+            "unknown availability flag",
+            "unbalanced availability else",
+        ]);
+    }
+
+    #[test]
+    fn formatter_keeps_boolean_expressions_and_else_branches() {
+        let path = schema_path("parse/availability_flags", "01.teo");
+        let (schema, _) = parse(&path, ParseOptions::new());
+        let formatted = format_document(&schema, &path);
+        assert!(formatted.contains("#if available(mysql || postgres)\n"));
+        assert!(formatted.contains("#else\n"));
+        assert!(formatted.contains("#if available(!mongo && !sqlite)\n"));
+    }
+}
@@ -0,0 +1,70 @@
+mod test {
+    use crate::schema_path;
+    use indexmap::indexmap;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::diagnostics::printer::print_diagnostics;
+    use teo_parser::{parse, ParseOptions};
+    use teo_parser::value::option_variant::OptionVariant;
+    use teo_parser::value::range::Range;
+    use teo_parser::value::Value;
+
+    #[test]
+    fn constants_are_evaluated() {
+        let (schema, diagnostics) = parse(schema_path("parse/constant_evaluation", "01.teo"), ParseOptions::new());
+        print_diagnostics(&diagnostics, true);
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(schema.evaluate_constant(&vec!["ratio"]), Some(Value::Float(1250.0)));
+        assert_eq!(schema.evaluate_constant(&vec!["window"]), Some(Value::Range(Range {
+            closed: true,
+            start: Box::new(Value::Int(1)),
+            end: Box::new(Value::Int(5000)),
+        })));
+        assert_eq!(schema.evaluate_constant(&vec!["limits"]), Some(Value::Dictionary(indexmap! {
+            "daily".to_owned() => Value::Int(10000),
+            "hours".to_owned() => Value::Array(vec![Value::Int(8), Value::Int(18)]),
+            "owner".to_owned() => Value::Dictionary(indexmap! { "name".to_owned() => Value::String("admin".to_owned()) }),
+        })));
+        assert_eq!(schema.evaluate_constant(&vec!["daily"]), Some(Value::Int(10000)));
+        assert_eq!(schema.evaluate_constant(&vec!["closing"]), Some(Value::Int(18)));
+        assert_eq!(schema.evaluate_constant(&vec!["owner"]), Some(Value::String("admin".to_owned())));
+        assert_eq!(schema.evaluate_constant(&vec!["enabled"]), Some(Value::Bool(true)));
+        assert!(matches!(schema.evaluate_constant(&vec!["permissions"]), Some(Value::OptionVariant(OptionVariant { value: 3, .. }))));
+        assert_eq!(schema.evaluate_constant(&vec!["settings", "timeout"]), Some(Value::Int(2)));
+        assert_eq!(schema.evaluate_constant(&vec!["missing"]), None);
+    }
+
+    #[test]
+    fn config_items_are_evaluated() {
+        let (schema, _) = parse(schema_path("parse/constant_evaluation", "01.teo"), ParseOptions::new());
+        let items = schema.server().unwrap().evaluated_items();
+        assert_eq!(items, indexmap! {
+            "bind".to_owned() => Value::Tuple(vec![Value::String("0.0.0.0".to_owned()), Value::Int(5080)]),
+            "pathPrefix".to_owned() => Value::String("/api".to_owned()),
+        });
+    }
+
+    #[test]
+    fn division_by_zero_is_reported() {
+        let (schema, diagnostics) = parse(schema_path("parse/constant_evaluation", "02.teo"), ParseOptions::new());
+        let messages: Vec<&str> = diagnostics.errors().iter().map(|e| e.message()).collect();
+        assert_eq!(messages, vec!["division by zero"]);
+        assert_eq!(schema.evaluate_constant(&vec!["broken"]), None);
+    }
+
+    #[test]
+    fn integer_overflow_is_reported() {
+        let (schema, diagnostics) = parse(schema_path("parse/constant_evaluation", "03.teo"), ParseOptions::new());
+        let lines: Vec<(&str, usize)> = diagnostics.errors().iter().map(|e| (e.message(), e.span().start_position.0)).collect();
+        assert_eq!(lines, vec![
+            ("ValueError: result is out of range of Int", 4),
+            ("ValueError: result is out of range of Int", 5),
+            ("ValueError: result is out of range of Int", 6),
+            ("ValueError: result is out of range of Int", 7),
+            ("ValueError: result is out of range of Int", 8),
+        ]);
+        assert_eq!(schema.evaluate_constant(&vec!["added"]), None);
+        assert_eq!(schema.evaluate_constant(&vec!["divided"]), None);
+        assert_eq!(schema.evaluate_constant(&vec!["shiftedByInt64"]), Some(Value::Int(8)));
+        assert_eq!(schema.evaluate_constant(&vec!["fits"]), Some(Value::Int(2147483646)));
+    }
+}
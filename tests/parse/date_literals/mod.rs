@@ -0,0 +1,62 @@
+mod test {
+    use crate::schema_path;
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use teo_parser::ast::schema::Schema;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::diagnostics::printer::print_diagnostics;
+    use teo_parser::{format_document, parse, ParseOptions};
+    use teo_parser::r#type::Type;
+    use teo_parser::traits::resolved::Resolve;
+    use teo_parser::value::Value;
+
+    fn constant<'a>(schema: &'a Schema, name: &str) -> (&'a Type, &'a Value) {
+        let node = schema.main_source().children().into_iter().find(|n| n.as_constant_declaration().map_or(false, |c| c.identifier().name() == name)).unwrap();
+        let resolved = node.as_constant_declaration().unwrap().resolved();
+        (resolved.r#type(), resolved.value().unwrap())
+    }
+
+    #[test]
+    fn date_literals_are_resolved() {
+        let (schema, diagnostics) = parse(schema_path("parse/date_literals", "01.teo"), ParseOptions::new());
+        print_diagnostics(&diagnostics, true);
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(constant(&schema, "launch"), (&Type::Date, &Value::Date(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())));
+        assert_eq!(constant(&schema, "released"), (&Type::DateTime, &Value::DateTime(Utc.with_ymd_and_hms(2024, 3, 15, 8, 30, 0).unwrap())));
+        assert_eq!(constant(&schema, "shifted").1.as_datetime().unwrap().to_rfc3339(), "2024-03-15T06:30:00.250+00:00");
+        assert_eq!(constant(&schema, "midnight"), (&Type::DateTime, &Value::DateTime(Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap())));
+    }
+
+    #[test]
+    fn date_literals_are_used_as_defaults_and_record_values() {
+        let (schema, _) = parse(schema_path("parse/date_literals", "01.teo"), ParseOptions::new());
+        let model = schema.models().into_iter().find(|m| m.identifier().name() == "Event").unwrap();
+        let default = model.fields().find(|f| f.identifier().name() == "startsOn").unwrap().decorators().next().unwrap();
+        let argument = default.argument_list().unwrap().arguments().next().unwrap();
+        assert_eq!(argument.value().resolved().r#type(), &Type::Date);
+        let record = schema.data_sets().first().unwrap().groups().next().unwrap().records().next().unwrap();
+        let ends_at = record.dictionary().expressions().last().unwrap().value().resolved();
+        assert_eq!(ends_at.value(), Some(&Value::DateTime(Utc.with_ymd_and_hms(2024, 3, 1, 18, 0, 0).unwrap())));
+    }
+
+    #[test]
+    fn invalid_dates_are_reported() {
+        let (_, diagnostics) = parse(schema_path("parse/date_literals", "02.teo"), ParseOptions::new());
+        let messages: Vec<&str> = diagnostics.errors().iter().map(|e| e.message()).collect();
+        assert_eq!(messages, vec![
+            "invalid date",
+            "invalid date",
+            "invalid datetime",
+            "expect Date, found DateTime",
+        ]);
+    }
+
+    #[test]
+    fn formatter_writes_date_literals() {
+        let path = schema_path("parse/date_literals", "01.teo");
+        let (schema, _) = parse(&path, ParseOptions::new());
+        let formatted = format_document(&schema, &path);
+        assert!(formatted.contains("let launch = @2024-01-01\n"));
+        assert!(formatted.contains("let shifted = @2024-03-15T08:30:00.250+02:00\n"));
+        assert!(formatted.contains("@default(@2024-01-01T12:00:00Z)"));
+    }
+}
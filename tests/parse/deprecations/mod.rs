@@ -0,0 +1,21 @@
+mod test {
+    use crate::schema_path;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::diagnostics::printer::print_diagnostics;
+    use teo_parser::{parse, ParseOptions};
+
+    #[test]
+    fn usages_of_deprecated_declarations_are_warned() {
+        let (_, diagnostics) = parse(schema_path("parse/deprecations", "01.teo"), ParseOptions::new());
+        print_diagnostics(&diagnostics, true);
+        assert_eq!(diagnostics.has_errors(), false);
+        let warnings: Vec<(&str, (usize, usize))> = diagnostics.warnings().iter().map(|w| (w.message(), w.span().start_position)).collect();
+        assert_eq!(warnings, vec![
+            ("Level is deprecated: use Role instead", (32, 10)),
+            ("nickname is deprecated: use name instead", (26, 10)),
+            ("guest is deprecated", (30, 13)),
+            ("legacyTrim is deprecated", (33, 11)),
+            ("track is deprecated: use @audit instead", (39, 2)),
+        ]);
+    }
+}
@@ -0,0 +1,32 @@
+mod test {
+    use teo_parser::ast::schema::Schema;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::{parse_str, ParseOptions};
+    use teo_parser::r#type::Type;
+    use teo_parser::traits::resolved::Resolve;
+    use teo_parser::value::Value;
+
+    fn constant<'a>(schema: &'a Schema, name: &str) -> (&'a Type, Option<&'a Value>) {
+        let node = schema.main_source().children().into_iter().find(|n| n.as_constant_declaration().map_or(false, |c| c.identifier().name() == name)).unwrap();
+        let resolved = node.as_constant_declaration().unwrap().resolved();
+        (resolved.r#type(), resolved.value())
+    }
+
+    #[test]
+    fn division_is_parsed() {
+        let source = "let ratio = 10 / 4 // integer division\nlet half = 5.0 / 2\n";
+        let (schema, diagnostics) = parse_str(source, "/virtual/schema.teo", ParseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(constant(&schema, "ratio"), (&Type::Int, Some(&Value::Int(2))));
+        assert_eq!(constant(&schema, "half"), (&Type::Float, Some(&Value::Float(2.5))));
+    }
+
+    #[test]
+    fn tuple_elements_are_accessed_by_index() {
+        let source = "let pair = (1, \"one\")\nlet first = pair.0\nlet second = pair.1\n";
+        let (schema, diagnostics) = parse_str(source, "/virtual/schema.teo", ParseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(constant(&schema, "first"), (&Type::Int, Some(&Value::Int(1))));
+        assert_eq!(constant(&schema, "second"), (&Type::String, Some(&Value::String("one".to_owned()))));
+    }
+}
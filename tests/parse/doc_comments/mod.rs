@@ -0,0 +1,61 @@
+mod test {
+    use crate::schema_path;
+    use teo_parser::ast::doc_comment::DocComment;
+    use teo_parser::ast::schema::Schema;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::diagnostics::printer::print_diagnostics;
+    use teo_parser::{format_document, parse, ParseOptions};
+
+    fn field_comment<'a>(schema: &'a Schema, field: &str) -> Option<&'a DocComment> {
+        let model = schema.models().into_iter().find(|m| m.identifier().name() == "User").unwrap();
+        model.fields().find(|f| f.identifier().name() == field).unwrap().comment()
+    }
+
+    #[test]
+    fn doc_tags_are_parsed() {
+        let (schema, diagnostics) = parse(schema_path("parse/doc_comments", "01.teo"), ParseOptions::new());
+        print_diagnostics(&diagnostics, true);
+        assert_eq!(diagnostics.has_errors(), false);
+        let email = field_comment(&schema, "email").unwrap();
+        assert_eq!(email.name(), Some("Email"));
+        assert_eq!(email.desc(), Some("The login email"));
+        assert_eq!(email.since(), Some("0.3"));
+        assert_eq!(email.see(), &vec!["User.name".to_owned()]);
+        assert!(!email.is_deprecated());
+        assert_eq!(field_comment(&schema, "name").unwrap().deprecated(), Some("use email instead"));
+        let declaration = schema.decorator_declarations().into_iter().find(|d| d.identifier().name() == "welcome").unwrap();
+        let comment = declaration.comment().unwrap();
+        assert_eq!(comment.desc(), Some("Sends a welcome mail"));
+        assert_eq!(comment.param("user"), Some("the recipient"));
+        assert_eq!(comment.param("template"), Some(""));
+        assert_eq!(comment.examples(), &vec!["welcome(user: 1)\nwelcome(user: 2, template: \"short\")".to_owned()]);
+        assert_eq!(comment.deprecated(), Some(""));
+    }
+
+    #[test]
+    fn formatter_writes_block_comments_and_doc_tags() {
+        let path = schema_path("parse/doc_comments", "01.teo");
+        let (schema, _) = parse(&path, ParseOptions::new());
+        let formatted = format_document(&schema, &path);
+        assert!(formatted.contains("/* the main\n   user model */\n"));
+        assert!(formatted.contains("id: Int /* primary key */\n"));
+        assert!(formatted.contains("/// @param user the recipient\n/// @param template\n/// @example\n/// welcome(user: 1)\n"));
+        assert!(formatted.contains("/// @deprecated use email instead\n"));
+    }
+
+    #[test]
+    fn block_comments_are_allowed_in_argument_lists() {
+        let path = schema_path("parse/doc_comments", "02.teo");
+        let (schema, diagnostics) = parse(&path, ParseOptions::new());
+        print_diagnostics(&diagnostics, true);
+        assert_eq!(diagnostics.has_errors(), false);
+        let model = schema.models().into_iter().find(|m| m.identifier().name() == "User").unwrap();
+        let arguments = model.decorators().next().unwrap().argument_list().unwrap();
+        let values: Vec<String> = arguments.arguments().map(|a| a.value().to_string()).collect();
+        assert_eq!(values, vec!["\"user\"", "\"red\""]);
+        let formatted = format_document(&schema, &path);
+        assert!(formatted.contains("/* table */"));
+        assert!(formatted.contains("/* shown in admin */"));
+        assert!(formatted.contains("// highlighted"));
+    }
+}
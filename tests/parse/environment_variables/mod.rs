@@ -0,0 +1,84 @@
+mod test {
+    use crate::schema_path;
+    use maplit::btreemap;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::utils::environment::Environment;
+    use teo_parser::value::Value;
+    use teo_parser::{auto_complete_items, parse, reparse, ParseOptions, ReparseOptions};
+
+    fn environment() -> Environment {
+        Environment::Variables(btreemap! {
+            "DATABASE_URL".to_owned() => "postgres://localhost:5432/app".to_owned(),
+            "JWT_SECRET".to_owned() => "secret".to_owned(),
+        })
+    }
+
+    #[test]
+    fn injected_variables_are_evaluated() {
+        let (schema, diagnostics) = parse(schema_path("parse/environment_variables", "01.teo"), ParseOptions::new().environment(environment()));
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(schema.evaluate_constant(&vec!["databaseUrl"]), Some(Value::String("postgres://localhost:5432/app".to_owned())));
+        assert_eq!(schema.evaluate_constant(&vec!["secret"]), Some(Value::String("secret".to_owned())));
+        assert_eq!(schema.evaluate_constant(&vec!["region"]), None);
+        let items = schema.connectors().first().unwrap().evaluated_items();
+        assert_eq!(items.get("url"), Some(&Value::String("postgres://localhost:5432/app".to_owned())));
+    }
+
+    #[test]
+    fn variables_are_read_from_dot_env_files() {
+        let (schema, _) = parse(schema_path("parse/environment_variables", "01.teo"), ParseOptions::new().environment(Environment::DotEnvFile("01.env".to_owned())));
+        assert_eq!(schema.environment().unwrap().len(), 2);
+        assert_eq!(schema.evaluate_constant(&vec!["databaseUrl"]), Some(Value::String("postgres://localhost:5432/app".to_owned())));
+        assert_eq!(schema.evaluate_constant(&vec!["secret"]), Some(Value::String("secret".to_owned())));
+        let (schema, _) = reparse(schema, vec![schema_path("parse/environment_variables", "01.teo")], ReparseOptions::new());
+        assert_eq!(schema.evaluate_constant(&vec!["secret"]), Some(Value::String("secret".to_owned())));
+    }
+
+    #[test]
+    fn changed_dot_env_files_are_read_again_on_reparse() {
+        let dot_env = schema_path("parse/environment_variables", "01.env");
+        let (schema, _) = parse(schema_path("parse/environment_variables", "01.teo"), ParseOptions::new().environment(Environment::DotEnvFile("01.env".to_owned())));
+        let (schema, diagnostics) = reparse(schema, vec![], ReparseOptions::new().unsaved_files(btreemap! {
+            dot_env.clone() => "JWT_SECRET=changed\nREGION=eu\n".to_owned(),
+        }));
+        assert_eq!(schema.environment().unwrap().len(), 2);
+        assert_eq!(schema.evaluate_constant(&vec!["secret"]), Some(Value::String("changed".to_owned())));
+        assert_eq!(schema.evaluate_constant(&vec!["region"]), Some(Value::String("eu".to_owned())));
+        assert!(diagnostics.warnings().iter().any(|w| w.message() == "undefined environment variable 'DATABASE_URL'"));
+        let (schema, diagnostics) = reparse(schema, vec![dot_env], ReparseOptions::new());
+        assert_eq!(schema.evaluate_constant(&vec!["secret"]), Some(Value::String("secret".to_owned())));
+        assert!(!diagnostics.warnings().iter().any(|w| w.message() == "undefined environment variable 'DATABASE_URL'"));
+    }
+
+    #[test]
+    fn undefined_variables_are_warned() {
+        let (_, diagnostics) = parse(schema_path("parse/environment_variables", "01.teo"), ParseOptions::new().environment(environment()));
+        let messages: Vec<&str> = diagnostics.warnings().iter().map(|w| w.message()).collect();
+        assert!(messages.contains(&"undefined environment variable 'REGION'"));
+        let (schema, diagnostics) = parse(schema_path("parse/environment_variables", "01.teo"), ParseOptions::new());
+        assert!(!diagnostics.warnings().iter().any(|w| w.message().starts_with("undefined environment variable")));
+        assert_eq!(schema.evaluate_constant(&vec!["secret"]), None);
+    }
+
+    #[test]
+    fn variable_names_are_completed_in_subscripts() {
+        let path = schema_path("parse/environment_variables", "01.teo");
+        let (schema, _) = parse(&path, ParseOptions::new().environment(environment()));
+        let completions = auto_complete_items(&schema, &path, (11, 20));
+        let labels: Vec<&str> = completions.iter().map(|c| c.label.as_str()).collect();
+        assert_eq!(labels, vec!["DATABASE_URL", "JWT_SECRET"]);
+        let path = schema_path("parse/environment_variables", "02.teo");
+        let (schema, _) = parse(&path, ParseOptions::new().environment(environment()));
+        let completions = auto_complete_items(&schema, &path, (1, 19));
+        let labels: Vec<&str> = completions.iter().map(|c| c.label.as_str()).collect();
+        assert_eq!(labels, vec!["\"DATABASE_URL\"", "\"JWT_SECRET\""]);
+    }
+
+    #[test]
+    fn unreadable_dot_env_files_are_reported() {
+        let (schema, diagnostics) = parse(schema_path("parse/environment_variables", "01.teo"), ParseOptions::new().environment(Environment::DotEnvFile("missing.env".to_owned())));
+        let messages: Vec<&str> = diagnostics.errors().iter().map(|e| e.message()).collect();
+        assert_eq!(messages, vec![format!("cannot read environment file {}", schema_path("parse/environment_variables", "missing.env"))]);
+        assert!(schema.environment().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,52 @@
+mod test {
+    use crate::schema_path;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::diagnostics::printer::print_diagnostics;
+    use teo_parser::{auto_complete_items, format_document, parse, ParseOptions};
+
+    #[test]
+    fn escaped_and_unicode_identifiers_are_parsed() {
+        let (schema, diagnostics) = parse(schema_path("parse/escaped_identifiers", "01.teo"), ParseOptions::new());
+        print_diagnostics(&diagnostics, true);
+        assert_eq!(diagnostics.has_errors(), false);
+        let model = schema.models().into_iter().find(|m| m.identifier().name() == "Record").unwrap();
+        let names: Vec<&str> = model.fields().map(|f| f.identifier().name()).collect();
+        assert_eq!(names, vec!["id", "kind", "type", "from", "名前", "model"]);
+        let field = model.fields().find(|f| f.identifier().name() == "type").unwrap();
+        assert!(field.identifier().is_escaped());
+        let r#enum = schema.enums().into_iter().find(|e| e.identifier().name() == "Kind").unwrap();
+        assert_eq!(r#enum.members().map(|m| m.identifier().name()).collect::<Vec<_>>(), vec!["type", "first name"]);
+    }
+
+    #[test]
+    fn escapes_are_completed() {
+        let path = schema_path("parse/escaped_identifiers", "01.teo");
+        let (schema, _) = parse(&path, ParseOptions::new());
+        let completions = auto_complete_items(&schema, &path, (14, 13));
+        let labels: Vec<&str> = completions.iter().map(|c| c.label.as_str()).collect();
+        assert_eq!(labels, vec!["`type`", "`first name`"]);
+        let completions = auto_complete_items(&schema, &path, (29, 20));
+        assert!(completions.iter().any(|c| c.label.as_str() == "größe"));
+    }
+
+    #[test]
+    fn formatter_keeps_escapes() {
+        let path = schema_path("parse/escaped_identifiers", "01.teo");
+        let (schema, _) = parse(&path, ParseOptions::new());
+        let formatted = format_document(&schema, &path);
+        assert!(formatted.contains("    `type`: String\n"));
+        assert!(formatted.contains("    名前: String\n"));
+        assert!(formatted.contains("@default(.`first name`)"));
+    }
+
+    #[test]
+    fn mapped_names_are_unescaped() {
+        let (schema, diagnostics) = parse(schema_path("parse/escaped_identifiers", "02.teo"), ParseOptions::new());
+        print_diagnostics(&diagnostics, true);
+        assert_eq!(diagnostics.has_errors(), false);
+        let model = schema.models().into_iter().find(|m| m.identifier().name() == "Record").unwrap();
+        assert_eq!(model.table_name(), "records");
+        let columns: Vec<&str> = model.fields().map(|f| f.column_name()).collect();
+        assert_eq!(columns, vec!["id", "type", "from_address"]);
+    }
+}
@@ -2,13 +2,13 @@ mod test {
     use teo_parser::ast::span::Span;
     use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
     use teo_parser::diagnostics::printer::print_diagnostics;
-    use teo_parser::parse;
+    use teo_parser::{parse, ParseOptions};
 
     #[test]
     fn self_get_correct_field_type_should_be_no_errors() {
         let path_buf = std::env::current_dir().unwrap().join("tests/parse/field_type/schemas/01.teo");
         let path = path_buf.to_str().unwrap();
-        let (_, diagnostics) = parse(path, None, None);
+        let (_, diagnostics) = parse(path, ParseOptions::new());
         print_diagnostics(&diagnostics, true);
         assert_eq!(diagnostics.has_errors(), false);
         assert_eq!(diagnostics.has_warnings(), false);
@@ -18,7 +18,7 @@ mod test {
     fn self_get_incorrect_existing_field_type_should_be_a_type_error() {
         let path_buf = std::env::current_dir().unwrap().join("tests/parse/field_type/schemas/02.teo");
         let path = path_buf.to_str().unwrap();
-        let (_, diagnostics) = parse(path, None, None);
+        let (_, diagnostics) = parse(path, ParseOptions::new());
         assert_eq!(diagnostics.has_errors(), true);
         assert_eq!(diagnostics.has_warnings(), false);
         let error = diagnostics.errors().first().unwrap();
@@ -35,7 +35,7 @@ mod test {
     fn self_get_incorrect_unexisting_field_type_should_be_a_hint_error_and_a_type_error() {
         let path_buf = std::env::current_dir().unwrap().join("tests/parse/field_type/schemas/03.teo");
         let path = path_buf.to_str().unwrap();
-        let (_, diagnostics) = parse(path, None, None);
+        let (_, diagnostics) = parse(path, ParseOptions::new());
         print_diagnostics(&diagnostics, true);
         assert_eq!(diagnostics.has_errors(), true);
         assert_eq!(diagnostics.has_warnings(), false);
@@ -50,7 +50,7 @@ mod test {
     fn self_set_incorrect_existing_field_type_should_be_a_hint_error() {
         let path_buf = std::env::current_dir().unwrap().join("tests/parse/field_type/schemas/04.teo");
         let path = path_buf.to_str().unwrap();
-        let (_, diagnostics) = parse(path, None, None);
+        let (_, diagnostics) = parse(path, ParseOptions::new());
         assert_eq!(diagnostics.has_errors(), true);
         assert_eq!(diagnostics.has_warnings(), false);
         assert_eq!(diagnostics.errors().len(), 1);
@@ -62,7 +62,7 @@ mod test {
     fn self_set_correct_existing_field_type_should_be_ok() {
         let path_buf = std::env::current_dir().unwrap().join("tests/parse/field_type/schemas/05.teo");
         let path = path_buf.to_str().unwrap();
-        let (_, diagnostics) = parse(path, None, None);
+        let (_, diagnostics) = parse(path, ParseOptions::new());
         print_diagnostics(&diagnostics, true);
         assert_eq!(diagnostics.has_errors(), false);
         assert_eq!(diagnostics.has_warnings(), false);
@@ -72,7 +72,7 @@ mod test {
     fn self_set_incorrect_unexisting_field_type_should_be_a_hint_error() {
         let path_buf = std::env::current_dir().unwrap().join("tests/parse/field_type/schemas/06.teo");
         let path = path_buf.to_str().unwrap();
-        let (_, diagnostics) = parse(path, None, None);
+        let (_, diagnostics) = parse(path, ParseOptions::new());
         assert_eq!(diagnostics.has_errors(), true);
         assert_eq!(diagnostics.has_warnings(), false);
         assert_eq!(diagnostics.errors().len(), 1);
@@ -84,7 +84,7 @@ mod test {
     fn shape_interface_get_correct_field_type_should_be_no_errors() {
         let path_buf = std::env::current_dir().unwrap().join("tests/parse/field_type/schemas/07.teo");
         let path = path_buf.to_str().unwrap();
-        let (_, diagnostics) = parse(path, None, None);
+        let (_, diagnostics) = parse(path, ParseOptions::new());
         print_diagnostics(&diagnostics, true);
         assert_eq!(diagnostics.has_errors(), false);
         assert_eq!(diagnostics.has_warnings(), false);
@@ -94,7 +94,7 @@ mod test {
     fn shape_declared_get_correct_field_type_should_be_no_errors() {
         let path_buf = std::env::current_dir().unwrap().join("tests/parse/field_type/schemas/08.teo");
         let path = path_buf.to_str().unwrap();
-        let (_, diagnostics) = parse(path, None, None);
+        let (_, diagnostics) = parse(path, ParseOptions::new());
         print_diagnostics(&diagnostics, true);
         assert_eq!(diagnostics.has_errors(), false);
         assert_eq!(diagnostics.has_warnings(), false);
@@ -0,0 +1,105 @@
+mod test {
+    use maplit::{btreemap, btreeset};
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use teo_parser::{parse, parse_str, ParseOptions};
+    use teo_parser::traits::has_availability::HasAvailability;
+    use teo_parser::utils::environment::Environment;
+    use teo_parser::utils::path::{InMemoryFileSystem, SchemaFileSystem, UnsavedFileSystem};
+    use teo_parser::value::Value;
+
+    #[test]
+    fn in_memory_source_is_resolved_against_std() {
+        let source = "interface Inputs {\n  name: String\n  count: Int?\n}\n";
+        let (schema, diagnostics) = parse_str(source, "/virtual/schema.teo", ParseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(schema.main_source().file_path, "/virtual/schema.teo");
+        assert!(schema.interfaces().iter().any(|interface| interface.identifier().name() == "Inputs"));
+    }
+
+    #[test]
+    fn in_memory_source_imports_from_virtual_files() {
+        let source = "import { Role } from \"./enums\"\nimport \"./shapes\"\n\ninterface Inputs {\n  role: Role\n  filter: Filter\n}\n";
+        let virtual_files = btreemap! {
+            "/virtual/enums.teo".to_owned() => "enum Role {\n  admin\n  user\n}\n".to_owned(),
+            "/virtual/shapes/filter.teo".to_owned() => "interface Filter {\n  keyword: String\n}\n".to_owned(),
+        };
+        let (schema, diagnostics) = parse_str(source, "/virtual/schema.teo", ParseOptions::new().file_system(Box::new(InMemoryFileSystem::new(virtual_files))));
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(schema.user_sources().len(), 3);
+    }
+
+    #[test]
+    fn in_memory_source_never_reads_the_file_system() {
+        let source = "import \"./tests/parse/stdlib/mod.rs\"\n";
+        let (_, diagnostics) = parse_str(source, "schema.teo", ParseOptions::new());
+        assert_eq!(diagnostics.errors().len(), 1);
+        assert_eq!(diagnostics.errors().first().unwrap().message(), "ImportError: file doesn't exist");
+        assert_eq!(diagnostics.errors().first().unwrap().source_path(), "schema.teo");
+    }
+
+    #[test]
+    fn in_memory_source_takes_parse_options() {
+        let source = "#if profile(test)\ninterface Fixture {\n  name: String\n}\n#end\n\nlet mode = ENV[\"MODE\"]\n";
+        let options = ParseOptions::new()
+            .profiles(btreeset! {"test".to_owned()})
+            .environment(Environment::Variables(btreemap! {"MODE".to_owned() => "ci".to_owned()}));
+        let (schema, diagnostics) = parse_str(source, "/virtual/schema.teo", options);
+        assert_eq!(diagnostics.has_errors(), false);
+        assert!(schema.interfaces().iter().any(|interface| interface.identifier().name() == "Fixture" && interface.is_available()));
+        assert_eq!(schema.evaluate_constant(&vec!["mode"]), Some(Value::String("ci".to_owned())));
+    }
+
+    #[test]
+    fn directories_are_listed_sorted_without_duplicates() {
+        let files = InMemoryFileSystem::new(btreemap! {
+            "/virtual/b.teo".to_owned() => "".to_owned(),
+            "/virtual/b/c.teo".to_owned() => "".to_owned(),
+            "/virtual/a.teo".to_owned() => "".to_owned(),
+        });
+        assert_eq!(files.list_dir("/virtual"), vec!["/virtual/a.teo", "/virtual/b", "/virtual/b.teo"]);
+        let overlay = UnsavedFileSystem::new(Arc::new(files), btreemap! {
+            "/virtual/a.teo".to_owned() => "".to_owned(),
+            "/virtual/0.teo".to_owned() => "".to_owned(),
+        });
+        assert_eq!(overlay.list_dir("/virtual"), vec!["/virtual/0.teo", "/virtual/a.teo", "/virtual/b", "/virtual/b.teo"]);
+    }
+
+    #[derive(Debug)]
+    struct CountingFileSystem {
+        files: InMemoryFileSystem,
+        reads: Arc<AtomicUsize>,
+    }
+
+    impl SchemaFileSystem for CountingFileSystem {
+        fn read(&self, file_path: &str) -> Option<String> {
+            self.reads.fetch_add(1, Ordering::SeqCst);
+            self.files.read(file_path)
+        }
+
+        fn exists(&self, file_path: &str) -> bool {
+            self.files.exists(file_path)
+        }
+
+        fn is_dir(&self, file_path: &str) -> bool {
+            self.files.is_dir(file_path)
+        }
+
+        fn list_dir(&self, directory: &str) -> Vec<String> {
+            self.files.list_dir(directory)
+        }
+    }
+
+    #[test]
+    fn custom_file_system_serves_every_schema_file() {
+        let mut files = InMemoryFileSystem::default();
+        files.insert("/virtual/schema.teo", "import \"./enums\"\n\ninterface Inputs {\n  role: Role\n}\n");
+        files.insert("/virtual/enums.teo", "enum Role {\n  admin\n}\n");
+        let reads = Arc::new(AtomicUsize::new(0));
+        let file_system = CountingFileSystem { files, reads: reads.clone() };
+        let (_, diagnostics) = parse("/virtual/schema.teo", ParseOptions::new().file_system(Box::new(file_system)));
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(reads.load(Ordering::SeqCst), 2);
+    }
+}
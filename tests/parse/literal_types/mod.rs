@@ -0,0 +1,62 @@
+mod test {
+    use crate::schema_path;
+    use teo_parser::ast::schema::Schema;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::diagnostics::printer::print_diagnostics;
+    use teo_parser::r#type::Type;
+    use teo_parser::traits::resolved::Resolve;
+    use teo_parser::{parse, ParseOptions};
+
+    fn field_type<'a>(schema: &'a Schema, interface: &str, field: &str) -> &'a Type {
+        let interface = schema.interfaces().into_iter().find(|i| i.identifier().name() == interface).unwrap();
+        interface.fields().find(|f| f.identifier().name() == field).unwrap().type_expr().resolved()
+    }
+
+    #[test]
+    fn literal_types_are_resolved() {
+        let (schema, diagnostics) = parse(schema_path("parse/literal_types", "01.teo"), ParseOptions::new());
+        print_diagnostics(&diagnostics, true);
+        assert_eq!(diagnostics.has_errors(), false);
+        let status = field_type(&schema, "Post", "status");
+        assert!(status.contains_literals());
+        assert_eq!(status.union_members().iter().map(|t| t.as_string_literal().unwrap()).collect::<Vec<_>>(), vec!["draft", "published"]);
+        assert_eq!(format!("{}", field_type(&schema, "Post", "level")), "1 | 2 | 3");
+        assert_eq!(field_type(&schema, "Post", "pinned").as_bool_literal(), Some(true));
+        assert!(field_type(&schema, "Post", "trueValue").is_string());
+    }
+
+    #[test]
+    fn discriminated_unions_are_detected() {
+        let (schema, _) = parse(schema_path("parse/literal_types", "01.teo"), ParseOptions::new());
+        let union = Type::Union(vec![
+            field_type(&schema, "Created", "kind").clone(),
+            field_type(&schema, "Deleted", "kind").clone(),
+        ]);
+        assert!(union.contains_literals());
+        let handler = schema.handler_declarations().into_iter().find(|h| h.identifier().name() == "notify").unwrap();
+        let input = handler.input_type().unwrap().resolved();
+        assert_eq!(input.union_discriminator(&schema), Some("kind".to_owned()));
+    }
+
+    #[test]
+    fn literal_type_mismatches_are_reported() {
+        let (_, diagnostics) = parse(schema_path("parse/literal_types", "02.teo"), ParseOptions::new());
+        let messages: Vec<&str> = diagnostics.errors().iter().map(|e| e.message()).collect();
+        assert_eq!(messages, vec![
+            "float literal types are not supported",
+            "handler argument union should be discriminated by a literal key",
+            "expect \"draft\" | \"published\", found \"archived\"",
+            "expect 1 | 2 | 3, found 4",
+        ]);
+    }
+
+    #[test]
+    fn literal_typed_arguments_keep_their_types() {
+        let (schema, diagnostics) = parse(schema_path("parse/literal_types", "01.teo"), ParseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+        let model = schema.models().into_iter().find(|m| m.identifier().name() == "Product").unwrap();
+        let decorator = model.decorators().next().unwrap();
+        let types: Vec<String> = decorator.argument_list().unwrap().arguments().map(|a| format!("{}", a.value().resolved().r#type())).collect();
+        assert_eq!(types, vec!["String", "Int"]);
+    }
+}
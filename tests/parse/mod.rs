@@ -8,4 +8,22 @@ pub mod handler;
 pub mod handler_template_inclusion;
 pub mod struct_subscription;
 pub mod pipeline_items;
-pub mod type_as_value_expression;
\ No newline at end of file
+pub mod type_as_value_expression;
+pub mod in_memory_source;
+pub mod syntax_errors;
+pub mod availability_flags;
+pub mod profile_flags;
+pub mod type_aliases;
+pub mod model_extends;
+pub mod shape_operations;
+pub mod literal_types;
+pub mod escaped_identifiers;
+pub mod doc_comments;
+pub mod deprecations;
+pub mod numeric_literals;
+pub mod string_literals;
+pub mod date_literals;
+pub mod optional_chaining_and_ternary;
+pub mod division_and_tuple_access;
+pub mod constant_evaluation;
+pub mod environment_variables;
\ No newline at end of file
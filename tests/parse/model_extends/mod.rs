@@ -0,0 +1,117 @@
+mod test {
+    use crate::schema_path;
+    use teo_parser::ast::model::Model;
+    use teo_parser::ast::schema::Schema;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::diagnostics::printer::print_diagnostics;
+    use teo_parser::traits::identifiable::Identifiable;
+    use teo_parser::traits::node_trait::NodeTrait;
+    use teo_parser::traits::resolved::Resolve;
+    use teo_parser::{format_document, jump_to_definition, parse, reparse, ParseOptions, ReparseOptions};
+
+    fn model<'a>(schema: &'a Schema, name: &str) -> &'a Model {
+        schema.models().into_iter().chain(schema.abstract_models()).find(|m| m.identifier().name() == name).unwrap()
+    }
+
+    fn field_names(model: &Model) -> Vec<&str> {
+        model.fields().map(|f| f.identifier().name()).collect()
+    }
+
+    #[test]
+    fn fields_are_copied_from_bases() {
+        let (schema, diagnostics) = parse(schema_path("parse/model_extends", "01.teo"), ParseOptions::new());
+        print_diagnostics(&diagnostics, true);
+        assert_eq!(diagnostics.has_errors(), false);
+        assert!(model(&schema, "Timestamps").r#abstract);
+        assert_eq!(field_names(model(&schema, "Post")), vec!["id", "createdAt", "name", "title", "updatedAt"]);
+        assert_eq!(field_names(model(&schema, "Comment")), vec!["id", "createdAt", "updatedAt", "auditedBy", "content"]);
+        let post = model(&schema, "Post");
+        assert!(post.fields().find(|f| f.identifier().name() == "updatedAt").unwrap().type_expr().resolved().is_optional());
+        assert!(post.fields().find(|f| f.identifier().name() == "createdAt").unwrap().type_expr().resolved().is_datetime());
+    }
+
+    #[test]
+    fn copied_fields_point_to_their_origin() {
+        let (schema, _) = parse(schema_path("parse/model_extends", "01.teo"), ParseOptions::new());
+        let post = model(&schema, "Post");
+        let timestamps = model(&schema, "Timestamps");
+        let copied = post.fields().find(|f| f.identifier().name() == "id").unwrap();
+        let origin = timestamps.fields().find(|f| f.identifier().name() == "id").unwrap();
+        assert!(post.is_mixin_field(copied));
+        assert!(copied.path().starts_with(post.path()));
+        assert_eq!(copied.origin(), origin.path());
+        assert_eq!(copied.span(), origin.span());
+        assert_eq!(post.declared_fields().map(|f| f.identifier().name()).collect::<Vec<_>>(), vec!["title", "updatedAt"]);
+    }
+
+    #[test]
+    fn invalid_extends_are_reported() {
+        let (_, diagnostics) = parse(schema_path("parse/model_extends", "02.teo"), ParseOptions::new());
+        let messages: Vec<&str> = diagnostics.errors().iter().map(|e| e.message()).collect();
+        assert_eq!(messages, vec![
+            "circular reference detected",
+            "circular reference detected",
+            "only abstract models can be extended",
+            "model or interface not found",
+        ]);
+    }
+
+    #[test]
+    fn problems_of_copied_fields_are_reported_by_their_origin() {
+        let (_, diagnostics) = parse(schema_path("parse/model_extends", "03.teo"), ParseOptions::new());
+        assert_eq!(diagnostics.errors().len(), 1);
+        assert_eq!(diagnostics.errors().first().unwrap().message(), "unknown type");
+        assert_eq!(diagnostics.errors().first().unwrap().span().start_position, (9, 10));
+    }
+
+    #[test]
+    fn model_only_problems_of_fields_copied_from_interfaces_are_reported_at_extends() {
+        let (_, diagnostics) = parse(schema_path("parse/model_extends", "04.teo"), ParseOptions::new());
+        let errors: Vec<(&str, (usize, usize))> = diagnostics.errors().iter().map(|e| (e.message(), e.span().start_position)).collect();
+        assert_eq!(errors, vec![
+            ("unknown type", (9, 10)),
+            ("expect String? | Pipeline<Null, String?>, found Int (field label copied from an interface)", (12, 20)),
+            ("expect String? | Pipeline<Null, String?>, found Int (field label copied from an interface)", (17, 23)),
+        ]);
+    }
+
+    #[test]
+    fn abstract_models_are_not_listed_and_have_no_shapes() {
+        let (schema, _) = parse(schema_path("parse/model_extends", "01.teo"), ParseOptions::new());
+        assert!(!schema.models().iter().any(|m| m.is_abstract()));
+        let abstract_names: Vec<&str> = schema.abstract_models().iter().map(|m| m.identifier().name()).collect();
+        assert_eq!(abstract_names, vec!["Timestamps", "Audited"]);
+        assert!(model(&schema, "Timestamps").resolved().shapes.is_empty());
+        assert!(!model(&schema, "Post").resolved().shapes.is_empty());
+    }
+
+    #[test]
+    fn reparse_does_not_duplicate_copied_fields() {
+        let (schema, _) = parse(schema_path("parse/model_extends", "01.teo"), ParseOptions::new());
+        let (schema, diagnostics) = reparse(schema, vec![], ReparseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(field_names(model(&schema, "Post")), vec!["id", "createdAt", "name", "title", "updatedAt"]);
+    }
+
+    #[test]
+    fn jump_to_base_definition() {
+        let path = schema_path("parse/model_extends", "01.teo");
+        let (schema, _) = parse(&path, ParseOptions::new());
+        let definitions = jump_to_definition(&schema, &path, (17, 22));
+        assert_eq!(definitions.len(), 1);
+        assert!(format!("{:?}", definitions.first().unwrap()).contains("identifier_span: Span { start: 76, end: 86, start_position: (6, 16)"));
+        let definitions = jump_to_definition(&schema, &path, (22, 32));
+        assert_eq!(definitions.len(), 1);
+        assert!(format!("{:?}", definitions.first().unwrap()).contains("identifier_span: Span { start: 382, end: 389, start_position: (27, 18)"));
+    }
+
+    #[test]
+    fn formatter_writes_declared_fields_only() {
+        let path = schema_path("parse/model_extends", "01.teo");
+        let (schema, _) = parse(&path, ParseOptions::new());
+        let formatted = format_document(&schema, &path);
+        assert!(formatted.contains("abstract model Timestamps"));
+        assert!(formatted.contains("model Post extends Timestamps, Named"));
+        assert_eq!(formatted.matches("createdAt").count(), 1);
+    }
+}
@@ -0,0 +1,48 @@
+mod test {
+    use crate::schema_path;
+    use std::str::FromStr;
+    use bigdecimal::BigDecimal;
+    use teo_parser::ast::schema::Schema;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::diagnostics::printer::print_diagnostics;
+    use teo_parser::{parse, ParseOptions};
+    use teo_parser::r#type::Type;
+    use teo_parser::traits::resolved::Resolve;
+    use teo_parser::value::Value;
+
+    fn constant<'a>(schema: &'a Schema, name: &str) -> (&'a Type, &'a Value) {
+        let node = schema.main_source().children().into_iter().find(|n| n.as_constant_declaration().map_or(false, |c| c.identifier().name() == name)).unwrap();
+        let resolved = node.as_constant_declaration().unwrap().resolved();
+        (resolved.r#type(), resolved.value().unwrap())
+    }
+
+    #[test]
+    fn numeric_literals_are_resolved() {
+        let (schema, diagnostics) = parse(schema_path("parse/numeric_literals", "01.teo"), ParseOptions::new());
+        print_diagnostics(&diagnostics, true);
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(constant(&schema, "mask").1, &Value::Int(255));
+        assert_eq!(constant(&schema, "flags").1, &Value::Int(10));
+        assert_eq!(constant(&schema, "tiny").1, &Value::Float(1e-6));
+        assert_eq!(constant(&schema, "limit").1, &Value::Int64(1_000_000_000_000));
+        assert_eq!(constant(&schema, "ratio"), (&Type::Float, &Value::Float(2500.0)));
+        assert_eq!(constant(&schema, "negative").1, &Value::Int(-16));
+        assert_eq!(constant(&schema, "price").1, &Value::Decimal(BigDecimal::from_str("12.50").unwrap()));
+        assert_eq!(constant(&schema, "huge"), (&Type::Decimal, &Value::Decimal(BigDecimal::from_str("100000000000000000000").unwrap())));
+        let permission = schema.enums().into_iter().find(|e| e.identifier().name() == "Permission").unwrap();
+        let values: Vec<i32> = permission.members().map(|m| m.resolved().as_int().unwrap()).collect();
+        assert_eq!(values, vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn overflows_are_reported_against_the_expected_type() {
+        let (_, diagnostics) = parse(schema_path("parse/numeric_literals", "02.teo"), ParseOptions::new());
+        let messages: Vec<&str> = diagnostics.errors().iter().map(|e| e.message()).collect();
+        assert_eq!(messages, vec![
+            "EnumMemberError: 0x1_0000_0000 is out of range of Int",
+            "ValueError: 0xFFFF_FFFF is out of range of Int",
+            "ValueError: 0xFFFF_FFFF_FFFF_FFFF is out of range of Int64",
+            "ValueError: 1e40 is out of range of Float32",
+        ]);
+    }
+}
@@ -0,0 +1,64 @@
+mod test {
+    use crate::schema_path;
+    use teo_parser::ast::schema::Schema;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::diagnostics::printer::print_diagnostics;
+    use teo_parser::{format_document, parse, ParseOptions};
+    use teo_parser::r#type::Type;
+    use teo_parser::traits::resolved::Resolve;
+    use teo_parser::value::Value;
+
+    fn constant<'a>(schema: &'a Schema, name: &str) -> (&'a Type, Option<&'a Value>) {
+        let node = schema.main_source().children().into_iter().find(|n| n.as_constant_declaration().map_or(false, |c| c.identifier().name() == name)).unwrap();
+        let resolved = node.as_constant_declaration().unwrap().resolved();
+        (resolved.r#type(), resolved.value())
+    }
+
+    #[test]
+    fn ternary_expressions_are_evaluated() {
+        let (schema, diagnostics) = parse(schema_path("parse/optional_chaining_and_ternary", "01.teo"), ParseOptions::new());
+        print_diagnostics(&diagnostics, true);
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(constant(&schema, "debug"), (&Type::Bool, Some(&Value::Bool(false))));
+        assert_eq!(constant(&schema, "port"), (&Type::Int, Some(&Value::Int(80))));
+        assert_eq!(constant(&schema, "label"), (&Type::Optional(Box::new(Type::String)), Some(&Value::Null)));
+        assert_eq!(constant(&schema, "level"), (&Type::Int, Some(&Value::Int(2))));
+        assert_eq!(constant(&schema, "mixed"), (&Type::Union(vec![Type::Int, Type::String]), Some(&Value::String("one".to_owned()))));
+    }
+
+    #[test]
+    fn optional_chaining_propagates_optionality() {
+        let (schema, _) = parse(schema_path("parse/optional_chaining_and_ternary", "01.teo"), ParseOptions::new());
+        assert!(constant(&schema, "present").0.is_optional());
+        assert_eq!(constant(&schema, "host").0, &Type::Optional(Box::new(Type::String)));
+        assert_eq!(constant(&schema, "missing"), (&Type::Optional(Box::new(Type::String)), Some(&Value::Null)));
+        assert_eq!(constant(&schema, "fallback"), (&Type::String, Some(&Value::String("127.0.0.1".to_owned()))));
+    }
+
+    #[test]
+    fn optional_access_without_chaining_is_reported() {
+        let (_, diagnostics) = parse(schema_path("parse/optional_chaining_and_ternary", "02.teo"), ParseOptions::new());
+        let messages: Vec<&str> = diagnostics.errors().iter().map(|e| e.message()).collect();
+        assert_eq!(messages, vec![
+            "expression might be null",
+            "undefined identifier",
+            "ternary condition must be Bool",
+        ]);
+    }
+
+    #[test]
+    fn formatter_writes_ternary_and_optional_chaining() {
+        let path = schema_path("parse/optional_chaining_and_ternary", "01.teo");
+        let (schema, _) = parse(&path, ParseOptions::new());
+        let formatted = format_document(&schema, &path);
+        assert!(formatted.contains("let label = debug ? \"debug\" : null\n"));
+        assert!(formatted.contains("let host = present?.host\n"));
+    }
+
+    #[test]
+    fn nullish_coalescing_keeps_present_values() {
+        let (schema, _) = teo_parser::parse_str("let name = \"teo\" ?? \"default\"\nlet port = 5050 ?? 80\n", "/virtual/schema.teo", ParseOptions::new());
+        assert_eq!(constant(&schema, "name"), (&Type::String, Some(&Value::String("teo".to_owned()))));
+        assert_eq!(constant(&schema, "port"), (&Type::Int, Some(&Value::Int(5050))));
+    }
+}
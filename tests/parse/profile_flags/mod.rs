@@ -0,0 +1,85 @@
+mod test {
+    use crate::schema_path;
+    use maplit::{btreemap, btreeset};
+    use teo_parser::ast::schema::Schema;
+    use teo_parser::{format_document, parse, reparse, ParseOptions, ReparseOptions};
+    use teo_parser::traits::has_availability::HasAvailability;
+    use teo_parser::utils::environment::Environment;
+
+    fn availability_of_models(schema: &Schema, name: &str) -> Vec<bool> {
+        schema.models().into_iter().filter(|model| model.identifier().name() == name).map(|model| model.is_available()).collect()
+    }
+
+    #[test]
+    fn active_profiles_enable_blocks() {
+        let (schema, diagnostics) = parse(schema_path("parse/profile_flags", "01.teo"), ParseOptions::new().profiles(btreeset!{"test".to_owned()}));
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(availability_of_models(&schema, "Fixture"), vec![true, false]);
+        assert_eq!(availability_of_models(&schema, "Audit"), vec![false]);
+        let fixture = schema.models().into_iter().find(|model| model.identifier().name() == "Fixture").unwrap();
+        assert!(fixture.fields().all(|field| field.is_available()));
+        assert!(schema.data_sets().first().unwrap().is_available());
+    }
+
+    #[test]
+    fn inactive_blocks_are_kept_without_errors() {
+        let (schema, diagnostics) = parse(schema_path("parse/profile_flags", "01.teo"), ParseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(availability_of_models(&schema, "Fixture"), vec![false, true]);
+        assert_eq!(availability_of_models(&schema, "Audit"), vec![true]);
+        assert_eq!(schema.data_sets().len(), 1);
+        assert!(!schema.data_sets().first().unwrap().is_available());
+        let inactive_lines: Vec<_> = schema.main_source().inactive_spans.iter().map(|span| (span.start_position.0, span.end_position.0)).collect();
+        assert_eq!(inactive_lines, vec![(12, 28)]);
+    }
+
+    #[test]
+    fn reparse_keeps_active_profiles() {
+        let (schema, _) = parse(schema_path("parse/profile_flags", "01.teo"), ParseOptions::new().profiles(btreeset!{"e2e".to_owned()}));
+        let (schema, diagnostics) = reparse(schema, vec![schema_path("parse/profile_flags", "01.teo")], ReparseOptions::new());
+        assert_eq!(diagnostics.has_errors(), false);
+        assert!(schema.profiles().contains("e2e"));
+        assert_eq!(availability_of_models(&schema, "Fixture"), vec![true, false]);
+        assert_eq!(availability_of_models(&schema, "Audit"), vec![true]);
+    }
+
+    #[test]
+    fn formatter_keeps_profile_conditions() {
+        let path = schema_path("parse/profile_flags", "01.teo");
+        let (schema, _) = parse(&path, ParseOptions::new());
+        let formatted = format_document(&schema, &path);
+        assert!(formatted.contains("#if profile(test || e2e)\n"));
+        assert!(formatted.contains("#if profile(!test)\n"));
+        assert!(formatted.contains("#if available(mysql)\n"));
+    }
+
+    #[test]
+    fn environment_conditions_are_evaluated_against_the_environment() {
+        let path = schema_path("parse/profile_flags", "02.teo");
+        let (schema, diagnostics) = parse(&path, ParseOptions::new().environment(Environment::Variables(btreemap! {
+            "CI".to_owned() => "true".to_owned(),
+        })));
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(availability_of_models(&schema, "Audit"), vec![true]);
+        assert_eq!(availability_of_models(&schema, "Scratch"), vec![false]);
+        let (schema, _) = parse(&path, ParseOptions::new());
+        assert_eq!(availability_of_models(&schema, "Audit"), vec![false]);
+        assert_eq!(availability_of_models(&schema, "Scratch"), vec![true]);
+        assert!(format_document(&schema, &path).contains("#if env(CI && !SKIP_AUDIT)\n"));
+    }
+
+    #[test]
+    fn environment_conditions_are_evaluated_again_after_dot_env_files_change() {
+        let dot_env = schema_path("parse/profile_flags", "02.env");
+        let (schema, _) = parse(schema_path("parse/profile_flags", "02.teo"), ParseOptions::new().environment(Environment::DotEnvFile("02.env".to_owned())));
+        assert_eq!(availability_of_models(&schema, "Audit"), vec![true]);
+        let (schema, diagnostics) = reparse(schema, vec![], ReparseOptions::new().unsaved_files(btreemap! {
+            dot_env.clone() => "CI=true\nSKIP_AUDIT=1\n".to_owned(),
+        }));
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(availability_of_models(&schema, "Audit"), vec![false]);
+        assert_eq!(availability_of_models(&schema, "Scratch"), vec![true]);
+        let (schema, _) = reparse(schema, vec![dot_env], ReparseOptions::new());
+        assert_eq!(availability_of_models(&schema, "Audit"), vec![true]);
+    }
+}
@@ -0,0 +1,79 @@
+mod test {
+    use crate::schema_path;
+    use teo_parser::ast::schema::Schema;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::diagnostics::printer::print_diagnostics;
+    use teo_parser::r#type::synthesized_shape::SynthesizedShape;
+    use teo_parser::traits::resolved::Resolve;
+    use teo_parser::{auto_complete_items, parse, ParseOptions};
+
+    fn interface_shape<'a>(schema: &'a Schema, name: &str) -> &'a SynthesizedShape {
+        schema.interfaces().into_iter().find(|i| i.identifier().name() == name).unwrap().resolved().shape()
+    }
+
+    fn keys(shape: &SynthesizedShape) -> Vec<&str> {
+        shape.keys().map(|k| k.as_str()).collect()
+    }
+
+    #[test]
+    fn interfaces_extend_shape_operations() {
+        let (schema, diagnostics) = parse(schema_path("parse/shape_operations", "01.teo"), ParseOptions::new());
+        print_diagnostics(&diagnostics, true);
+        assert_eq!(diagnostics.has_errors(), false);
+        let update = interface_shape(&schema, "ProfileUpdate");
+        assert_eq!(keys(update), vec!["age", "bio", "email", "name"]);
+        assert!(update.iter().all(|(_, t)| t.is_optional()));
+        let summary = interface_shape(&schema, "ProfileSummary");
+        assert_eq!(keys(summary), vec!["email", "id", "name"]);
+        assert!(summary.get("name").unwrap().is_string());
+        let input = interface_shape(&schema, "ProfileInput");
+        assert_eq!(keys(input), vec!["age", "email", "name"]);
+        assert!(input.get("age").unwrap().is_int());
+    }
+
+    #[test]
+    fn shape_operations_are_evaluated_after_generics_are_replaced() {
+        let (schema, _) = parse(schema_path("parse/shape_operations", "01.teo"), ParseOptions::new());
+        let page = interface_shape(&schema, "ProfilePage").get("page").unwrap();
+        let (reference, types) = page.as_interface_object().unwrap();
+        let interface = schema.find_top_by_path(reference.path()).unwrap().as_interface_declaration().unwrap();
+        let items = interface.shape_from_generics(types).get("items").unwrap().clone();
+        assert_eq!(items.to_string(), "Partial<Profile>[]");
+        let shape = items.unwrap_array().evaluate_synthesized_shape(&schema).unwrap();
+        assert!(shape.get("name").unwrap().is_optional());
+    }
+
+    #[test]
+    fn handlers_accept_shape_operations() {
+        let (schema, _) = parse(schema_path("parse/shape_operations", "01.teo"), ParseOptions::new());
+        let handler = schema.handler_declarations().into_iter().find(|h| h.identifier().name() == "updateProfile").unwrap();
+        assert_eq!(handler.input_type().unwrap().resolved().to_string(), "Partial<Profile>");
+        let output = handler.output_type().resolved().evaluate_synthesized_shape(&schema).unwrap();
+        assert_eq!(keys(&output), vec!["name"]);
+    }
+
+    #[test]
+    fn invalid_shape_operations_are_reported() {
+        let (_, diagnostics) = parse(schema_path("parse/shape_operations", "02.teo"), ParseOptions::new());
+        let messages: Vec<&str> = diagnostics.errors().iter().map(|e| e.message()).collect();
+        assert_eq!(messages, vec![
+            "Partial takes an interface or shape",
+            "type is invalid for extending",
+            "type is not field reference",
+            "type is invalid for extending",
+            "wrong number of generic arguments, expect 2, found 1",
+            "invalid form handler input type: file is not supported in json input",
+            "field `typo` not found in Person",
+            "field `nmae` not found in Person",
+        ]);
+    }
+
+    #[test]
+    fn shape_operations_are_completed_in_type_positions() {
+        let path = schema_path("parse/shape_operations", "01.teo");
+        let (schema, _) = parse(&path, ParseOptions::new());
+        let completions = auto_complete_items(&schema, &path, (13, 9));
+        assert!(completions.iter().any(|c| c.label.as_str() == "Partial"));
+        assert!(completions.iter().any(|c| c.label.as_str() == "Pick"));
+    }
+}
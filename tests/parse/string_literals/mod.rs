@@ -0,0 +1,73 @@
+mod test {
+    use crate::schema_path;
+    use teo_parser::ast::schema::Schema;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::diagnostics::printer::print_diagnostics;
+    use teo_parser::traits::resolved::Resolve;
+    use teo_parser::value::Value;
+    use teo_parser::{format_document, parse, ParseOptions};
+
+    fn constant_value<'a>(schema: &'a Schema, name: &str) -> Option<&'a Value> {
+        let node = schema.main_source().children().into_iter().find(|n| n.as_constant_declaration().map_or(false, |c| c.identifier().name() == name)).unwrap();
+        node.as_constant_declaration().unwrap().resolved().value()
+    }
+
+    #[test]
+    fn string_literals_are_resolved() {
+        let (schema, diagnostics) = parse(schema_path("parse/string_literals", "01.teo"), ParseOptions::new());
+        print_diagnostics(&diagnostics, true);
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(constant_value(&schema, "url"), Some(&Value::String("postgres://localhost:5432/app".to_owned())));
+        assert_eq!(constant_value(&schema, "pattern"), Some(&Value::String("^\\d+\\.\\d+$".to_owned())));
+        assert_eq!(constant_value(&schema, "query"), Some(&Value::String("SELECT * FROM \"users\"\nWHERE name = 'localhost'\n".to_owned())));
+        assert_eq!(constant_value(&schema, "escaped"), Some(&Value::String("${HOST}".to_owned())));
+        assert_eq!(constant_value(&schema, "multilineEscaped"), Some(&Value::String("${HOST}\n".to_owned())));
+        assert_eq!(constant_value(&schema, "text"), Some(&Value::String("costs ${} or ${ HOST }".to_owned())));
+    }
+
+    #[test]
+    fn interpolations_are_read_by_consumers() {
+        let (schema, _) = parse(schema_path("parse/string_literals", "01.teo"), ParseOptions::new());
+        let interface = schema.interfaces().into_iter().find(|i| i.identifier().name() == "Server").unwrap();
+        let host = interface.fields().next().unwrap().type_expr().resolved();
+        assert_eq!(host.as_string_literal(), Some("localhost"));
+        let model = schema.models().into_iter().find(|m| m.identifier().name() == "User").unwrap();
+        assert_eq!(model.table_name(), "app_users");
+        assert_eq!(model.fields().map(|f| f.column_name()).collect::<Vec<_>>(), vec!["id", "localhost_name"]);
+    }
+
+    #[test]
+    fn invalid_interpolations_are_reported() {
+        let (_, diagnostics) = parse(schema_path("parse/string_literals", "02.teo"), ParseOptions::new());
+        let messages: Vec<&str> = diagnostics.errors().iter().map(|e| e.message()).collect();
+        assert_eq!(messages, vec![
+            "ImportError: import path cannot be interpolated",
+            "undefined identifier",
+            "only constants can be interpolated",
+        ]);
+    }
+
+    #[test]
+    fn invalid_escape_sequences_are_reported() {
+        let (schema, diagnostics) = parse(schema_path("parse/string_literals", "03.teo"), ParseOptions::new());
+        let lines: Vec<(&str, usize)> = diagnostics.errors().iter().map(|e| (e.message(), e.span().start_position.0)).collect();
+        assert_eq!(lines, vec![
+            ("invalid escape sequence", 1),
+            ("invalid escape sequence", 2),
+            ("invalid escape sequence", 3),
+            ("invalid escape sequence", 4),
+        ]);
+        assert_eq!(constant_value(&schema, "pattern"), Some(&Value::String("\\d+".to_owned())));
+        assert_eq!(constant_value(&schema, "path"), Some(&Value::String("C:\\path".to_owned())));
+    }
+
+    #[test]
+    fn formatter_keeps_string_literals() {
+        let path = schema_path("parse/string_literals", "01.teo");
+        let (schema, _) = parse(&path, ParseOptions::new());
+        let formatted = format_document(&schema, &path);
+        assert!(formatted.contains("let url = \"postgres://${HOST}:${PORT}/app\"\n"));
+        assert!(formatted.contains("let pattern = r\"^\\d+\\.\\d+$\"\n"));
+        assert!(formatted.contains("let query = \"\"\"\nSELECT * FROM \"users\"\nWHERE name = '${HOST}'\n\"\"\"\n"));
+    }
+}
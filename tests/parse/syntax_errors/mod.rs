@@ -0,0 +1,63 @@
+mod test {
+    use crate::schema_path;
+    use teo_parser::ast::span::Span;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::{parse, ParseOptions};
+
+    #[test]
+    fn every_broken_field_is_reported() {
+        let (_, diagnostics) = parse(schema_path("parse/syntax_errors", "01.teo"), ParseOptions::new());
+        let messages: Vec<&str> = diagnostics.errors().iter().map(|error| error.message()).filter(|message| message.starts_with("SyntaxError")).collect();
+        assert_eq!(messages, vec![
+            "SyntaxError: expected ':', found 'Int'",
+            "SyntaxError: expected ':', found 'String'",
+        ]);
+        assert_eq!(*diagnostics.errors().first().unwrap().span(), Span {
+            start: 24,
+            end: 27,
+            start_position: (3, 6),
+            end_position: (3, 9),
+        });
+    }
+
+    #[test]
+    fn errors_in_different_declarations_are_reported() {
+        let (_, diagnostics) = parse(schema_path("parse/syntax_errors", "02.teo"), ParseOptions::new());
+        let messages: Vec<&str> = diagnostics.errors().iter().map(|error| error.message()).collect();
+        assert_eq!(messages, vec![
+            "SyntaxError: unexpected ','",
+            "SyntaxError: expected ':', found 'Status'",
+        ]);
+    }
+
+    #[test]
+    fn unclosed_block_is_reported_once_at_end_of_file() {
+        let (_, diagnostics) = parse(schema_path("parse/syntax_errors", "03.teo"), ParseOptions::new());
+        let errors: Vec<_> = diagnostics.errors().iter().filter(|error| error.message().starts_with("SyntaxError")).collect();
+        assert_eq!(errors.len(), 1);
+        let error = errors.first().unwrap();
+        assert!(error.message().contains("'}'"));
+        assert!(error.message().ends_with("found end of file"));
+        assert_eq!(error.span().start_position, (4, 16));
+    }
+
+    #[test]
+    fn parsing_recovers_at_next_declaration() {
+        let (schema, diagnostics) = parse(schema_path("parse/syntax_errors", "04.teo"), ParseOptions::new());
+        assert_eq!(diagnostics.errors().len(), 1);
+        assert_eq!(diagnostics.errors().first().unwrap().message(), "SyntaxError: unexpected 'modle'");
+        assert!(schema.enums().iter().any(|r#enum| r#enum.identifier().name() == "Role"));
+    }
+
+    #[test]
+    fn broken_lines_are_reported_up_to_the_next_line() {
+        let (schema, diagnostics) = parse(schema_path("parse/syntax_errors", "05.teo"), ParseOptions::new());
+        let messages: Vec<&str> = diagnostics.errors().iter().map(|e| e.message()).filter(|m| m.starts_with("SyntaxError")).collect();
+        assert_eq!(messages, vec![
+            "SyntaxError: expected ':', found 'Int'",
+            "SyntaxError: expected ':', found 'String'",
+            "SyntaxError: unexpected '['",
+        ]);
+        assert!(schema.models().iter().any(|m| m.identifier().name() == "Post"));
+    }
+}
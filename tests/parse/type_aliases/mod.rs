@@ -0,0 +1,82 @@
+mod test {
+    use crate::schema_path;
+    use teo_parser::ast::schema::Schema;
+    use teo_parser::diagnostics::diagnostics::DiagnosticsLog;
+    use teo_parser::diagnostics::printer::print_diagnostics;
+    use teo_parser::r#type::Type;
+    use teo_parser::traits::resolved::Resolve;
+    use teo_parser::{auto_complete_items, format_document, jump_to_definition, parse, ParseOptions};
+
+    fn field_type<'a>(schema: &'a Schema, interface: &str, field: &str) -> &'a Type {
+        let interface = schema.interfaces().into_iter().find(|i| i.identifier().name() == interface).unwrap();
+        interface.fields().find(|f| f.identifier().name() == field).unwrap().type_expr().resolved()
+    }
+
+    #[test]
+    fn aliases_resolve_to_their_types() {
+        let (schema, diagnostics) = parse(schema_path("parse/type_aliases", "01.teo"), ParseOptions::new());
+        print_diagnostics(&diagnostics, true);
+        assert_eq!(diagnostics.has_errors(), false);
+        assert_eq!(schema.type_alias_declarations().len(), 4);
+        assert!(field_type(&schema, "Contact", "email").is_string());
+        assert!(field_type(&schema, "Contact", "emails").unwrap_array().is_string());
+        assert!(field_type(&schema, "Contact", "balance").is_optional());
+        assert!(field_type(&schema, "Contact", "balance").unwrap_optional().is_int());
+        assert!(field_type(&schema, "Invoice", "total").unwrap_optional().is_float());
+        assert!(field_type(&schema, "Invoice", "currency").is_string());
+        let (reference, _) = field_type(&schema, "Invoice", "contact").as_interface_object().unwrap();
+        assert_eq!(reference.str_path(), vec!["Contact"]);
+    }
+
+    #[test]
+    fn invalid_alias_usages_are_reported() {
+        let (_, diagnostics) = parse(schema_path("parse/type_aliases", "02.teo"), ParseOptions::new());
+        let messages: Vec<&str> = diagnostics.errors().iter().map(|e| e.message()).collect();
+        assert_eq!(messages, vec![
+            "circular reference detected",
+            "type String doesn't satisfy Int | Float",
+            "wrong number of generic arguments, expect 1, found 0",
+        ]);
+    }
+
+    #[test]
+    fn aliases_cannot_be_used_as_values() {
+        let (_, diagnostics) = parse(schema_path("parse/type_aliases", "03.teo"), ParseOptions::new());
+        let messages: Vec<&str> = diagnostics.errors().iter().map(|e| e.message()).collect();
+        assert_eq!(messages, vec![
+            "type alias cannot be used as a value",
+            "type alias cannot be used as a value",
+            "type alias cannot be used as a value",
+        ]);
+    }
+
+    #[test]
+    fn jump_to_alias_definition() {
+        let path = schema_path("parse/type_aliases", "01.teo");
+        let (schema, _) = parse(&path, ParseOptions::new());
+        let definitions = jump_to_definition(&schema, &path, (15, 11));
+        assert_eq!(definitions.len(), 1);
+        assert!(format!("{:?}", definitions.first().unwrap()).contains("identifier_span: Span { start: 98, end: 103, start_position: (8, 6)"));
+        let definitions = jump_to_definition(&schema, &path, (25, 23));
+        assert_eq!(definitions.len(), 1);
+        assert!(format!("{:?}", definitions.first().unwrap()).contains("identifier_span: Span { start: 446, end: 454, start_position: (33, 8)"));
+    }
+
+    #[test]
+    fn aliases_are_completed_in_type_positions() {
+        let path = schema_path("parse/type_aliases", "01.teo");
+        let (schema, _) = parse(&path, ParseOptions::new());
+        let completions = auto_complete_items(&schema, &path, (19, 12));
+        assert!(completions.iter().any(|c| c.label.as_str() == "Email"));
+        assert!(completions.iter().any(|c| c.label.as_str() == "Recipient"));
+    }
+
+    #[test]
+    fn formatter_writes_aliases() {
+        let path = schema_path("parse/type_aliases", "01.teo");
+        let (schema, _) = parse(&path, ParseOptions::new());
+        let formatted = format_document(&schema, &path);
+        assert!(formatted.contains("type Email = String\n"));
+        assert!(formatted.contains("type Currency = String\n"));
+    }
+}